@@ -6,6 +6,16 @@ mod m20220521_173415_migrate_likes_book;
 mod m20220521_173450_migrate_likes_book_tag;
 mod m20220611_152057_add_page_column_to_history_book_table;
 mod m20220611_161637_add_is_dislike_column_to_likes_table;
+mod m20221019_120000_create_collections_table;
+mod m20221020_120000_add_visibility_to_collections_and_profiles;
+mod m20221021_120000_create_ratings_book_table;
+mod m20221022_120000_create_likes_book_related_table;
+mod m20221023_120000_create_follows_and_notifications_user_tables;
+mod m20221024_120000_create_blocks_table;
+mod m20221025_120000_create_notifications_book_series_table;
+mod m20221026_120000_create_books_title_table_and_search_indexes;
+mod m20221027_120000_add_published_at_to_books_title;
+mod m20221028_120000_add_collections_indexes;
 
 pub struct Migrator;
 
@@ -19,6 +29,16 @@ impl MigratorTrait for Migrator {
             Box::new(m20220521_173450_migrate_likes_book_tag::Migration),
             // Box::new(m20220611_152057_add_page_column_to_history_book_table::Migration),
             // Box::new(m20220611_161637_add_is_dislike_column_to_likes_table::Migration),
+            Box::new(m20221019_120000_create_collections_table::Migration),
+            Box::new(m20221020_120000_add_visibility_to_collections_and_profiles::Migration),
+            Box::new(m20221021_120000_create_ratings_book_table::Migration),
+            Box::new(m20221022_120000_create_likes_book_related_table::Migration),
+            Box::new(m20221023_120000_create_follows_and_notifications_user_tables::Migration),
+            Box::new(m20221024_120000_create_blocks_table::Migration),
+            Box::new(m20221025_120000_create_notifications_book_series_table::Migration),
+            Box::new(m20221026_120000_create_books_title_table_and_search_indexes::Migration),
+            Box::new(m20221027_120000_add_published_at_to_books_title::Migration),
+            Box::new(m20221028_120000_add_collections_indexes::Migration),
         ]
     }
 }
@@ -26,3 +46,8 @@ impl MigratorTrait for Migrator {
 pub async fn up(db: &DatabaseConnection) -> Result<(), DbErr> {
     Migrator::up(db, None).await
 }
+
+/// 아직 적용되지 않은 마이그레이션 수
+pub async fn pending(db: &DatabaseConnection) -> Result<usize, DbErr> {
+    Ok(Migrator::get_pending_migrations(db).await?.len())
+}
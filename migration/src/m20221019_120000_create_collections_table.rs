@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221019_120000_create_collections_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("collections"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("name")).string().not_null())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-collections-user_id")
+                    .from(Alias::new("collections"), Alias::new("user_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        let stmt = Table::create()
+            .table(Alias::new("collections_item"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(
+                ColumnDef::new(Alias::new("collection_id"))
+                    .uuid()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Alias::new("book_id")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("position")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-collections_item-collection_id")
+                    .from(Alias::new("collections_item"), Alias::new("collection_id"))
+                    .to(Alias::new("collections"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("collections_item"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("collections")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
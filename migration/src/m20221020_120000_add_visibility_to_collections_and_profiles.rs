@@ -0,0 +1,83 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221020_120000_add_visibility_to_collections_and_profiles"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // collections 테이블은 repository가 시작할 때 이미 새 컬럼까지 만들어져 있을 수 있음
+        let add_columns_to_collections = r#"
+            ALTER TABLE collections
+                ADD COLUMN IF NOT EXISTS visibility SMALLINT NOT NULL DEFAULT 0,
+                ADD COLUMN IF NOT EXISTS share_token VARCHAR UNIQUE
+            "#;
+
+        conn.execute(Statement::from_sql_and_values(
+            backend,
+            add_columns_to_collections,
+            [],
+        ))
+        .await?;
+
+        let stmt = Table::create()
+            .table(Alias::new("profiles"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().primary_key())
+            .col(
+                ColumnDef::new(Alias::new("visibility"))
+                    .small_integer()
+                    .not_null()
+                    .default::<i16>(0),
+            )
+            .col(
+                ColumnDef::new(Alias::new("share_token"))
+                    .string()
+                    .unique_key(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-profiles-user_id")
+                    .from(Alias::new("profiles"), Alias::new("user_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("profiles")).to_owned())
+            .await?;
+
+        let stmt = Table::alter()
+            .table(Alias::new("collections"))
+            .drop_column(Alias::new("share_token"))
+            .drop_column(Alias::new("visibility"))
+            .to_owned();
+
+        manager.alter_table(stmt).await?;
+
+        Ok(())
+    }
+}
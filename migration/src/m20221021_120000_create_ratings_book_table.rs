@@ -0,0 +1,74 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221021_120000_create_ratings_book_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("ratings_book"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("book_id")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("score"))
+                    .small_integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Alias::new("note")).string())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-ratings_book-user_id")
+                    .from(Alias::new("ratings_book"), Alias::new("user_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        // 라이브러리에서 작품별 통계를 가져갈 때 사용함
+        let create_book_id_index = r#"
+            CREATE INDEX IF NOT EXISTS "idx-ratings_book-book_id" ON ratings_book (book_id)
+            "#;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_sql_and_values(
+                manager.get_database_backend(),
+                create_book_id_index,
+                [],
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("ratings_book")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
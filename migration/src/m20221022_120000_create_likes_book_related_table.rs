@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221022_120000_create_likes_book_related_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("likes_book_related"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("book_id")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("related_book_id"))
+                    .integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Alias::new("support")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .primary_key(
+                Index::create()
+                    .col(Alias::new("book_id"))
+                    .col(Alias::new("related_book_id")),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("likes_book_related"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221023_120000_create_follows_and_notifications_user_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("follows"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("follower_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("followee_id")).uuid().not_null())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-follows-follower_id")
+                    .from(Alias::new("follows"), Alias::new("follower_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-follows-followee_id")
+                    .from(Alias::new("follows"), Alias::new("followee_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        let stmt = Table::create()
+            .table(Alias::new("notifications_user"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("followee_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("book_id")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("notifications_user"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("follows")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221024_120000_create_blocks_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("blocks"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("target_id")).uuid().not_null())
+            .col(
+                ColumnDef::new(Alias::new("kind"))
+                    .small_integer()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-blocks-user_id")
+                    .from(Alias::new("blocks"), Alias::new("user_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-blocks-target_id")
+                    .from(Alias::new("blocks"), Alias::new("target_id"))
+                    .to(Alias::new("users"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Alias::new("blocks")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221025_120000_create_notifications_book_series_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("notifications_book_series"))
+            .if_not_exists()
+            .col(ColumnDef::new(Alias::new("id")).uuid().primary_key())
+            .col(ColumnDef::new(Alias::new("user_id")).uuid().not_null())
+            .col(ColumnDef::new(Alias::new("book_id")).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("series_name"))
+                    .string()
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("notifications_book_series"))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
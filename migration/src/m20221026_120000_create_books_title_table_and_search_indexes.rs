@@ -0,0 +1,76 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221026_120000_create_books_title_table_and_search_indexes"
+    }
+}
+
+/// 제목은 to_tsvector로, 태그 이름이나 제목의 부분 일치는 pg_trgm으로 검색함
+///
+/// extension은 데이터베이스에 하나만 있으므로 `POSTGRES_SCHEMA`와 상관없이 public에 만들고,
+/// search_path에 public이 없어도 찾을 수 있도록 opclass에 스키마를 붙임
+const SEARCH_INDEXES: [&str; 4] = [
+    "CREATE EXTENSION IF NOT EXISTS pg_trgm SCHEMA public",
+    "CREATE INDEX IF NOT EXISTS idx_books_title_title_tsv ON books_title USING GIN (to_tsvector('simple', title))",
+    "CREATE INDEX IF NOT EXISTS idx_books_title_title_trgm ON books_title USING GIN (title public.gin_trgm_ops)",
+    "CREATE INDEX IF NOT EXISTS idx_likes_book_tag_tag_name_trgm ON likes_book_tag USING GIN (tag_name public.gin_trgm_ops)",
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let stmt = Table::create()
+            .table(Alias::new("books_title"))
+            .if_not_exists()
+            .col(
+                ColumnDef::new(Alias::new("book_id"))
+                    .integer()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Alias::new("title")).string().not_null())
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_with_time_zone()
+                    .not_null(),
+            )
+            .to_owned();
+
+        manager.create_table(stmt).await?;
+
+        let conn = manager.get_connection();
+
+        for sql in SEARCH_INDEXES {
+            conn.execute(Statement::from_sql_and_values(
+                manager.get_database_backend(),
+                sql,
+                [],
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute(Statement::from_sql_and_values(
+                manager.get_database_backend(),
+                "DROP INDEX IF EXISTS idx_likes_book_tag_tag_name_trgm",
+                [],
+            ))
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new("books_title")).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
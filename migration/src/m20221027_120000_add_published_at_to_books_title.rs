@@ -0,0 +1,60 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221027_120000_add_published_at_to_books_title"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // books_title 테이블은 repository가 시작할 때 이미 새 컬럼까지 만들어져 있을 수 있음
+        let add_column = r#"
+            ALTER TABLE books_title
+                ADD COLUMN IF NOT EXISTS published_at TIMESTAMP WITH TIME ZONE
+            "#;
+
+        conn.execute(Statement::from_sql_and_values(backend, add_column, []))
+            .await?;
+
+        // 인기순으로 정렬할 때 작품별 좋아요 수를 셈
+        let create_index = r#"
+            CREATE INDEX IF NOT EXISTS idx_likes_book_book_id ON likes_book (book_id)
+            "#;
+
+        conn.execute(Statement::from_sql_and_values(backend, create_index, []))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        conn.execute(Statement::from_sql_and_values(
+            backend,
+            "DROP INDEX IF EXISTS idx_likes_book_book_id",
+            [],
+        ))
+        .await?;
+
+        let stmt = Table::alter()
+            .table(Alias::new("books_title"))
+            .drop_column(Alias::new("published_at"))
+            .to_owned();
+
+        manager.alter_table(stmt).await?;
+
+        Ok(())
+    }
+}
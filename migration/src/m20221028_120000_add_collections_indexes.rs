@@ -0,0 +1,58 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{ConnectionTrait, Statement},
+};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20221028_120000_add_collections_indexes"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        // 이미 겹치는 이름은 먼저 만든 컬렉션만 그대로 두고 뒤에 번호를 붙임
+        let dedupe_names = r#"
+            UPDATE collections c
+            SET name = c.name || ' (' || r.rn || ')'
+            FROM (
+                SELECT id, ROW_NUMBER() OVER (PARTITION BY user_id, name ORDER BY created_at, id) AS rn
+                FROM collections
+            ) r
+            WHERE c.id = r.id AND r.rn > 1
+        "#;
+
+        // (user_id, name) 인덱스는 사용자의 컬렉션 목록을 가져올 때도 씀
+        for statement in [
+            dedupe_names,
+            "CREATE UNIQUE INDEX IF NOT EXISTS uq_collections_user_id_name ON collections (user_id, name)",
+            "CREATE INDEX IF NOT EXISTS idx_collections_item_collection_id ON collections_item (collection_id)",
+        ] {
+            conn.execute(Statement::from_sql_and_values(backend, statement, []))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let backend = manager.get_database_backend();
+
+        for drop_index in [
+            "DROP INDEX IF EXISTS idx_collections_item_collection_id",
+            "DROP INDEX IF EXISTS uq_collections_user_id_name",
+        ] {
+            conn.execute(Statement::from_sql_and_values(backend, drop_index, []))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::SystemTime;
 use std::{convert::Infallible, net::SocketAddr};
 
 use hyper::Server;
@@ -11,36 +11,61 @@ use hyper::{
 use inspect::{Inspect, InspectOk};
 use sai::{Component, ComponentLifecycle, Injected};
 use tokio::sync::oneshot;
-use util::elapse;
-use util::sea_orm::advisory_lock;
+use tracing::Instrument;
 
 use crate::command::CommandSet;
 use crate::config::Config;
-use crate::database::DatabaseSet;
-use crate::model::{Model, Presenter};
+use crate::database::Migrator;
+use crate::health::Health;
+use crate::metrics::Metrics;
+use crate::model::{self, Model, Presenter};
 use crate::msg::Msg;
-use crate::repository::RepositorySet;
+use crate::openapi;
+use crate::repository::{Backend, RepositorySet};
+use crate::router::Route;
+use crate::telemetry;
 use crate::usecase::{
-    create_like, create_notifications, create_or_update_fcm_token, create_or_update_history,
-    create_user, delete_history, delete_like, get_fcm_tokens, get_histories, get_histories_by,
-    get_likes, get_likes_by, get_notifications, get_user,
+    add_collection_item, create_block, create_collection, create_like, create_notifications,
+    create_or_update_fcm_token, create_or_update_history, create_or_update_rating, create_user,
+    delete_block, delete_collection, delete_collection_item, delete_history, delete_like,
+    delete_rating, follow_user, get_collection, get_collections, get_fcm_tokens, get_followers,
+    get_following, get_histories, get_histories_by, get_likes, get_likes_by, get_notifications,
+    get_rating_aggregates, get_ratings, get_recommendations, get_related_books, get_search_results,
+    get_shared, get_user, invalidate_library_cache, reorder_collection_items, unfollow_user,
+    update_collection, update_collection_visibility, update_profile_visibility,
 };
 
 #[derive(Component)]
-pub struct Resolver {
+pub struct Resolver<B: Backend> {
     #[injected]
-    repository: Injected<RepositorySet>,
+    repository: Injected<RepositorySet<B>>,
 
     #[injected]
     command: Injected<CommandSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+
+    #[injected]
+    health: Injected<Health<B>>,
     // #[injected]
     // config: Injected<Config>,
 }
 
-impl Resolver {
+impl<B: Backend> Resolver<B> {
+    /// Presenter에서 Library의 작품 정보를 가져올 때 씀
+    fn command(&self) -> Arc<CommandSet> {
+        Arc::clone(&self.command)
+    }
+
+    fn metrics(&self) -> Arc<Metrics> {
+        Arc::clone(&self.metrics)
+    }
+
     async fn resolve(&self, msg: Msg) -> crate::Result<Model> {
         let repository = Arc::clone(&self.repository);
         let command = Arc::clone(&self.command);
+        let metrics = Arc::clone(&self.metrics);
         // let config = Arc::clone(&self.config);
 
         let model = match msg {
@@ -48,7 +73,7 @@ impl Resolver {
 
             Msg::GetUser(payload) => get_user::execute(payload, repository).await?.into(),
 
-            Msg::CreateLike(payload) => create_like::execute(payload, repository, command)
+            Msg::CreateLike(payload) => create_like::execute(payload, repository, command, metrics)
                 .await?
                 .into(),
 
@@ -59,7 +84,7 @@ impl Resolver {
             Msg::GetLikesBy(payload) => get_likes_by::execute(payload, repository).await?.into(),
 
             Msg::CreateNotifications(payload) => {
-                create_notifications::execute(payload, repository, command)
+                create_notifications::execute(payload, repository, command, metrics)
                     .await?
                     .into()
             }
@@ -93,6 +118,114 @@ impl Resolver {
             Msg::DeleteHistory(payload) => {
                 delete_history::execute(payload, repository).await?.into()
             }
+
+            Msg::CreateCollection(payload) => create_collection::execute(payload, repository)
+                .await?
+                .into(),
+
+            Msg::GetCollections(payload) => {
+                get_collections::execute(payload, repository).await?.into()
+            }
+
+            Msg::GetCollection(payload) => {
+                get_collection::execute(payload, repository).await?.into()
+            }
+
+            Msg::UpdateCollection(payload) => update_collection::execute(payload, repository)
+                .await?
+                .into(),
+
+            Msg::DeleteCollection(payload) => delete_collection::execute(payload, repository)
+                .await?
+                .into(),
+
+            Msg::AddCollectionItem(payload) => {
+                add_collection_item::execute(payload, repository, command)
+                    .await?
+                    .into()
+            }
+
+            Msg::DeleteCollectionItem(payload) => {
+                delete_collection_item::execute(payload, repository)
+                    .await?
+                    .into()
+            }
+
+            Msg::ReorderCollectionItems(payload) => {
+                reorder_collection_items::execute(payload, repository)
+                    .await?
+                    .into()
+            }
+
+            Msg::UpdateCollectionVisibility(payload) => {
+                update_collection_visibility::execute(payload, repository)
+                    .await?
+                    .into()
+            }
+
+            Msg::UpdateProfileVisibility(payload) => {
+                update_profile_visibility::execute(payload, repository)
+                    .await?
+                    .into()
+            }
+
+            Msg::GetShared(payload) => get_shared::execute(payload, repository).await?.into(),
+
+            Msg::CreateOrUpdateRating(payload) => {
+                create_or_update_rating::execute(payload, repository, command)
+                    .await?
+                    .into()
+            }
+
+            Msg::GetRatings(payload) => get_ratings::execute(payload, repository).await?.into(),
+
+            Msg::DeleteRating(payload) => delete_rating::execute(payload, repository).await?.into(),
+
+            Msg::GetRatingAggregates(payload) => {
+                get_rating_aggregates::execute(payload, repository)
+                    .await?
+                    .into()
+            }
+
+            Msg::GetRelatedBooks(payload) => get_related_books::execute(payload, repository)
+                .await?
+                .into(),
+
+            Msg::GetRecommendations(payload) => {
+                get_recommendations::execute(payload, repository, command)
+                    .await?
+                    .into()
+            }
+
+            Msg::FollowUser(payload) => follow_user::execute(payload, repository).await?.into(),
+
+            Msg::UnfollowUser(payload) => unfollow_user::execute(payload, repository).await?.into(),
+
+            Msg::GetFollowers(payload) => get_followers::execute(payload, repository).await?.into(),
+
+            Msg::GetFollowing(payload) => get_following::execute(payload, repository).await?.into(),
+
+            Msg::CreateBlock(payload) => create_block::execute(payload, repository).await?.into(),
+
+            Msg::DeleteBlock(payload) => delete_block::execute(payload, repository).await?.into(),
+
+            Msg::GetSearchResults(payload) => get_search_results::execute(payload, repository)
+                .await?
+                .into(),
+
+            Msg::InvalidateLibraryCache(payload) => {
+                invalidate_library_cache::execute(payload, repository, command)
+                    .await?
+                    .into()
+            }
+
+            Msg::GetOpenApi => model::OpenApi(openapi::document()).into(),
+
+            Msg::GetMetrics => model::Metrics(self.metrics.render()).into(),
+
+            Msg::GetLiveness => model::Liveness.into(),
+
+            Msg::GetReadiness => model::Readiness(self.health.check().await).into(),
         };
 
         Ok(model)
@@ -101,9 +234,9 @@ impl Resolver {
 
 #[derive(Component)]
 #[lifecycle]
-pub struct HttpServer {
+pub struct HttpServer<B: Backend> {
     #[injected]
-    resolver: Injected<Resolver>,
+    resolver: Injected<Resolver<B>>,
     /* tx: Option<mpsc::Sender<()>>,
     rx: Option<mpsc::Receiver<()>>, */
     #[injected]
@@ -111,34 +244,75 @@ pub struct HttpServer {
 
     /// for database migration
     #[injected]
-    database: Injected<DatabaseSet>,
+    database: Injected<B::Database>,
 
     stop_sender: Option<oneshot::Sender<()>>,
 
     stopped_reciever: Option<oneshot::Receiver<()>>,
 }
 
-async fn handler(
+async fn handler<B: Backend>(
     request: &mut Request<Body>,
     response: &mut Response<Body>,
-    resolver: Arc<Resolver>,
+    resolver: Arc<Resolver<B>>,
     config: Arc<Config>,
 ) -> crate::Result<()> {
-    let msg = elapse!("route", Msg::http(request, response, config.clone()).await?);
+    let msg = Msg::http(request, response, config.clone(), &resolver.metrics)
+        .instrument(tracing::info_span!("route"))
+        .await?;
 
-    let model = elapse!("execute", resolver.resolve(msg).await?);
+    let model = resolver
+        .resolve(msg)
+        .instrument(tracing::info_span!("execute"))
+        .await?;
 
-    let _r = elapse!(
-        "present",
-        model.set_response(request, response, config).await?
-    );
+    model
+        .set_response(request, response, config, resolver.command())
+        .instrument(tracing::info_span!("present"))
+        .await?;
 
     Ok(())
 }
 
-async fn service(
+/// 요청 아이디를 정하고, 요청을 처리하는 동안의 로그와 span에 붙임
+///
+/// 요청에 `X-Request-Id`가 있으면 그대로 씀
+async fn service<B: Backend>(
+    request: Request<Body>,
+    resolver: Arc<Resolver<B>>,
+    config: Arc<Config>,
+) -> Result<Response<Body>, Infallible> {
+    let request_id = telemetry::request_id_or_new(
+        request
+            .headers()
+            .get(telemetry::REQUEST_ID)
+            .and_then(|x| x.to_str().ok()),
+    );
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+
+    let mut response = telemetry::with_request_id(
+        request_id.clone(),
+        respond(request, resolver, config).instrument(span),
+    )
+    .await?;
+
+    response.headers_mut().insert(
+        telemetry::REQUEST_ID,
+        request_id.parse().expect("valid request id"),
+    );
+
+    Ok(response)
+}
+
+async fn respond<B: Backend>(
     mut request: Request<Body>,
-    resolver: Arc<Resolver>,
+    resolver: Arc<Resolver<B>>,
     config: Arc<Config>,
 ) -> Result<Response<Body>, Infallible> {
     let req_method = request.method().to_owned();
@@ -149,43 +323,44 @@ async fn service(
     let start = SystemTime::now();
 
     let mut response = Response::new(Body::empty());
+    let command = resolver.command();
+    let metrics = resolver.metrics();
     let ret = handler(&mut request, &mut response, resolver, config.clone()).await;
 
-    let end = start
-        .elapsed()
-        .as_ref()
-        .map(Duration::as_micros)
-        .unwrap_or(0);
+    let elapsed = start.elapsed().unwrap_or_default();
 
     if let Err(err) = ret {
         err.inspect(|e| log::error!("{}", e))
-            .set_response(&mut request, &mut response, config)
+            .set_response(&mut request, &mut response, config, command)
             .await
             .expect("in err.set_response()");
     }
 
+    // 라우트를 찾지 못한 요청은 경로마다 라벨이 생기지 않도록 묶음
+    let route = request
+        .extensions()
+        .get::<&'static Route>()
+        .map(|x| x.path)
+        .unwrap_or("unmatched");
+
+    metrics.observe_request(&req_method, route, response.status(), elapsed);
+
     Ok(response).inspect_ok(|res| {
         log::info!(
             "<-- {} {} {} {}ms",
             req_method,
             req_uri,
             res.status(),
-            end as f64 / 1000.0
+            elapsed.as_micros() as f64 / 1000.0
         )
     })
 }
 
 #[async_trait::async_trait]
-impl ComponentLifecycle for HttpServer {
+impl<B: Backend> ComponentLifecycle for HttpServer<B> {
     async fn start(&mut self) {
         // TODO: 현재로서는 데이터베이스 마이그레이션을 놓기에는 최적의 위치인데 나중에 다시 생각해보자
-        advisory_lock(
-            self.config.postgres_url(),
-            self.database.postgresql(),
-            migration::up,
-        )
-        .await
-        .expect("failed to migration");
+        self.database.migrate().await.expect("failed to migration");
 
         let (stop_tx, stop_rx) = oneshot::channel();
         let (stopped_tx, stopped_rx) = oneshot::channel();
@@ -201,7 +376,7 @@ impl ComponentLifecycle for HttpServer {
         tokio::spawn(async move {
             let addr = SocketAddr::from(([0, 0, 0, 0], port));
 
-            let svc = |resolver: Arc<Resolver>, config: Arc<Config>| async move {
+            let svc = |resolver: Arc<Resolver<B>>, config: Arc<Config>| async move {
                 Ok::<_, Infallible>(service_fn(move |request| {
                     service(request, resolver.clone(), config.clone())
                 }))
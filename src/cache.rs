@@ -0,0 +1,297 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use madome_sdk::api::library;
+use parking_lot::Mutex;
+use sai::{Component, ComponentLifecycle, Injected};
+
+use crate::config::Config;
+
+struct Entry<V> {
+    value: V,
+    expires_at: Instant,
+    /// `order`에 남아있는 예전 기록과 구분함
+    seq: u64,
+}
+
+struct Inner<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    /// 넣은 순서, 유효기간이 모두 같으므로 만료되는 순서이기도 함
+    ///
+    /// 다시 넣거나 지운 키의 예전 기록은 꺼낼 때 건너뜀
+    order: VecDeque<(K, u64)>,
+    seq: u64,
+}
+
+impl<K, V> Inner<K, V>
+where
+    K: Eq + Hash,
+{
+    fn is_current(&self, key: &K, seq: u64) -> bool {
+        self.entries.get(key).map(|x| x.seq) == Some(seq)
+    }
+
+    /// 만료된 것과 예전 기록을 앞에서부터 치우고, 그래도 가득 차 있으면 가장 먼저 넣은 것을 지움
+    fn evict(&mut self, now: Instant, capacity: usize) {
+        while let Some((key, seq)) = self.order.front() {
+            let expired = match self.entries.get(key) {
+                Some(entry) if entry.seq == *seq => entry.expires_at <= now,
+                _ => true,
+            };
+
+            if !expired && self.entries.len() < capacity {
+                break;
+            }
+
+            let (key, seq) = self.order.pop_front().unwrap();
+
+            if self.is_current(&key, seq) {
+                self.entries.remove(&key);
+            }
+        }
+    }
+
+    /// 예전 기록이 쌓이지 않도록 가끔 정리함
+    fn compact(&mut self, capacity: usize) {
+        if self.order.len() <= capacity * 2 {
+            return;
+        }
+
+        let Self { entries, order, .. } = self;
+
+        order.retain(|(key, seq)| entries.get(key).map(|x| x.seq) == Some(*seq));
+    }
+}
+
+/// 유효기간과 최대 크기가 있는 캐시
+///
+/// 유효기간이 모두 같으므로 넣은 순서대로 만료됨, 가득 차면 가장 먼저 넣은 것을 지움
+pub struct TtlCache<K, V> {
+    inner: Mutex<Inner<K, V>>,
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+                seq: 0,
+            }),
+            ttl,
+            capacity,
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.inner.lock();
+
+        match inner.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.value.clone()),
+            Some(_) => {
+                inner.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock();
+        let now = Instant::now();
+
+        if !inner.entries.contains_key(&key) {
+            inner.evict(now, self.capacity);
+        }
+
+        inner.seq += 1;
+        let seq = inner.seq;
+
+        inner.entries.insert(
+            key.clone(),
+            Entry {
+                value,
+                expires_at: now + self.ttl,
+                seq,
+            },
+        );
+        inner.order.push_back((key, seq));
+
+        inner.compact(self.capacity);
+    }
+
+    pub fn remove(&self, key: &K) {
+        self.inner.lock().entries.remove(key);
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.inner.lock().entries.len()
+    }
+}
+
+/// Library에서 가져온 작품과 작품, 태그의 존재 여부를 캐싱함
+///
+/// Library에서 작품이 수정되거나 삭제되면 webhook으로 무효화됨,
+/// 캐시는 인스턴스마다 따로 있으므로 webhook을 받은 인스턴스의 캐시만 무효화되고
+/// 다른 인스턴스는 유효기간(`LIBRARY_CACHE_TTL`)이 지나야 반영됨
+#[derive(Component)]
+#[lifecycle]
+pub struct LibraryCache {
+    #[injected]
+    config: Injected<Config>,
+
+    books: Option<TtlCache<u32, library::model::Book>>,
+
+    /// 새로 올라온 작품이 바로 보이도록 있는 것만 캐싱함
+    has_books: Option<TtlCache<u32, ()>>,
+
+    /// 새로 올라온 태그가 바로 보이도록 있는 것만 캐싱함
+    has_book_tags: Option<TtlCache<(String, String), ()>>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for LibraryCache {
+    async fn start(&mut self) {
+        let ttl = self.config.library_cache_ttl();
+        let capacity = self.config.library_cache_capacity();
+
+        self.books.replace(TtlCache::new(ttl, capacity));
+        self.has_books.replace(TtlCache::new(ttl, capacity));
+        self.has_book_tags.replace(TtlCache::new(ttl, capacity));
+    }
+}
+
+impl LibraryCache {
+    fn books(&self) -> &TtlCache<u32, library::model::Book> {
+        self.books.as_ref().unwrap()
+    }
+
+    fn has_books(&self) -> &TtlCache<u32, ()> {
+        self.has_books.as_ref().unwrap()
+    }
+
+    fn has_book_tags(&self) -> &TtlCache<(String, String), ()> {
+        self.has_book_tags.as_ref().unwrap()
+    }
+
+    pub fn get_book(&self, book_id: u32) -> Option<library::model::Book> {
+        self.books().get(&book_id)
+    }
+
+    pub fn add_book(&self, book: library::model::Book) {
+        self.has_books().insert(book.id, ());
+        self.books().insert(book.id, book);
+    }
+
+    pub fn has_book(&self, book_id: u32) -> bool {
+        self.has_books().get(&book_id).is_some()
+    }
+
+    pub fn add_has_book(&self, book_id: u32) {
+        self.has_books().insert(book_id, ());
+    }
+
+    pub fn has_book_tag(&self, tag: &(String, String)) -> bool {
+        self.has_book_tags().get(tag).is_some()
+    }
+
+    pub fn add_has_book_tag(&self, tag: (String, String)) {
+        self.has_book_tags().insert(tag, ());
+    }
+
+    pub fn invalidate_book(&self, book_id: u32) {
+        self.books().remove(&book_id);
+        self.has_books().remove(&book_id);
+    }
+
+    pub fn invalidate_book_tag(&self, tag: &(String, String)) {
+        self.has_book_tags().remove(tag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::TtlCache;
+
+    #[test]
+    fn expire() {
+        let cache = TtlCache::new(Duration::ZERO, 10);
+
+        cache.insert(1, "a");
+
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[test]
+    fn bounded() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        cache.insert(3, "c");
+
+        assert_eq!(cache.len(), 2);
+        // 가장 먼저 들어간 것이 지워짐
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some("c"));
+
+        // 이미 있는 키는 다른 것을 지우지 않음
+        cache.insert(3, "d");
+
+        assert_eq!(cache.get(&2), Some("b"));
+        assert_eq!(cache.get(&3), Some("d"));
+    }
+
+    #[test]
+    fn reinsert() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        // 다시 넣으면 가장 나중에 넣은 것이 됨
+        cache.insert(1, "c");
+        cache.insert(3, "d");
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some("c"));
+        assert_eq!(cache.get(&3), Some("d"));
+    }
+
+    #[test]
+    fn compact() {
+        let cache = TtlCache::new(Duration::from_secs(60), 2);
+
+        for _ in 0..100 {
+            cache.insert(1, "a");
+        }
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.inner.lock().order.len() <= 4);
+    }
+
+    #[test]
+    fn remove() {
+        let cache = TtlCache::new(Duration::from_secs(60), 10);
+
+        cache.insert(1, "a");
+        cache.remove(&1);
+
+        assert_eq!(cache.get(&1), None);
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use madome_sdk::api::{library, BaseError};
+use parking_lot::Mutex;
+use rand::Rng;
+use sai::{Component, ComponentLifecycle, Injected};
+
+use crate::{
+    config::Config,
+    error::CommandError,
+    metrics::{Metrics, Upstream},
+    telemetry,
+};
+
+/// 다시 요청하기 전에 기다리는 최대 시간, jitter를 더하기 전의 값
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Library에 요청할 때 쓰는 공용 클라이언트
+///
+/// 실패하면 잠깐 기다렸다가 다시 요청하고, 연달아 실패하면 한동안 요청하지 않고 바로 실패함
+#[derive(Component)]
+#[lifecycle]
+pub struct LibraryClient {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+
+    http: Option<reqwest::Client>,
+
+    breaker: CircuitBreaker,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for LibraryClient {
+    async fn start(&mut self) {
+        let http = reqwest::Client::builder()
+            .connect_timeout(self.config.library_connect_timeout())
+            .timeout(self.config.library_timeout())
+            .build()
+            .expect("build library http client");
+
+        self.http.replace(http);
+    }
+}
+
+impl LibraryClient {
+    fn http(&self) -> &reqwest::Client {
+        self.http.as_ref().unwrap()
+    }
+
+    /// Library의 로그에서도 같은 요청을 찾을 수 있도록 요청 아이디를 붙임
+    pub fn post(&self, url: impl reqwest::IntoUrl) -> reqwest::RequestBuilder {
+        let builder = self.http().post(url);
+
+        match telemetry::request_id() {
+            Some(request_id) => builder.header(telemetry::REQUEST_ID, request_id),
+            None => builder,
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.config.library_timeout()
+    }
+
+    /// `is_failure`가 참인 에러만 다시 시도하고 circuit breaker와 지표에 실패로 기록함
+    ///
+    /// `operation`은 지표의 라벨로 씀
+    pub async fn execute<T, E, F, Fut>(
+        &self,
+        operation: &'static str,
+        is_failure: fn(&E) -> bool,
+        f: F,
+    ) -> crate::Result<T>
+    where
+        E: Into<crate::Error>,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let retries = self.config.library_retries();
+        let threshold = self.config.library_circuit_threshold();
+        let open_for = self.config.library_circuit_open();
+
+        let mut attempt = 0;
+
+        loop {
+            if !self.breaker.allow(open_for) {
+                return Err(CommandError::LibraryUnavailable.into());
+            }
+
+            let start = Instant::now();
+            let r = f().await;
+
+            self.metrics.observe_upstream(
+                Upstream::Library,
+                operation,
+                start.elapsed(),
+                r.as_ref().err().map(is_failure).unwrap_or(false),
+            );
+
+            match r {
+                Ok(x) => {
+                    self.breaker.success();
+                    return Ok(x);
+                }
+                Err(err) if is_failure(&err) => {
+                    self.breaker.failure(threshold);
+
+                    if attempt >= retries {
+                        return Err(err.into());
+                    }
+                }
+                // 응답은 제대로 왔음
+                Err(err) => {
+                    self.breaker.success();
+                    return Err(err.into());
+                }
+            }
+
+            tokio::time::sleep(self.backoff(attempt)).await;
+
+            attempt += 1;
+        }
+    }
+
+    /// 지수적으로 늘어나는 대기 시간에 jitter를 더함
+    fn backoff(&self, attempt: u32) -> Duration {
+        let base = self.config.library_retry_backoff();
+        let jitter = rand::thread_rng().gen_range(0..=base.min(MAX_BACKOFF).as_millis() as u64);
+
+        exponential_backoff(base, attempt) + Duration::from_millis(jitter)
+    }
+}
+
+/// `base * 2^attempt`, `MAX_BACKOFF`를 넘지 않음
+fn exponential_backoff(base: Duration, attempt: u32) -> Duration {
+    2_u32
+        .checked_pow(attempt)
+        .and_then(|x| base.checked_mul(x))
+        .unwrap_or(MAX_BACKOFF)
+        .min(MAX_BACKOFF)
+}
+
+pub fn is_reqwest_failure(err: &reqwest::Error) -> bool {
+    err.is_timeout()
+        || err.is_connect()
+        || err.status().map(|x| x.is_server_error()).unwrap_or(false)
+}
+
+pub fn is_library_failure(err: &library::Error) -> bool {
+    match err {
+        library::Error::Base(BaseError::Undefined(code, _)) => code.is_server_error(),
+        library::Error::Base(BaseError::Unauthorized | BaseError::PermissionDenied) => false,
+        // 요청을 보내지 못했거나 응답을 읽지 못함
+        library::Error::Base(_) => true,
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct CircuitBreaker {
+    state: Mutex<CircuitState>,
+}
+
+#[derive(Default)]
+struct CircuitState {
+    failures: usize,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn allow(&self, open_for: Duration) -> bool {
+        let mut state = self.state.lock();
+
+        match state.opened_at {
+            Some(opened_at) if opened_at.elapsed() < open_for => false,
+            // half-open: 한 번만 시도해보고 결과에 따라 닫거나 다시 열음
+            Some(_) => {
+                state.opened_at.replace(Instant::now());
+                true
+            }
+            None => true,
+        }
+    }
+
+    fn success(&self) {
+        let mut state = self.state.lock();
+
+        state.failures = 0;
+        state.opened_at = None;
+    }
+
+    fn failure(&self, threshold: usize) {
+        let mut state = self.state.lock();
+
+        state.failures += 1;
+
+        if state.failures >= threshold {
+            state.opened_at.replace(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{exponential_backoff, CircuitBreaker, MAX_BACKOFF};
+
+    #[test]
+    fn backoff() {
+        let base = Duration::from_millis(100);
+
+        assert_eq!(exponential_backoff(base, 0), base);
+        assert_eq!(exponential_backoff(base, 3), Duration::from_millis(800));
+        assert_eq!(exponential_backoff(base, 10), MAX_BACKOFF);
+        // 2^32는 u32를 넘음
+        assert_eq!(exponential_backoff(base, 32), MAX_BACKOFF);
+        assert_eq!(exponential_backoff(Duration::MAX, 1), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn open_after_threshold() {
+        let breaker = CircuitBreaker::default();
+        let open_for = Duration::from_secs(60);
+
+        breaker.failure(2);
+        assert!(breaker.allow(open_for));
+
+        breaker.failure(2);
+        assert!(!breaker.allow(open_for));
+    }
+
+    #[test]
+    fn close_on_success() {
+        let breaker = CircuitBreaker::default();
+        let open_for = Duration::from_secs(60);
+
+        breaker.failure(2);
+        breaker.success();
+        breaker.failure(2);
+
+        assert!(breaker.allow(open_for));
+    }
+
+    #[test]
+    fn half_open() {
+        let breaker = CircuitBreaker::default();
+
+        breaker.failure(1);
+        assert!(!breaker.allow(Duration::from_secs(60)));
+
+        // 기다린 뒤에는 한 번만 통과시킴
+        assert!(breaker.allow(Duration::ZERO));
+        assert!(!breaker.allow(Duration::from_secs(60)));
+
+        breaker.success();
+        assert!(breaker.allow(Duration::from_secs(60)));
+    }
+}
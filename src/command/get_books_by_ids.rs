@@ -0,0 +1,83 @@
+use madome_sdk::api::{library, Token};
+use sai::{Component, Injected};
+
+use crate::{
+    command::{
+        client::{is_library_failure, LibraryClient},
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
+
+#[derive(Component)]
+pub struct GetBooksByIds {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
+}
+
+impl GetBooksByIds {
+    pub async fn get_books_by_ids(
+        &self,
+        book_ids: Vec<u32>,
+    ) -> crate::Result<Vec<library::model::Book>> {
+        if book_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.client
+            .execute("get_books_by_ids", Error::is_failure, || {
+                self.request(book_ids.clone())
+            })
+            .await
+    }
+
+    async fn request(&self, book_ids: Vec<u32>) -> Result<Vec<library::model::Book>, Error> {
+        // sdk에서 만드는 클라이언트에는 타임아웃이 없고, 요청 아이디도 붙일 수 없음
+        let books = tokio::time::timeout(
+            self.client.timeout(),
+            library::get_books_by_ids(self.config.library_url(), Token::default(), book_ids),
+        )
+        .await
+        .map_err(|_| Error::Timeout)??;
+
+        Ok(books)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Library(#[from] library::Error),
+    #[error("Timeout")]
+    Timeout,
+}
+
+impl Error {
+    fn is_failure(&self) -> bool {
+        match self {
+            Self::Library(err) => is_library_failure(err),
+            Self::Timeout => true,
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        CommandError::from(err).into()
+    }
+}
+
+#[async_trait::async_trait]
+impl Command<Vec<u32>, Vec<library::model::Book>> for GetBooksByIds {
+    type Error = crate::Error;
+
+    async fn execute(&self, book_ids: Vec<u32>) -> Result<Vec<library::model::Book>, Self::Error> {
+        let x = self.get_books_by_ids(book_ids).await?;
+
+        Ok(x)
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use madome_sdk::api::{library, Token};
+use sai::{Component, Injected};
+
+use crate::{
+    command::{
+        client::{is_library_failure, LibraryClient},
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
+
+#[derive(Component)]
+pub struct GetBooksByTags {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
+}
+
+impl GetBooksByTags {
+    pub async fn get_books_by_tags(
+        &self,
+        book_tags: Vec<(String, String)>,
+        per_page: usize,
+        page: usize,
+        sort_by: Option<library::payload::BookSortBy>,
+    ) -> crate::Result<HashMap<(String, String), Vec<library::model::Book>>> {
+        if book_tags.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        self.client
+            .execute("get_books_by_tags", Error::is_failure, || {
+                self.request(book_tags.clone(), per_page, page, sort_by.clone())
+            })
+            .await
+    }
+
+    async fn request(
+        &self,
+        book_tags: Vec<(String, String)>,
+        per_page: usize,
+        page: usize,
+        sort_by: Option<library::payload::BookSortBy>,
+    ) -> Result<HashMap<(String, String), Vec<library::model::Book>>, Error> {
+        // sdk에서 만드는 클라이언트에는 타임아웃이 없고, 요청 아이디도 붙일 수 없음
+        let books = tokio::time::timeout(
+            self.client.timeout(),
+            library::get_books_by_tags(
+                self.config.library_url(),
+                Token::default(),
+                book_tags,
+                per_page,
+                page,
+                sort_by,
+            ),
+        )
+        .await
+        .map_err(|_| Error::Timeout)??;
+
+        Ok(books)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Library(#[from] library::Error),
+    #[error("Timeout")]
+    Timeout,
+}
+
+impl Error {
+    fn is_failure(&self) -> bool {
+        match self {
+            Self::Library(err) => is_library_failure(err),
+            Self::Timeout => true,
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        CommandError::from(err).into()
+    }
+}
+
+type Input = (
+    Vec<(String, String)>,
+    usize,
+    usize,
+    Option<library::payload::BookSortBy>,
+);
+
+#[async_trait::async_trait]
+impl Command<Input, HashMap<(String, String), Vec<library::model::Book>>> for GetBooksByTags {
+    type Error = crate::Error;
+
+    async fn execute(
+        &self,
+        (book_tags, per_page, page, sort_by): Input,
+    ) -> Result<HashMap<(String, String), Vec<library::model::Book>>, Self::Error> {
+        let x = self
+            .get_books_by_tags(book_tags, per_page, page, sort_by)
+            .await?;
+
+        Ok(x)
+    }
+}
@@ -1,16 +1,36 @@
 use hyper::header;
 use sai::{Component, Injected};
 
-use crate::{command::r#trait::Command, config::Config, error::CommandError};
+use crate::{
+    command::{
+        client::{is_reqwest_failure, LibraryClient},
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
 
 #[derive(Component)]
 pub struct HasBook {
     #[injected]
     config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
 }
 
 impl HasBook {
-    pub async fn has_book(&self, book_id: u32) -> Result<bool, Error> {
+    pub async fn has_book(&self, book_id: u32) -> crate::Result<bool> {
+        self.client
+            .execute(
+                "has_book",
+                |Error::Reqwest(err)| is_reqwest_failure(err),
+                || self.request(book_id),
+            )
+            .await
+    }
+
+    async fn request(&self, book_id: u32) -> Result<bool, Error> {
         let url = format!("{}/command", self.config.library_url());
 
         log::debug!("{url}");
@@ -26,12 +46,14 @@ impl HasBook {
             book_id,
         };
 
-        let resp = reqwest::Client::new()
+        let resp = self
+            .client
             .post(url)
             .header(header::CONTENT_TYPE, "application/json")
             .body(serde_json::to_string(&body).unwrap())
             .send()
-            .await?;
+            .await?
+            .error_for_status()?;
 
         #[derive(serde::Deserialize)]
         struct Resp {
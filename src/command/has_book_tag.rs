@@ -1,16 +1,36 @@
 use hyper::header;
 use sai::{Component, Injected};
 
-use crate::{command::r#trait::Command, config::Config, error::CommandError};
+use crate::{
+    command::{
+        client::{is_reqwest_failure, LibraryClient},
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
 
 #[derive(Component)]
 pub struct HasBookTag {
     #[injected]
     config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
 }
 
 impl HasBookTag {
-    pub async fn has_book_tag(&self, tag_kind: &str, tag_name: &str) -> Result<bool, Error> {
+    pub async fn has_book_tag(&self, tag_kind: &str, tag_name: &str) -> crate::Result<bool> {
+        self.client
+            .execute(
+                "has_book_tag",
+                |Error::Reqwest(err)| is_reqwest_failure(err),
+                || self.request(tag_kind, tag_name),
+            )
+            .await
+    }
+
+    async fn request(&self, tag_kind: &str, tag_name: &str) -> Result<bool, Error> {
         let url = format!("{}/command", self.config.library_url());
 
         #[derive(serde::Serialize)]
@@ -24,12 +44,14 @@ impl HasBookTag {
             book_tag: (tag_kind, tag_name),
         };
 
-        let resp = reqwest::Client::new()
+        let resp = self
+            .client
             .post(url)
             .header(header::CONTENT_TYPE, "application/json")
             .body(serde_json::to_vec(&body).unwrap())
             .send()
-            .await?;
+            .await?
+            .error_for_status()?;
 
         #[derive(serde::Deserialize)]
         struct Resp {
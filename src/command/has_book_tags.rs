@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use futures::{stream, StreamExt, TryStreamExt};
+use hyper::header;
+use sai::{Component, Injected};
+
+use crate::{
+    command::{
+        client::{is_reqwest_failure, LibraryClient},
+        has_book_tag::HasBookTag,
+        has_books::is_unsupported,
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
+
+#[derive(Component)]
+pub struct HasBookTags {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
+
+    #[injected]
+    has_book_tag: Injected<HasBookTag>,
+}
+
+impl HasBookTags {
+    /// 존재하는 태그만 반환함
+    pub async fn has_book_tags(
+        &self,
+        book_tags: Vec<(String, String)>,
+    ) -> crate::Result<HashSet<(String, String)>> {
+        if book_tags.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let has = self
+            .client
+            .execute(
+                "has_book_tags",
+                |Error::Reqwest(err)| is_reqwest_failure(err),
+                || self.request(&book_tags),
+            )
+            .await?;
+
+        match has {
+            Some(has) => Ok(has),
+            // batch를 지원하지 않는 Library라면 하나씩 확인함
+            None => {
+                stream::iter(book_tags)
+                    .map(|tag| async move {
+                        let has = self.has_book_tag.has_book_tag(&tag.0, &tag.1).await?;
+
+                        crate::Result::Ok(has.then(|| tag))
+                    })
+                    .buffer_unordered(self.config.library_concurrency())
+                    .try_filter_map(|x| async move { Ok(x) })
+                    .try_collect()
+                    .await
+            }
+        }
+    }
+
+    async fn request(
+        &self,
+        book_tags: &[(String, String)],
+    ) -> Result<Option<HashSet<(String, String)>>, Error> {
+        let url = format!("{}/command", self.config.library_url());
+
+        #[derive(serde::Serialize)]
+        struct Req<'a> {
+            pub kind: &'a str,
+            pub book_tags: &'a [(String, String)],
+        }
+
+        let body = Req {
+            kind: "has_book_tags",
+            book_tags,
+        };
+
+        let resp = self
+            .client
+            .post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&body).unwrap())
+            .send()
+            .await?;
+
+        if is_unsupported(resp.status()) {
+            return Ok(None);
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            pub has: HashSet<(String, String)>,
+        }
+
+        let Resp { has } = resp.error_for_status()?.json::<Resp>().await?;
+
+        Ok(Some(has))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Reqwest(#[from] reqwest::Error),
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        CommandError::from(err).into()
+    }
+}
+
+#[async_trait::async_trait]
+impl Command<Vec<(String, String)>, HashSet<(String, String)>> for HasBookTags {
+    type Error = crate::Error;
+
+    async fn execute(
+        &self,
+        book_tags: Vec<(String, String)>,
+    ) -> Result<HashSet<(String, String)>, Self::Error> {
+        let x = self.has_book_tags(book_tags).await?;
+
+        Ok(x)
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use futures::{stream, StreamExt, TryStreamExt};
+use hyper::{header, StatusCode};
+use sai::{Component, Injected};
+
+use crate::{
+    command::{
+        client::{is_reqwest_failure, LibraryClient},
+        has_book::HasBook,
+        r#trait::Command,
+    },
+    config::Config,
+    error::CommandError,
+};
+
+#[derive(Component)]
+pub struct HasBooks {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    client: Injected<LibraryClient>,
+
+    #[injected]
+    has_book: Injected<HasBook>,
+}
+
+impl HasBooks {
+    /// 존재하는 작품의 id만 반환함
+    pub async fn has_books(&self, book_ids: Vec<u32>) -> crate::Result<HashSet<u32>> {
+        if book_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let has = self
+            .client
+            .execute(
+                "has_books",
+                |Error::Reqwest(err)| is_reqwest_failure(err),
+                || self.request(&book_ids),
+            )
+            .await?;
+
+        match has {
+            Some(has) => Ok(has),
+            // batch를 지원하지 않는 Library라면 하나씩 확인함
+            None => {
+                stream::iter(book_ids)
+                    .map(|book_id| async move {
+                        let has = self.has_book.has_book(book_id).await?;
+
+                        crate::Result::Ok(has.then(|| book_id))
+                    })
+                    .buffer_unordered(self.config.library_concurrency())
+                    .try_filter_map(|x| async move { Ok(x) })
+                    .try_collect()
+                    .await
+            }
+        }
+    }
+
+    async fn request(&self, book_ids: &[u32]) -> Result<Option<HashSet<u32>>, Error> {
+        let url = format!("{}/command", self.config.library_url());
+
+        #[derive(serde::Serialize)]
+        struct Req<'a> {
+            pub kind: &'a str,
+            pub book_ids: &'a [u32],
+        }
+
+        let body = Req {
+            kind: "has_books",
+            book_ids,
+        };
+
+        let resp = self
+            .client
+            .post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&body).unwrap())
+            .send()
+            .await?;
+
+        if is_unsupported(resp.status()) {
+            return Ok(None);
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Resp {
+            pub has: HashSet<u32>,
+        }
+
+        let Resp { has } = resp.error_for_status()?.json::<Resp>().await?;
+
+        Ok(Some(has))
+    }
+}
+
+/// 모르는 kind를 받았을 때 Library가 돌려주는 상태 코드
+///
+/// 400이나 422는 요청이 잘못된 것이므로 하나씩 다시 보내지 않음
+pub(super) fn is_unsupported(status: StatusCode) -> bool {
+    matches!(status, StatusCode::NOT_FOUND | StatusCode::NOT_IMPLEMENTED)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Reqwest(#[from] reqwest::Error),
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        CommandError::from(err).into()
+    }
+}
+
+#[async_trait::async_trait]
+impl Command<Vec<u32>, HashSet<u32>> for HasBooks {
+    type Error = crate::Error;
+
+    async fn execute(&self, book_ids: Vec<u32>) -> Result<HashSet<u32>, Self::Error> {
+        let x = self.has_books(book_ids).await?;
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::StatusCode;
+
+    use super::is_unsupported;
+
+    #[test]
+    fn unsupported() {
+        assert!(is_unsupported(StatusCode::NOT_FOUND));
+        assert!(is_unsupported(StatusCode::NOT_IMPLEMENTED));
+
+        assert!(!is_unsupported(StatusCode::OK));
+        assert!(!is_unsupported(StatusCode::BAD_REQUEST));
+        assert!(!is_unsupported(StatusCode::UNPROCESSABLE_ENTITY));
+        assert!(!is_unsupported(StatusCode::UNAUTHORIZED));
+        assert!(!is_unsupported(StatusCode::SERVICE_UNAVAILABLE));
+    }
+}
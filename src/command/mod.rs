@@ -1,10 +1,20 @@
+pub mod client;
+pub mod get_books_by_ids;
+pub mod get_books_by_tags;
 pub mod has_book;
 pub mod has_book_tag;
+pub mod has_book_tags;
+pub mod has_books;
 pub mod send_notification;
 
+use std::collections::{HashMap, HashSet};
+
 use fcm_sdk::Message;
+use madome_sdk::api::library;
 use sai::{Component, Injected};
 
+use crate::cache::LibraryCache;
+
 use self::{r#trait::Command, send_notification::SendNotification};
 
 pub mod r#trait {
@@ -29,10 +39,26 @@ pub struct CommandSet {
 
     #[injected]
     has_book_tag: Injected<has_book_tag::HasBookTag>,
+
+    #[injected]
+    has_books: Injected<has_books::HasBooks>,
+
+    #[injected]
+    has_book_tags: Injected<has_book_tags::HasBookTags>,
+
+    #[injected]
+    get_books_by_ids: Injected<get_books_by_ids::GetBooksByIds>,
+
+    #[injected]
+    get_books_by_tags: Injected<get_books_by_tags::GetBooksByTags>,
+
+    #[injected]
+    cache: Injected<LibraryCache>,
 }
 
 impl CommandSet {
     #[allow(dead_code)]
+    #[tracing::instrument(name = "CommandSet::send_notification", skip_all)]
     pub async fn send_notification(
         &self,
         tokens: Vec<String>,
@@ -41,18 +67,153 @@ impl CommandSet {
         self.send_notification.execute((tokens, message)).await
     }
 
+    #[tracing::instrument(name = "CommandSet::has_book", skip_all)]
     pub async fn has_book(&self, book_id: u32) -> crate::Result<bool> {
-        self.has_book.execute(book_id).await
+        if self.cache.has_book(book_id) {
+            return Ok(true);
+        }
+
+        let has = self.has_book.execute(book_id).await?;
+
+        if has {
+            self.cache.add_has_book(book_id);
+        }
+
+        Ok(has)
     }
 
+    #[tracing::instrument(name = "CommandSet::has_book_tag", skip_all)]
     pub async fn has_book_tag(
         &self,
         tag_kind: impl Into<String>,
         tag_name: impl Into<String>,
     ) -> crate::Result<bool> {
-        self.has_book_tag
-            .execute((tag_kind.into(), tag_name.into()))
-            .await
+        let tag = (tag_kind.into(), tag_name.into());
+
+        if self.cache.has_book_tag(&tag) {
+            return Ok(true);
+        }
+
+        let has = self.has_book_tag.execute(tag.clone()).await?;
+
+        if has {
+            self.cache.add_has_book_tag(tag);
+        }
+
+        Ok(has)
+    }
+
+    /// 존재하는 작품의 id만 반환함
+    #[tracing::instrument(name = "CommandSet::has_books", skip_all)]
+    pub async fn has_books(&self, book_ids: Vec<u32>) -> crate::Result<HashSet<u32>> {
+        let mut has = HashSet::with_capacity(book_ids.len());
+        let mut missed = Vec::new();
+
+        for book_id in book_ids {
+            if self.cache.has_book(book_id) {
+                has.insert(book_id);
+            } else {
+                missed.push(book_id);
+            }
+        }
+
+        for book_id in self.has_books.execute(missed).await? {
+            self.cache.add_has_book(book_id);
+            has.insert(book_id);
+        }
+
+        Ok(has)
+    }
+
+    /// 존재하는 태그만 반환함
+    #[tracing::instrument(name = "CommandSet::has_book_tags", skip_all)]
+    pub async fn has_book_tags(
+        &self,
+        book_tags: Vec<(String, String)>,
+    ) -> crate::Result<HashSet<(String, String)>> {
+        let mut has = HashSet::with_capacity(book_tags.len());
+        let mut missed = Vec::new();
+
+        for tag in book_tags {
+            if self.cache.has_book_tag(&tag) {
+                has.insert(tag);
+            } else {
+                missed.push(tag);
+            }
+        }
+
+        for tag in self.has_book_tags.execute(missed).await? {
+            self.cache.add_has_book_tag(tag.clone());
+            has.insert(tag);
+        }
+
+        Ok(has)
+    }
+
+    /// 캐시에 없는 작품만 Library에서 가져옴
+    #[tracing::instrument(name = "CommandSet::get_books_by_ids", skip_all)]
+    pub async fn get_books_by_ids(
+        &self,
+        book_ids: Vec<u32>,
+    ) -> crate::Result<Vec<library::model::Book>> {
+        let mut books = Vec::with_capacity(book_ids.len());
+        let mut missed = Vec::new();
+
+        for book_id in book_ids {
+            match self.cache.get_book(book_id) {
+                Some(book) => books.push(book),
+                None => missed.push(book_id),
+            }
+        }
+
+        if !missed.is_empty() {
+            let fetched = self.get_books_by_ids.execute(missed).await?;
+
+            for book in fetched {
+                self.cache.add_book(book.clone());
+                books.push(book);
+            }
+        }
+
+        Ok(books)
+    }
+
+    /// 태그마다 결과가 달라서 작품만 캐싱함
+    #[tracing::instrument(name = "CommandSet::get_books_by_tags", skip_all)]
+    pub async fn get_books_by_tags(
+        &self,
+        book_tags: Vec<(String, String)>,
+        per_page: usize,
+        page: usize,
+        sort_by: Option<library::payload::BookSortBy>,
+    ) -> crate::Result<HashMap<(String, String), Vec<library::model::Book>>> {
+        let books_by_tags = self
+            .get_books_by_tags
+            .execute((book_tags, per_page, page, sort_by))
+            .await?;
+
+        for (tag, books) in books_by_tags.iter() {
+            if !books.is_empty() {
+                self.cache.add_has_book_tag(tag.clone());
+            }
+
+            for book in books {
+                self.cache.add_book(book.clone());
+            }
+        }
+
+        Ok(books_by_tags)
+    }
+
+    /// Library에서 작품이 수정되거나 삭제됐을 때
+    pub fn invalidate_books(&self, book_ids: &[u32], book_tags: &[(String, String)]) {
+        for book_id in book_ids {
+            self.cache.invalidate_book(*book_id);
+        }
+
+        for tag in book_tags {
+            self.cache.invalidate_book_tag(tag);
+        }
     }
 }
 
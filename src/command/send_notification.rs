@@ -1,7 +1,7 @@
 use fcm_sdk::FirebaseCloudMessaging;
-use sai::{Component, ComponentLifecycle};
+use sai::{Component, ComponentLifecycle, Injected};
 
-use crate::command::r#trait::Command;
+use crate::{command::r#trait::Command, metrics::Metrics};
 
 #[derive(Debug)]
 pub struct Message {
@@ -27,6 +27,9 @@ impl From<Message> for fcm_sdk::Message {
 #[derive(Component)]
 #[lifecycle]
 pub struct SendNotification {
+    #[injected]
+    metrics: Injected<Metrics>,
+
     fcm_client: Option<FirebaseCloudMessaging>,
 }
 
@@ -59,10 +62,9 @@ where
         let message = message.into();
 
         // TODO: error handle
-        self.fcm_client()
-            .send_to_devices(tokens, message)
-            .await
-            .ok();
+        let sent = self.fcm_client().send_to_devices(tokens, message).await;
+
+        self.metrics.observe_fcm(sent.is_ok());
 
         Ok(())
     }
@@ -1,4 +1,4 @@
-use std::{env, fmt::Debug, str::FromStr};
+use std::{env, fmt::Debug, str::FromStr, time::Duration};
 
 use sai::{Component, ComponentLifecycle};
 
@@ -12,12 +12,28 @@ where
     var.parse().expect("Please set dotenv to valid value")
 }
 
+/// 다시 요청하는 횟수가 이보다 많으면 Library가 죽었을 때 응답이 너무 늦어짐
+const MAX_LIBRARY_RETRIES: u32 = 10;
+
+fn env_or<T>(key: &str, default: T) -> T
+where
+    T: FromStr,
+    <T as FromStr>::Err: Debug,
+{
+    match env::var(key) {
+        Ok(var) => var.parse().expect("Please set dotenv to valid value"),
+        Err(_) => default,
+    }
+}
+
 #[derive(Debug, Component)]
 #[lifecycle]
 pub struct Config {
     port: Option<u16>,
 
     postgres_url: Option<String>,
+    /// 없으면 `public`
+    postgres_schema: Option<String>,
     /* postgres_port: Option<String>,
     postgres_host: Option<String>,
     postgres_user: Option<String>,
@@ -25,6 +41,34 @@ pub struct Config {
     postgres_db: Option<String>, */
     madome_auth_url: Option<String>,
     madome_library_url: Option<String>,
+
+    /// seconds
+    related_books_refresh_interval: Option<u64>,
+    related_books_min_support: Option<usize>,
+
+    /// seconds
+    book_titles_backfill_interval: Option<u64>,
+    book_titles_backfill_batch: Option<usize>,
+
+    /// seconds
+    library_cache_ttl: Option<u64>,
+    library_cache_capacity: Option<usize>,
+
+    /// milliseconds
+    library_timeout: Option<u64>,
+    library_connect_timeout: Option<u64>,
+    library_retries: Option<u32>,
+    library_retry_backoff: Option<u64>,
+    library_circuit_threshold: Option<usize>,
+    /// seconds
+    library_circuit_open: Option<u64>,
+    /// batch를 지원하지 않을 때 동시에 보내는 요청 수
+    library_concurrency: Option<usize>,
+
+    /// `/readyz`에서 Library와 Auth에 연결되는지도 확인함
+    health_check_upstream: Option<bool>,
+    /// milliseconds
+    health_check_timeout: Option<u64>,
 }
 
 #[async_trait::async_trait]
@@ -39,16 +83,63 @@ impl ComponentLifecycle for Config {
         let pg_user: String = env("POSTGRES_USER");
         let pg_pw: String = env("POSTGRES_PW");
         let pg_db: String = env("POSTGRES_DB");
+        let pg_schema = env::var("POSTGRES_SCHEMA").ok();
         let pg_url = format!(
             "postgres://{}:{}@{}:{}/{}",
             pg_user, pg_pw, pg_host, pg_port, pg_db
         );
+        // 연결할 때 search_path를 바꿔서 다른 스키마의 테이블을 씀
+        // pg_trgm 같은 extension은 public에 있으므로 뒤에 붙여둠
+        let pg_url = match &pg_schema {
+            Some(schema) => format!("{pg_url}?options=-c%20search_path%3D{schema}%2Cpublic"),
+            None => pg_url,
+        };
         self.postgres_url.replace(pg_url);
+        self.postgres_schema = pg_schema;
 
         self.madome_auth_url.replace(env("MADOME_AUTH_URL"));
 
         self.madome_library_url.replace(env("MADOME_LIBRARY_URL"));
 
+        self.related_books_refresh_interval
+            .replace(env_or("RELATED_BOOKS_REFRESH_INTERVAL", 60 * 60));
+        self.related_books_min_support
+            .replace(env_or("RELATED_BOOKS_MIN_SUPPORT", 2));
+
+        self.book_titles_backfill_interval
+            .replace(env_or("BOOK_TITLES_BACKFILL_INTERVAL", 10 * 60));
+        self.book_titles_backfill_batch
+            .replace(env_or("BOOK_TITLES_BACKFILL_BATCH", 100));
+
+        self.library_cache_ttl
+            .replace(env_or("LIBRARY_CACHE_TTL", 5 * 60));
+        self.library_cache_capacity
+            .replace(env_or("LIBRARY_CACHE_CAPACITY", 10_000));
+
+        self.library_timeout
+            .replace(env_or("LIBRARY_TIMEOUT_MS", 3_000));
+        self.library_connect_timeout
+            .replace(env_or("LIBRARY_CONNECT_TIMEOUT_MS", 1_000));
+        let library_retries = env_or("LIBRARY_RETRIES", 2);
+        assert!(
+            library_retries <= MAX_LIBRARY_RETRIES,
+            "LIBRARY_RETRIES must be at most {MAX_LIBRARY_RETRIES}"
+        );
+        self.library_retries.replace(library_retries);
+        self.library_retry_backoff
+            .replace(env_or("LIBRARY_RETRY_BACKOFF_MS", 100));
+        self.library_circuit_threshold
+            .replace(env_or("LIBRARY_CIRCUIT_THRESHOLD", 5));
+        self.library_circuit_open
+            .replace(env_or("LIBRARY_CIRCUIT_OPEN_SECS", 30));
+        self.library_concurrency
+            .replace(env_or("LIBRARY_CONCURRENCY", 8));
+
+        self.health_check_upstream
+            .replace(env_or("HEALTH_CHECK_UPSTREAM", false));
+        self.health_check_timeout
+            .replace(env_or("HEALTH_CHECK_TIMEOUT_MS", 1_000));
+
         log::info!("{:?}", self);
     }
 }
@@ -62,6 +153,10 @@ impl Config {
         self.postgres_url.as_ref().unwrap()
     }
 
+    pub fn postgres_schema(&self) -> Option<&str> {
+        self.postgres_schema.as_deref()
+    }
+
     pub fn auth_url(&self) -> &str {
         self.madome_auth_url.as_ref().unwrap()
     }
@@ -69,4 +164,64 @@ impl Config {
     pub fn library_url(&self) -> &str {
         self.madome_library_url.as_ref().unwrap()
     }
+
+    pub fn related_books_refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.related_books_refresh_interval.unwrap())
+    }
+
+    pub fn related_books_min_support(&self) -> usize {
+        self.related_books_min_support.unwrap()
+    }
+
+    pub fn book_titles_backfill_interval(&self) -> Duration {
+        Duration::from_secs(self.book_titles_backfill_interval.unwrap())
+    }
+
+    pub fn book_titles_backfill_batch(&self) -> usize {
+        self.book_titles_backfill_batch.unwrap()
+    }
+
+    pub fn library_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.library_cache_ttl.unwrap())
+    }
+
+    pub fn library_cache_capacity(&self) -> usize {
+        self.library_cache_capacity.unwrap()
+    }
+
+    pub fn library_timeout(&self) -> Duration {
+        Duration::from_millis(self.library_timeout.unwrap())
+    }
+
+    pub fn library_connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.library_connect_timeout.unwrap())
+    }
+
+    pub fn library_retries(&self) -> u32 {
+        self.library_retries.unwrap()
+    }
+
+    pub fn library_retry_backoff(&self) -> Duration {
+        Duration::from_millis(self.library_retry_backoff.unwrap())
+    }
+
+    pub fn library_circuit_threshold(&self) -> usize {
+        self.library_circuit_threshold.unwrap()
+    }
+
+    pub fn library_circuit_open(&self) -> Duration {
+        Duration::from_secs(self.library_circuit_open.unwrap())
+    }
+
+    pub fn library_concurrency(&self) -> usize {
+        self.library_concurrency.unwrap()
+    }
+
+    pub fn health_check_upstream(&self) -> bool {
+        self.health_check_upstream.unwrap()
+    }
+
+    pub fn health_check_timeout(&self) -> Duration {
+        Duration::from_millis(self.health_check_timeout.unwrap())
+    }
 }
@@ -1,2 +1,5 @@
 // duplicate key value violates unique constraint "users_name_key"
 pub const DUPLICATE_KEY_VALUE: &str = "duplicate key value";
+
+/// pg_try_advisory_xact_lock key for refreshing likes_book_related
+pub const REFRESH_RELATED_BOOKS_LOCK_KEY: i64 = 0x6c_696b_6573_7265;
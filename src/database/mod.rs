@@ -1,10 +1,26 @@
 use sai::{Component, ComponentLifecycle, Injected};
-use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbErr, Statement};
+use util::sea_orm::advisory_lock;
 
 use crate::config::Config;
 
 pub mod postgresql;
 
+/// HttpServer의 마이그레이션과 `/readyz`에서 쓰는 데이터베이스
+///
+/// 인메모리 저장소를 쓸 때는 `InMemoryDatabase`로 대신함
+#[async_trait::async_trait]
+pub trait Migrator: Component + Send + Sync + 'static {
+    /// 다른 pod이 마이그레이션하는 중이면 끝날 때까지 기다림
+    async fn migrate(&self) -> Result<(), DbErr>;
+
+    /// 커넥션 풀에서 연결을 꺼내 쿼리를 보내봄
+    async fn ping(&self) -> Result<(), DbErr>;
+
+    /// 아직 적용되지 않은 마이그레이션 수
+    async fn pending_migrations(&self) -> Result<usize, DbErr>;
+}
+
 #[derive(Component)]
 #[lifecycle]
 pub struct DatabaseSet {
@@ -19,6 +35,10 @@ impl ComponentLifecycle for DatabaseSet {
     async fn start(&mut self) {
         let postgresql = Self::connect_postgresql(self.config.postgres_url()).await;
 
+        if let Some(schema) = self.config.postgres_schema() {
+            Self::create_schema(&postgresql, schema).await;
+        }
+
         self.postgresql.replace(postgresql);
     }
 
@@ -34,7 +54,60 @@ impl DatabaseSet {
         Database::connect(option).await.expect("connect postgresql")
     }
 
+    /// search_path는 연결할 때 정해지므로 스키마가 나중에 만들어져도 상관없음
+    async fn create_schema(db: &DatabaseConnection, schema: &str) {
+        let stmt = Statement::from_string(
+            db.get_database_backend(),
+            format!("CREATE SCHEMA IF NOT EXISTS \"{schema}\""),
+        );
+
+        db.execute(stmt).await.expect("create postgresql schema");
+    }
+
     pub fn postgresql(&self) -> &DatabaseConnection {
         self.postgresql.as_ref().unwrap()
     }
 }
+
+#[async_trait::async_trait]
+impl Migrator for DatabaseSet {
+    async fn migrate(&self) -> Result<(), DbErr> {
+        advisory_lock(self.config.postgres_url(), self.postgresql(), migration::up)
+            .await
+            .map(|_| ())
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        let db = self.postgresql();
+
+        db.execute(Statement::from_string(
+            db.get_database_backend(),
+            "SELECT 1".to_string(),
+        ))
+        .await
+        .map(|_| ())
+    }
+
+    async fn pending_migrations(&self) -> Result<usize, DbErr> {
+        migration::pending(self.postgresql()).await
+    }
+}
+
+/// 인메모리 저장소에는 마이그레이션할 것이 없으므로 항상 준비된 것으로 봄
+#[derive(Component)]
+pub struct InMemoryDatabase {}
+
+#[async_trait::async_trait]
+impl Migrator for InMemoryDatabase {
+    async fn migrate(&self) -> Result<(), DbErr> {
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), DbErr> {
+        Ok(())
+    }
+
+    async fn pending_migrations(&self) -> Result<usize, DbErr> {
+        Ok(0)
+    }
+}
@@ -0,0 +1,101 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::database::postgresql::entity;
+use crate::entity::{Block, BlockKind};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "blocks")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub user_id: Uuid,
+    #[sea_orm(index)]
+    pub target_id: Uuid,
+    #[sea_orm(column_type = "SmallInteger")]
+    pub kind: i16,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::UserId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::TargetId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Target,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    pub fn id(user_id: Uuid, target_id: Uuid, kind: BlockKind) -> Uuid {
+        Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("{user_id}{target_id}{}", kind as u8).as_bytes(),
+        )
+    }
+}
+
+impl From<Block> for ActiveModel {
+    fn from(
+        Block {
+            user_id,
+            target_id,
+            kind,
+            created_at,
+        }: Block,
+    ) -> Self {
+        use sea_orm::ActiveValue::*;
+
+        Self {
+            id: Set(Self::id(user_id, target_id, kind)),
+            user_id: Set(user_id),
+            target_id: Set(target_id),
+            kind: Set(kind as i16),
+            created_at: Set(created_at),
+        }
+    }
+}
+
+impl From<Model> for Block {
+    fn from(
+        Model {
+            user_id,
+            target_id,
+            kind,
+            created_at,
+            ..
+        }: Model,
+    ) -> Self {
+        Self {
+            user_id,
+            target_id,
+            kind: (kind as u8).into(),
+            created_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::block table");
+}
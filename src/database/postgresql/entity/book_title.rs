@@ -0,0 +1,50 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::entity::BookTitle;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "books_title")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub book_id: i32,
+    pub title: String,
+    pub published_at: Option<DateTimeUtc>,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for BookTitle {
+    fn from(
+        Model {
+            book_id,
+            title,
+            published_at,
+            updated_at,
+        }: Model,
+    ) -> Self {
+        Self {
+            book_id: book_id as u32,
+            title,
+            published_at,
+            updated_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::book_title table");
+}
@@ -0,0 +1,185 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::database::postgresql::entity;
+use crate::entity::Collection;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "collections")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub user_id: Uuid,
+    pub name: String,
+    #[sea_orm(column_type = "SmallInteger")]
+    pub visibility: i16,
+    #[sea_orm(unique, nullable)]
+    pub share_token: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::UserId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+    #[sea_orm(has_many = "item::Entity")]
+    Item,
+}
+
+impl Related<item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Item.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Collection {
+    fn from(
+        Model {
+            id,
+            user_id,
+            name,
+            visibility,
+            share_token,
+            created_at,
+            updated_at,
+        }: Model,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            visibility: (visibility as u8).into(),
+            share_token,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+impl From<Collection> for ActiveModel {
+    fn from(
+        Collection {
+            id,
+            user_id,
+            name,
+            visibility,
+            share_token,
+            created_at,
+            updated_at,
+        }: Collection,
+    ) -> Self {
+        use sea_orm::ActiveValue::*;
+
+        Self {
+            id: Set(id),
+            user_id: Set(user_id),
+            name: Set(name),
+            visibility: Set(visibility as i16),
+            share_token: Set(share_token),
+            created_at: Set(created_at),
+            updated_at: Set(updated_at),
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::collection table");
+}
+
+pub mod item {
+    use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+    use crate::database::postgresql::entity::{self, collection};
+    use crate::entity::CollectionItem;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "collections_item")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        #[sea_orm(index)]
+        pub collection_id: Uuid,
+        pub book_id: i32,
+        pub position: i32,
+        pub created_at: DateTimeUtc,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "entity::collection::Entity",
+            from = "Column::CollectionId",
+            to = "entity::collection::Column::Id",
+            on_delete = "Cascade"
+        )]
+        Collection,
+    }
+
+    impl Related<collection::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Collection.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+
+    impl ActiveModel {
+        pub fn id(collection_id: Uuid, book_id: u32) -> Uuid {
+            Uuid::new_v5(
+                &Uuid::NAMESPACE_OID,
+                format!("{collection_id}{book_id}").as_bytes(),
+            )
+        }
+    }
+
+    impl From<Model> for CollectionItem {
+        fn from(
+            Model {
+                collection_id,
+                book_id,
+                position,
+                created_at,
+                ..
+            }: Model,
+        ) -> Self {
+            Self {
+                collection_id,
+                book_id: book_id as u32,
+                position: position as usize,
+                created_at,
+            }
+        }
+    }
+
+    pub async fn create_table(db: &DatabaseConnection) {
+        let schema = Schema::new(DbBackend::Postgres);
+
+        let stmt = schema
+            .create_table_from_entity(Entity)
+            .if_not_exists()
+            .to_owned();
+
+        let psql = db.get_database_backend();
+        db.execute(psql.build(&stmt))
+            .await
+            .expect("create entity::collection::item table");
+    }
+}
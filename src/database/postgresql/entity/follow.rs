@@ -0,0 +1,95 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::database::postgresql::entity;
+use crate::entity::Follow;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "follows")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub follower_id: Uuid,
+    #[sea_orm(index)]
+    pub followee_id: Uuid,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::FollowerId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Follower,
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::FolloweeId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Followee,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    pub fn id(follower_id: Uuid, followee_id: Uuid) -> Uuid {
+        Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("{follower_id}{followee_id}").as_bytes(),
+        )
+    }
+}
+
+impl From<Follow> for ActiveModel {
+    fn from(
+        Follow {
+            follower_id,
+            followee_id,
+            created_at,
+        }: Follow,
+    ) -> Self {
+        use sea_orm::ActiveValue::*;
+
+        Self {
+            id: Set(Self::id(follower_id, followee_id)),
+            follower_id: Set(follower_id),
+            followee_id: Set(followee_id),
+            created_at: Set(created_at),
+        }
+    }
+}
+
+impl From<Model> for Follow {
+    fn from(
+        Model {
+            follower_id,
+            followee_id,
+            created_at,
+            ..
+        }: Model,
+    ) -> Self {
+        Self {
+            follower_id,
+            followee_id,
+            created_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::follow table");
+}
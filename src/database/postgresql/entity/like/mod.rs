@@ -1,2 +1,3 @@
 pub mod book;
 pub mod book_tag;
+pub mod related_book;
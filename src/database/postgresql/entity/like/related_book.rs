@@ -0,0 +1,51 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::entity::RelatedBook;
+
+/// likes_book에서 주기적으로 계산해서 저장해두는 테이블
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "likes_book_related")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub book_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub related_book_id: i32,
+    pub support: i32,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for RelatedBook {
+    fn from(
+        Model {
+            book_id,
+            related_book_id,
+            support,
+            ..
+        }: Model,
+    ) -> Self {
+        Self {
+            book_id: book_id as u32,
+            related_book_id: related_book_id as u32,
+            support: support as usize,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::like::related_book table");
+}
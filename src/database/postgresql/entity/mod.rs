@@ -1,5 +1,11 @@
+pub mod block;
+pub mod book_title;
+pub mod collection;
 pub mod fcm_token;
+pub mod follow;
 pub mod history;
 pub mod like;
 pub mod notification;
+pub mod profile;
+pub mod rating;
 pub mod user;
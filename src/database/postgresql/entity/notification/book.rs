@@ -61,6 +61,9 @@ impl ActiveModel {
 
                 (active_model, tag_active_models)
             }
+            Notification::BookSeries { .. } | Notification::User { .. } => {
+                unreachable!("not a book notification")
+            }
         }
     }
 }
@@ -0,0 +1,84 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::entity::Notification;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "notifications_book_series")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub user_id: Uuid,
+    pub book_id: i32,
+    pub series_name: String,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    fn id(book_id: u32, user_id: Uuid) -> Uuid {
+        Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("series{book_id}{user_id}").as_bytes(),
+        )
+    }
+
+    pub fn insert(noti: Notification) -> Self {
+        use sea_orm::ActiveValue::*;
+
+        match noti {
+            Notification::BookSeries {
+                user_id,
+                book_id,
+                series_name,
+                created_at,
+            } => Self {
+                id: Set(Self::id(book_id, user_id)),
+                user_id: Set(user_id),
+                book_id: Set(book_id as i32),
+                series_name: Set(series_name),
+                created_at: Set(created_at),
+            },
+            Notification::Book { .. } | Notification::User { .. } => {
+                unreachable!("not a book series notification")
+            }
+        }
+    }
+}
+
+impl From<Model> for Notification {
+    fn from(
+        Model {
+            user_id,
+            book_id,
+            series_name,
+            created_at,
+            ..
+        }: Model,
+    ) -> Self {
+        Self::BookSeries {
+            user_id,
+            book_id: book_id as u32,
+            series_name,
+            created_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::notification::book_series table");
+}
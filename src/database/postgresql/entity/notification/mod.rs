@@ -1 +1,3 @@
 pub mod book;
+pub mod book_series;
+pub mod user;
@@ -0,0 +1,84 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::entity::Notification;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "notifications_user")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub user_id: Uuid,
+    pub followee_id: Uuid,
+    pub book_id: i32,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    fn id(user_id: Uuid, followee_id: Uuid, book_id: u32) -> Uuid {
+        Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("{user_id}{followee_id}{book_id}").as_bytes(),
+        )
+    }
+
+    pub fn insert(noti: Notification) -> Self {
+        use sea_orm::ActiveValue::*;
+
+        match noti {
+            Notification::User {
+                user_id,
+                followee_id,
+                book_id,
+                created_at,
+            } => Self {
+                id: Set(Self::id(user_id, followee_id, book_id)),
+                user_id: Set(user_id),
+                followee_id: Set(followee_id),
+                book_id: Set(book_id as i32),
+                created_at: Set(created_at),
+            },
+            Notification::Book { .. } | Notification::BookSeries { .. } => {
+                unreachable!("not a user notification")
+            }
+        }
+    }
+}
+
+impl From<Model> for Notification {
+    fn from(
+        Model {
+            user_id,
+            followee_id,
+            book_id,
+            created_at,
+            ..
+        }: Model,
+    ) -> Self {
+        Self::User {
+            user_id,
+            followee_id,
+            book_id: book_id as u32,
+            created_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::notification::user table");
+}
@@ -0,0 +1,61 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, Schema};
+
+use crate::database::postgresql::entity;
+use crate::entity::Profile;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "profiles")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "SmallInteger")]
+    pub visibility: i16,
+    #[sea_orm(unique, nullable)]
+    pub share_token: Option<String>,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::UserId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl From<Model> for Profile {
+    fn from(
+        Model {
+            user_id,
+            visibility,
+            share_token,
+            updated_at,
+        }: Model,
+    ) -> Self {
+        Self {
+            user_id,
+            visibility: (visibility as u8).into(),
+            share_token,
+            updated_at,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::profile table");
+}
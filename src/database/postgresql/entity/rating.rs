@@ -0,0 +1,103 @@
+use sea_orm::{prelude::*, ConnectionTrait, DbBackend, FromQueryResult, Schema};
+
+use crate::database::postgresql::entity;
+use crate::entity::{Rating, RatingAggregate};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "ratings_book")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(index)]
+    pub user_id: Uuid,
+    #[sea_orm(index)]
+    pub book_id: i32,
+    #[sea_orm(column_type = "SmallInteger")]
+    pub score: i16,
+    #[sea_orm(nullable)]
+    pub note: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "entity::user::Entity",
+        from = "Column::UserId",
+        to = "entity::user::Column::Id",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl ActiveModel {
+    pub fn id(user_id: Uuid, book_id: u32) -> Uuid {
+        Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("{user_id}{book_id}").as_bytes(),
+        )
+    }
+}
+
+impl From<Model> for Rating {
+    fn from(
+        Model {
+            user_id,
+            book_id,
+            score,
+            note,
+            created_at,
+            updated_at,
+            ..
+        }: Model,
+    ) -> Self {
+        Self {
+            user_id,
+            book_id: book_id as u32,
+            score: score as u8,
+            note,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+pub struct Aggregate {
+    pub book_id: i32,
+    pub average: f64,
+    pub count: i64,
+}
+
+impl From<Aggregate> for RatingAggregate {
+    fn from(
+        Aggregate {
+            book_id,
+            average,
+            count,
+        }: Aggregate,
+    ) -> Self {
+        Self {
+            book_id: book_id as u32,
+            average,
+            count: count as usize,
+        }
+    }
+}
+
+pub async fn create_table(db: &DatabaseConnection) {
+    let schema = Schema::new(DbBackend::Postgres);
+
+    let stmt = schema
+        .create_table_from_entity(Entity)
+        .if_not_exists()
+        .to_owned();
+
+    let psql = db.get_database_backend();
+    db.execute(psql.build(&stmt))
+        .await
+        .expect("create entity::rating table");
+}
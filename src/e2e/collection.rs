@@ -0,0 +1,142 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{public, request, stub::NOT_FOUND_BOOK_ID, Caller};
+
+#[tokio::test]
+async fn collection() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "name": "favorite" });
+    let resp = public(
+        Method::POST,
+        "/users/@me/collections",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let collection_id = resp.json::<Value>()["id"].as_str().unwrap().to_string();
+    let path = format!("/users/@me/collections/{collection_id}");
+
+    let resp = public(Method::POST, "/users/@me/collections", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CONFLICT);
+
+    let resp = public(Method::GET, "/users/@me/collections", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.json::<Vec<Value>>().len(), 1);
+
+    let body = json!({ "name": "best" });
+    let resp = public(Method::PATCH, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::GET, &path, user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(Method::DELETE, &path, user_id, None).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::GET, &path, user_id, None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn collection_with_invalid_name() {
+    let body = json!({ "name": "" });
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/collections",
+        Uuid::new_v4(),
+        Some(body),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn collection_items() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "name": "favorite" });
+    let resp = public(Method::POST, "/users/@me/collections", user_id, Some(body)).await;
+    let collection_id = resp.json::<Value>()["id"].as_str().unwrap().to_string();
+    let path = format!("/users/@me/collections/{collection_id}/items");
+
+    for book_id in [1, 2] {
+        let body = json!({ "book_id": book_id });
+        let resp = public(Method::POST, &path, user_id, Some(body)).await;
+        assert_eq!(resp.status, StatusCode::CREATED);
+    }
+
+    let body = json!({ "book_id": 1 });
+    let resp = public(Method::POST, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CONFLICT);
+
+    let body = json!({ "book_id": NOT_FOUND_BOOK_ID });
+    let resp = public(Method::POST, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+
+    let body = json!({ "book_ids": [2, 1] });
+    let resp = public(Method::PUT, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let body = json!({ "book_ids": [2] });
+    let resp = public(Method::PUT, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+
+    let body = json!({ "book_id": 1 });
+    let resp = public(Method::DELETE, &path, user_id, Some(body.clone())).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::DELETE, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+
+    // 다른 사용자의 컬렉션은 없는 것처럼 보임
+    let body = json!({ "book_id": 3 });
+    let resp = public(Method::POST, &path, Uuid::new_v4(), Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn share_collection() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "name": "favorite" });
+    let resp = public(Method::POST, "/users/@me/collections", user_id, Some(body)).await;
+    let collection_id = resp.json::<Value>()["id"].as_str().unwrap().to_string();
+    let path = format!("/users/@me/collections/{collection_id}/visibility");
+
+    let body = json!({ "visibility": "unlisted" });
+    let resp = public(Method::PUT, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let share_token = resp.json::<Value>()["share_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let resp = request(
+        Method::GET,
+        &format!("/users/shared/{share_token}"),
+        Caller::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let body = json!({ "visibility": "private" });
+    let resp = public(Method::PUT, &path, user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = request(
+        Method::GET,
+        &format!("/users/shared/{share_token}"),
+        Caller::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,97 @@
+use hyper::{header, http::StatusCode, Method};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{base_url, internal, public, request, stub::FAILED_BOOK_ID, Caller, PUBLIC_ACCESS};
+
+#[tokio::test]
+async fn not_found_route() {
+    let resp = public(Method::GET, "/users/@me/nothing", Uuid::new_v4(), None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+
+    let body = resp.json::<Value>();
+    assert_eq!(body["code"], "not_found");
+    assert_eq!(body["message"], "Not found");
+    assert_eq!(
+        body["correlation_id"],
+        resp.headers["x-request-id"].to_str().unwrap()
+    );
+
+    // 경로부터 찾으므로 로그인하지 않아도 404
+    let resp = request(Method::GET, "/users/@me/nothing", Caller::Anonymous, None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn method_not_allowed() {
+    let resp = public(Method::PUT, "/users/@me/likes", Uuid::new_v4(), None).await;
+    assert_eq!(resp.status, StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(resp.headers[header::ALLOW], "POST, GET, DELETE");
+    assert_eq!(
+        resp.json::<Value>()["details"],
+        json!({ "allow": ["POST", "GET", "DELETE"] })
+    );
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let resp = request(Method::GET, "/users/@me", Caller::Anonymous, None).await;
+    assert_eq!(resp.status, StatusCode::UNAUTHORIZED);
+
+    // 다른 서비스의 요청에는 사용자가 없음
+    let resp = internal(Method::GET, "/users/@me/likes", None).await;
+    assert_eq!(resp.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn invalid_payload() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book" });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+    assert_eq!(resp.json::<Value>()["code"], "invalid_json");
+
+    let resp = public(Method::GET, "/users/@me/likes?kind=nothing", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+    assert_eq!(resp.json::<Value>()["code"], "invalid_querystring");
+
+    let resp = public(Method::GET, "/users/@me/likes?per-page=0", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+
+    let body = resp.json::<Value>();
+    assert_eq!(body["code"], "invalid_per_page");
+    assert_eq!(body["details"], json!({ "field": "per-page" }));
+}
+
+#[tokio::test]
+async fn library_failure() {
+    let body = json!({ "kind": "book", "book_id": FAILED_BOOK_ID });
+
+    let resp = public(Method::POST, "/users/@me/likes", Uuid::new_v4(), Some(body)).await;
+    assert_eq!(resp.status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    // 내부 사정은 알려주지 않음
+    let body = resp.json::<Value>();
+    assert_eq!(body["code"], "has_book_failed");
+    assert_eq!(body["message"], "Internal server error");
+    assert_eq!(body["details"], Value::Null);
+}
+
+#[tokio::test]
+async fn plain_text_for_old_clients() {
+    let resp = reqwest::Client::new()
+        .get(format!("{}/users/@me/nothing", base_url()))
+        .header(PUBLIC_ACCESS, "true")
+        .header(header::ACCEPT, "text/plain")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    assert!(resp.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+    assert_eq!(resp.text().await.unwrap(), "Not found");
+}
@@ -0,0 +1,98 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{public, sign_up};
+
+#[tokio::test]
+async fn follow() {
+    let (user_id, followee_id) = (sign_up().await, sign_up().await);
+    let body = json!({ "followee_id": followee_id });
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/following",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/following",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CONFLICT);
+
+    let resp = public(Method::GET, "/users/@me/following", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(Method::GET, "/users/@me/followers", followee_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(
+        Method::DELETE,
+        "/users/@me/following",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::DELETE, "/users/@me/following", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn follow_invalid_user() {
+    let user_id = sign_up().await;
+
+    let body = json!({ "followee_id": user_id });
+    let resp = public(Method::POST, "/users/@me/following", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+
+    let body = json!({ "followee_id": Uuid::new_v4() });
+    let resp = public(Method::POST, "/users/@me/following", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn block_and_mute() {
+    let (user_id, target_id) = (sign_up().await, sign_up().await);
+    let body = json!({ "target_id": target_id });
+
+    for path in ["/users/@me/blocks", "/users/@me/mutes"] {
+        let resp = public(Method::POST, path, user_id, Some(body.clone())).await;
+        assert_eq!(resp.status, StatusCode::CREATED);
+
+        let resp = public(Method::POST, path, user_id, Some(body.clone())).await;
+        assert_eq!(resp.status, StatusCode::CONFLICT);
+
+        let resp = public(Method::DELETE, path, user_id, Some(body.clone())).await;
+        assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+        let resp = public(Method::DELETE, path, user_id, Some(body.clone())).await;
+        assert_eq!(resp.status, StatusCode::NOT_FOUND);
+    }
+
+    let body = json!({ "target_id": user_id });
+    let resp = public(Method::POST, "/users/@me/blocks", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn follow_blocked_user() {
+    let (user_id, followee_id) = (sign_up().await, sign_up().await);
+
+    let body = json!({ "target_id": user_id });
+    let resp = public(Method::POST, "/users/@me/blocks", followee_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    // 차단 관계면 없는 사용자처럼 보임
+    let body = json!({ "followee_id": followee_id });
+    let resp = public(Method::POST, "/users/@me/following", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,40 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::Value;
+
+use super::{internal, request, Caller};
+
+#[tokio::test]
+async fn get_healthz() {
+    // 쿠키가 없어도 인증을 요구하지 않음
+    let resp = internal(Method::GET, "/healthz", None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let body = resp.json::<Value>();
+    assert_eq!(body["status"], "ok");
+}
+
+#[tokio::test]
+async fn get_readyz() {
+    let resp = internal(Method::GET, "/readyz", None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let body = resp.json::<Value>();
+    assert_eq!(body["status"], "ok");
+
+    // Postgres는 `InMemoryDatabase`가 대신 응답함
+    for dependency in ["postgres", "migration", "library", "auth"] {
+        let check = &body["checks"][dependency];
+
+        assert_eq!(check["status"], "ok", "{dependency}");
+        assert!(check["latency_ms"].is_number(), "{dependency}");
+        assert!(check.get("error").is_none(), "{dependency}");
+    }
+}
+
+#[tokio::test]
+async fn hide_health_from_gateway() {
+    for path in ["/healthz", "/readyz"] {
+        let resp = request(Method::GET, path, Caller::Anonymous, None).await;
+        assert_eq!(resp.status, StatusCode::NOT_FOUND, "{path}");
+    }
+}
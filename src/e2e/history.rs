@@ -0,0 +1,49 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{public, stub::NOT_FOUND_BOOK_ID};
+
+#[tokio::test]
+async fn create_and_delete_history() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book", "book_id": 1, "page": 3 });
+    let resp = public(Method::POST, "/users/@me/histories", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    // 같은 작품이면 페이지만 갱신함
+    let body = json!({ "kind": "book", "book_id": 1, "page": 5 });
+    let resp = public(Method::POST, "/users/@me/histories", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::GET, "/users/@me/histories", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let body = json!({ "kind": "book", "book_id": 1 });
+    let resp = public(
+        Method::DELETE,
+        "/users/@me/histories",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::DELETE, "/users/@me/histories", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn create_history_of_not_found_book() {
+    let body = json!({ "kind": "book", "book_id": NOT_FOUND_BOOK_ID, "page": 1 });
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/histories",
+        Uuid::new_v4(),
+        Some(body),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,73 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{internal, public};
+
+#[tokio::test]
+async fn get_likes_by() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book", "book_id": 1 });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = internal(
+        Method::GET,
+        &format!("/users/{user_id}/likes?kind=book&ids[0]=1&ids[1]=2"),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = internal(
+        Method::GET,
+        &format!("/users/{user_id}/likes?kind=book-tag&tags[0][0]=artist&tags[0][1]=madome"),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_histories_by() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book", "book_id": 1, "page": 1 });
+    let resp = public(Method::POST, "/users/@me/histories", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = internal(
+        Method::GET,
+        &format!("/users/{user_id}/histories?kind=Book&ids[0]=1"),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_related_books() {
+    let resp = internal(Method::GET, "/users/likes/related?book-id=1", None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn invalidate_library_cache() {
+    let body = json!({ "book_ids": [1], "book_tags": [["artist", "madome"]] });
+
+    let resp = internal(Method::POST, "/users/library/invalidate", Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn public_cannot_access_internal() {
+    let resp = public(
+        Method::GET,
+        "/users/likes/related?book-id=1",
+        Uuid::new_v4(),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
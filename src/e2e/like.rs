@@ -0,0 +1,86 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{
+    public,
+    stub::{NOT_FOUND_BOOK_ID, NOT_FOUND_TAG_NAME},
+};
+
+#[tokio::test]
+async fn like_book() {
+    let user_id = Uuid::new_v4();
+    let body = json!({ "kind": "book", "book_id": 1 });
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/likes",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/likes",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CONFLICT);
+
+    // Library가 응답하지 못하면 Warning 헤더와 함께 줄어든 응답을 받음
+    let resp = public(Method::GET, "/users/@me/likes?kind=book", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(
+        Method::DELETE,
+        "/users/@me/likes",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::DELETE, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn like_book_tag() {
+    let user_id = Uuid::new_v4();
+    let body = json!({ "kind": "book_tag", "tag_kind": "artist", "tag_name": "madome" });
+
+    let resp = public(
+        Method::POST,
+        "/users/@me/likes",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::GET, "/users/@me/likes?kind=book-tag", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(Method::DELETE, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn like_not_found() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book", "book_id": NOT_FOUND_BOOK_ID });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+
+    let body = json!({
+        "kind": "book_tag",
+        "tag_kind": "artist",
+        "tag_name": NOT_FOUND_TAG_NAME
+    });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
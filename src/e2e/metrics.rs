@@ -0,0 +1,35 @@
+use hyper::{header, http::StatusCode, Method};
+
+use super::{internal, public, request, sign_up, Caller};
+
+#[tokio::test]
+async fn get_metrics() {
+    let user_id = sign_up().await;
+
+    let resp = public(Method::GET, "/users/@me", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = internal(Method::GET, "/metrics", None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert!(resp.headers[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+
+    let text = String::from_utf8(resp.body).unwrap();
+
+    // 사용자 아이디 대신 라우트 테이블의 경로가 라벨이 됨
+    assert!(text.contains(
+        r#"madome_user_http_requests_total{method="GET",route="/users/@me",status="200"}"#
+    ));
+    assert!(!text.contains(&user_id.to_string()));
+    assert!(text.contains(
+        r#"madome_user_upstream_request_duration_seconds_count{operation="check_access_token",service="auth"}"#
+    ));
+}
+
+#[tokio::test]
+async fn hide_metrics_from_gateway() {
+    let resp = request(Method::GET, "/metrics", Caller::Anonymous, None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,187 @@
+//! HttpServer를 띄워서 실제로 요청을 보내는 테스트
+//!
+//! 저장소는 인메모리, Auth와 Library는 `stub`의 서버를 씀
+
+mod stub;
+
+mod collection;
+mod error;
+mod follow;
+mod health;
+mod history;
+mod internal;
+mod like;
+mod metrics;
+mod notification;
+mod openapi;
+mod rating;
+mod request_id;
+mod share;
+mod user;
+
+use std::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, OnceLock},
+    thread,
+    time::Duration,
+};
+
+use hyper::{header, http::StatusCode, HeaderMap, Method};
+use madome_sdk::api::cookie::MADOME_ACCESS_TOKEN;
+use sai::System;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::registry::tests::{set_env, E2eRegistry};
+
+/// 게이트웨이를 거친 요청에 붙는 헤더
+///
+/// 이 헤더가 없으면 `auth::check_internal`에서 내부 요청으로 봄
+const PUBLIC_ACCESS: &str = "x-madome-public-access";
+
+static HARNESS: OnceLock<String> = OnceLock::new();
+
+/// 테스트마다 포트와 환경 변수가 겹치지 않도록 서버는 한 번만 띄움
+///
+/// `#[tokio::test]`의 런타임은 테스트가 끝나면 사라지므로 별도의 스레드에서 띄워둠
+fn base_url() -> &'static str {
+    HARNESS.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+
+            runtime.block_on(async move {
+                let auth_url = stub::auth().await;
+                let library_url = stub::library().await;
+
+                let port = TcpListener::bind("127.0.0.1:0")
+                    .and_then(|x| x.local_addr())
+                    .unwrap()
+                    .port();
+
+                set_env();
+
+                for (key, value) in [
+                    ("PORT", port.to_string()),
+                    ("MADOME_AUTH_URL", auth_url),
+                    ("MADOME_LIBRARY_URL", library_url),
+                    ("LIBRARY_RETRIES", "0".to_string()),
+                    // 일부러 실패시키는 테스트 때문에 회로가 열리지 않도록 함
+                    ("LIBRARY_CIRCUIT_THRESHOLD", "1000".to_string()),
+                    ("HEALTH_CHECK_UPSTREAM", "true".to_string()),
+                ] {
+                    std::env::set_var(key, value);
+                }
+
+                let mut system = System::<E2eRegistry>::new();
+
+                system.start().await;
+
+                tx.send(port).unwrap();
+
+                futures::future::pending::<()>().await;
+            });
+        });
+
+        let port = rx.recv().unwrap();
+
+        // HttpServer는 start에서 spawn하므로 포트가 열릴 때까지 기다림
+        for _ in 0..100 {
+            if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+                break;
+            }
+
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        format!("http://127.0.0.1:{port}")
+    })
+}
+
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).expect("json deserialize")
+    }
+}
+
+pub enum Caller {
+    /// 로그인한 사용자
+    Public(Uuid),
+    /// 로그인하지 않은 사용자
+    Anonymous,
+    /// 다른 서비스
+    Internal,
+}
+
+pub async fn request(method: Method, path: &str, caller: Caller, body: Option<Value>) -> Response {
+    let url = format!("{}{path}", base_url());
+
+    // 테스트마다 런타임이 달라서 연결을 재사용하지 않음
+    let mut request = reqwest::Client::new().request(method, url);
+
+    match caller {
+        Caller::Public(user_id) => {
+            request = request
+                .header(PUBLIC_ACCESS, "true")
+                .header(header::COOKIE, format!("{MADOME_ACCESS_TOKEN}={user_id}"));
+        }
+        Caller::Anonymous => {
+            request = request.header(PUBLIC_ACCESS, "true");
+        }
+        Caller::Internal => {}
+    }
+
+    if let Some(body) = body {
+        request = request
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body.to_string());
+    }
+
+    let resp = request.send().await.expect("send request");
+
+    Response {
+        status: resp.status(),
+        headers: resp.headers().clone(),
+        body: resp.bytes().await.unwrap().to_vec(),
+    }
+}
+
+pub async fn public(method: Method, path: &str, user_id: Uuid, body: Option<Value>) -> Response {
+    request(method, path, Caller::Public(user_id), body).await
+}
+
+pub async fn internal(method: Method, path: &str, body: Option<Value>) -> Response {
+    request(method, path, Caller::Internal, body).await
+}
+
+/// 가입된 사용자를 만들고 아이디를 돌려줌
+pub async fn sign_up() -> Uuid {
+    let email = format!("{}@madome.app", Uuid::new_v4());
+
+    let resp = public(
+        Method::POST,
+        "/users",
+        Uuid::new_v4(),
+        Some(json!({ "name": "madome", "email": email, "role": 0 })),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = internal(Method::GET, &format!("/users/{email}"), None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let user = resp.json::<Value>();
+
+    user["id"].as_str().unwrap().parse().unwrap()
+}
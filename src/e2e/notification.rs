@@ -0,0 +1,67 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{internal, public};
+
+#[tokio::test]
+async fn notify_liked_book_tag() {
+    let user_id = Uuid::new_v4();
+    let tag_name = Uuid::new_v4().to_string();
+
+    let body = json!({ "kind": "book_tag", "tag_kind": "artist", "tag_name": tag_name });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let body = json!({
+        "kind": "book",
+        "book_id": 1,
+        "book_title": "madome",
+        "book_tags": [["artist", tag_name]]
+    });
+    let resp = internal(Method::POST, "/users/notifications", Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::GET, "/users/@me/notifications", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(Method::GET, "/users/@me/search?q=madome", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn fcm_token() {
+    let user_id = Uuid::new_v4();
+    let fcm_token = Uuid::new_v4().to_string();
+
+    let body = json!({ "udid": Uuid::new_v4(), "fcm_token": fcm_token });
+    let resp = public(Method::POST, "/users/@me/fcm-token", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = internal(
+        Method::GET,
+        &format!("/users/fcm-token?user-ids[0]={user_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.json::<Vec<String>>(), vec![fcm_token]);
+}
+
+#[tokio::test]
+async fn search_with_empty_query() {
+    let resp = public(Method::GET, "/users/@me/search?q=", Uuid::new_v4(), None).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn recommendations() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "kind": "book", "book_id": 1 });
+    let resp = public(Method::POST, "/users/@me/likes", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::GET, "/users/@me/recommendations", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
@@ -0,0 +1,19 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::Value;
+
+use super::{internal, request, Caller};
+
+#[tokio::test]
+async fn get_openapi() {
+    // 로그인하지 않아도 볼 수 있음
+    let resp = request(Method::GET, "/users/openapi.json", Caller::Anonymous, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let doc = resp.json::<Value>();
+    assert_eq!(doc["openapi"], "3.0.3");
+    assert!(doc["paths"]["/users/@me/likes"]["get"].is_object());
+
+    // 사용자를 찾는 라우트로 가지 않음
+    let resp = internal(Method::GET, "/v1/users/openapi.json", None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
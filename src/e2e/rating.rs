@@ -0,0 +1,57 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{internal, public, stub::NOT_FOUND_BOOK_ID};
+
+#[tokio::test]
+async fn rating() {
+    let user_id = Uuid::new_v4();
+    // 다른 테스트의 평점과 섞이지 않도록 함
+    let book_id = 900_000 + rand::random::<u32>() % 10_000;
+
+    let body = json!({ "book_id": book_id, "score": 4, "note": "good" });
+    let resp = public(Method::POST, "/users/@me/ratings", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::GET, "/users/@me/ratings", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = internal(
+        Method::GET,
+        &format!("/users/ratings/aggregates?book-ids[0]={book_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let aggregates = resp.json::<Vec<Value>>();
+    assert_eq!(aggregates.len(), 1);
+    assert_eq!(aggregates[0]["count"], 1);
+
+    let body = json!({ "book_id": book_id });
+    let resp = public(
+        Method::DELETE,
+        "/users/@me/ratings",
+        user_id,
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NO_CONTENT);
+
+    let resp = public(Method::DELETE, "/users/@me/ratings", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn invalid_rating() {
+    let user_id = Uuid::new_v4();
+
+    let body = json!({ "book_id": 1, "score": 6, "note": "" });
+    let resp = public(Method::POST, "/users/@me/ratings", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+
+    let body = json!({ "book_id": NOT_FOUND_BOOK_ID, "score": 5, "note": "" });
+    let resp = public(Method::POST, "/users/@me/ratings", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
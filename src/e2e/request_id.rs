@@ -0,0 +1,45 @@
+use hyper::{header, http::StatusCode, Method};
+use madome_sdk::api::cookie::MADOME_ACCESS_TOKEN;
+use rand::Rng;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{
+    base_url, public,
+    stub::{NOT_FOUND_BOOK_ID, REQUEST_IDS},
+    PUBLIC_ACCESS,
+};
+
+#[tokio::test]
+async fn generate_request_id() {
+    let resp = public(Method::GET, "/users/@me/likes", Uuid::new_v4(), None).await;
+
+    let request_id = resp.headers["x-request-id"].to_str().unwrap();
+    assert!(Uuid::parse_str(request_id).is_ok());
+}
+
+#[tokio::test]
+async fn propagate_request_id() {
+    let request_id = format!("e2e-{}", Uuid::new_v4());
+    // 캐시되지 않은 작품이어야 Library에 요청함
+    let book_id = rand::thread_rng().gen_range(100_000..NOT_FOUND_BOOK_ID);
+
+    let resp = reqwest::Client::new()
+        .post(format!("{}/users/@me/likes", base_url()))
+        .header(PUBLIC_ACCESS, "true")
+        .header(
+            header::COOKIE,
+            format!("{MADOME_ACCESS_TOKEN}={}", Uuid::new_v4()),
+        )
+        .header(header::CONTENT_TYPE, "application/json")
+        .header("x-request-id", &request_id)
+        .body(json!({ "kind": "book", "book_id": book_id }).to_string())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(resp.headers()["x-request-id"], request_id.as_str());
+
+    assert!(REQUEST_IDS.lock().contains(&request_id));
+}
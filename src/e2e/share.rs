@@ -0,0 +1,50 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::{json, Value};
+
+use super::{public, request, sign_up, Caller};
+
+#[tokio::test]
+async fn share_profile() {
+    let user_id = sign_up().await;
+
+    let body = json!({ "visibility": "public" });
+    let resp = public(
+        Method::PUT,
+        "/users/@me/profile/visibility",
+        user_id,
+        Some(body),
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let share_token = resp.json::<Value>()["share_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let path = format!("/users/shared/{share_token}");
+
+    let resp = request(Method::GET, &path, Caller::Anonymous, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    // 차단한 사용자에게는 보이지 않음
+    let viewer_id = sign_up().await;
+
+    let body = json!({ "target_id": viewer_id });
+    let resp = public(Method::POST, "/users/@me/blocks", user_id, Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = request(Method::GET, &path, Caller::Public(viewer_id), None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn get_not_found_shared() {
+    let resp = request(
+        Method::GET,
+        "/users/shared/nothing",
+        Caller::Anonymous,
+        None,
+    )
+    .await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
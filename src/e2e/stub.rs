@@ -0,0 +1,222 @@
+use std::{collections::HashSet, convert::Infallible, net::SocketAddr};
+
+use hyper::{
+    body::Body,
+    header,
+    http::{Request, Response, StatusCode},
+    service::{make_service_fn, service_fn},
+    Method, Server,
+};
+use madome_sdk::api::cookie::MADOME_ACCESS_TOKEN;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+/// Library에 없는 작품
+///
+/// 이것보다 작은 작품은 모두 있는 것으로 봄
+pub const NOT_FOUND_BOOK_ID: u32 = 1_000_000;
+
+/// Library에 없는 태그 이름
+pub const NOT_FOUND_TAG_NAME: &str = "nothing";
+
+/// Library가 500을 돌려주는 작품
+pub const FAILED_BOOK_ID: u32 = 5_000_000;
+
+/// `/command`로 받은 요청 아이디
+pub static REQUEST_IDS: Mutex<Vec<String>> = parking_lot::const_mutex(Vec::new());
+
+async fn serve<F, Fut>(handler: F) -> String
+where
+    F: Fn(Request<Body>) -> Fut + Copy + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Response<Body>> + Send + 'static,
+{
+    let addr = SocketAddr::from(([127, 0, 0, 1], 0));
+
+    let server = Server::bind(&addr).serve(make_service_fn(move |_| async move {
+        Ok::<_, Infallible>(service_fn(move |request| async move {
+            Ok::<_, Infallible>(handler(request).await)
+        }))
+    }));
+
+    let url = format!("http://{}", server.local_addr());
+
+    tokio::spawn(async move {
+        if let Err(err) = server.await {
+            log::error!("{:?}", err);
+        }
+    });
+
+    url
+}
+
+fn json(status: StatusCode, body: Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body.to_string().into())
+        .unwrap()
+}
+
+/// 쿠키에 있는 토큰을 사용자 아이디로 봄
+pub async fn auth() -> String {
+    serve(|request| async move {
+        let user_id = request
+            .headers()
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|x| x.to_str().ok())
+            .flat_map(|x| x.split(';'))
+            .filter_map(|x| x.trim().split_once('='))
+            .find(|(key, _)| *key == MADOME_ACCESS_TOKEN)
+            .and_then(|(_, value)| value.parse::<Uuid>().ok());
+
+        match user_id {
+            Some(user_id) => json(
+                StatusCode::OK,
+                json!({ "user_id": user_id, "user_role": 0 }),
+            ),
+            None => json(StatusCode::UNAUTHORIZED, json!("Unauthorized")),
+        }
+    })
+    .await
+}
+
+fn has_book(book_id: u32) -> bool {
+    book_id < NOT_FOUND_BOOK_ID
+}
+
+fn has_book_tag(tag_name: &str) -> bool {
+    tag_name != NOT_FOUND_TAG_NAME
+}
+
+fn book(book_id: u32) -> Value {
+    json!({
+        "id": book_id,
+        "title": format!("book {book_id}"),
+        "kind": "doujinshi",
+        "page": 20,
+        "language": "korean",
+        "tags": [["artist", "madome"]],
+        "created_at": "2022-01-01T00:00:00Z",
+        "updated_at": "2022-01-01T00:00:00Z",
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Command {
+    HasBook { book_id: u32 },
+    HasBookTag { book_tag: (String, String) },
+    HasBooks { book_ids: Vec<u32> },
+    HasBookTags { book_tags: Vec<(String, String)> },
+}
+
+async fn command(request: Request<Body>) -> Response<Body> {
+    if let Some(request_id) = request.headers().get("x-request-id") {
+        REQUEST_IDS
+            .lock()
+            .push(request_id.to_str().unwrap().to_owned());
+    }
+
+    let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+
+    let command = match serde_json::from_slice::<Command>(&body) {
+        Ok(command) => command,
+        Err(_) => return json(StatusCode::BAD_REQUEST, json!("Unknown command")),
+    };
+
+    match command {
+        Command::HasBook { book_id } if book_id == FAILED_BOOK_ID => {
+            json(StatusCode::INTERNAL_SERVER_ERROR, json!("Internal"))
+        }
+        Command::HasBook { book_id } => json(StatusCode::OK, json!({ "has": has_book(book_id) })),
+        Command::HasBookTag {
+            book_tag: (_, tag_name),
+        } => json(StatusCode::OK, json!({ "has": has_book_tag(&tag_name) })),
+        Command::HasBooks { book_ids } => {
+            let has = book_ids
+                .into_iter()
+                .filter(|x| has_book(*x))
+                .collect::<HashSet<_>>();
+
+            json(StatusCode::OK, json!({ "has": has }))
+        }
+        Command::HasBookTags { book_tags } => {
+            let has = book_tags
+                .into_iter()
+                .filter(|(_, tag_name)| has_book_tag(tag_name))
+                .collect::<HashSet<_>>();
+
+            json(StatusCode::OK, json!({ "has": has }))
+        }
+    }
+}
+
+/// 쿼리의 배열 표기(`ids[0]=1`, `tags[0][0]=artist`)를 느슨하게 읽음
+fn books(request: Request<Body>) -> Response<Body> {
+    let path = request.uri().path();
+
+    if let Some(book_id) = path
+        .strip_prefix("/books/")
+        .and_then(|x| x.parse::<u32>().ok())
+    {
+        return match has_book(book_id) {
+            true => json(StatusCode::OK, book(book_id)),
+            false => json(StatusCode::NOT_FOUND, json!("Not found book")),
+        };
+    }
+
+    let query = request.uri().query().unwrap_or_default();
+
+    let pairs = querystring::querify(query)
+        .into_iter()
+        .map(|(key, value)| (key.replace("%5B", "[").replace("%5D", "]"), value))
+        .collect::<Vec<_>>();
+
+    let book_ids = pairs
+        .iter()
+        .filter(|(key, _)| key.starts_with("ids"))
+        .filter_map(|(_, value)| value.parse::<u32>().ok())
+        .filter(|x| has_book(*x))
+        .collect::<Vec<_>>();
+
+    if !book_ids.is_empty() {
+        return json(
+            StatusCode::OK,
+            book_ids.into_iter().map(book).collect::<Vec<_>>().into(),
+        );
+    }
+
+    let tag_names = pairs
+        .iter()
+        .filter(|(key, _)| key.starts_with("tags") && key.ends_with("[1]"))
+        .map(|(_, value)| value.replace('-', " "))
+        .collect::<Vec<_>>();
+
+    if !tag_names.is_empty() {
+        let r = tag_names
+            .into_iter()
+            .map(|tag_name| json!([["artist", tag_name], [book(1)]]))
+            .collect::<Vec<_>>();
+
+        return json(StatusCode::OK, r.into());
+    }
+
+    json(StatusCode::OK, json!([book(1)]))
+}
+
+/// `/command`와 작품 정보를 돌려주는 엔드포인트만 흉내냄
+pub async fn library() -> String {
+    serve(|request| async move {
+        let path = request.uri().path().to_owned();
+
+        match (request.method(), path.as_str()) {
+            (&Method::POST, "/command") => command(request).await,
+            (&Method::GET, path) if path.starts_with("/books") => books(request),
+            _ => json(StatusCode::NOT_FOUND, json!("Not found")),
+        }
+    })
+    .await
+}
@@ -0,0 +1,64 @@
+use hyper::{http::StatusCode, Method};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{internal, public, sign_up};
+
+#[tokio::test]
+async fn create_and_get_user() {
+    let user_id = sign_up().await;
+
+    let resp = public(Method::GET, "/users/@me", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.json::<Value>()["id"], user_id.to_string());
+
+    let resp = internal(Method::GET, &format!("/users/{user_id}"), None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn create_user_twice() {
+    let body = json!({
+        "name": "madome",
+        "email": format!("{}@madome.app", Uuid::new_v4()),
+        "role": 0
+    });
+
+    let resp = public(Method::POST, "/users", Uuid::new_v4(), Some(body.clone())).await;
+    assert_eq!(resp.status, StatusCode::CREATED);
+
+    let resp = public(Method::POST, "/users", Uuid::new_v4(), Some(body)).await;
+    assert_eq!(resp.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn create_user_with_invalid_email() {
+    let body = json!({ "name": "madome", "email": "madome", "role": 0 });
+
+    let resp = public(Method::POST, "/users", Uuid::new_v4(), Some(body)).await;
+    assert_eq!(resp.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_not_found_user() {
+    let resp = public(Method::GET, "/users/@me", Uuid::new_v4(), None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+
+    let resp = internal(Method::GET, &format!("/users/{}", Uuid::new_v4()), None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn versioned_path() {
+    let user_id = sign_up().await;
+
+    let resp = public(Method::GET, "/v1/users/@me", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+    assert_eq!(resp.json::<Value>()["id"], user_id.to_string());
+
+    let resp = internal(Method::GET, &format!("/v1/users/{user_id}"), None).await;
+    assert_eq!(resp.status, StatusCode::OK);
+
+    let resp = public(Method::GET, "/v2/users/@me", user_id, None).await;
+    assert_eq!(resp.status, StatusCode::NOT_FOUND);
+}
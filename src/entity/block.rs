@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 차단은 서로 보이지 않게 하고, 뮤트는 알림만 받지 않음
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    Block = 0,
+    Mute = 1,
+}
+
+impl From<u8> for BlockKind {
+    fn from(kind: u8) -> Self {
+        match kind {
+            0 => Self::Block,
+            1 => Self::Mute,
+            _ => panic!(),
+        }
+    }
+}
+
+/// `user_id`가 `target_id`를 차단(뮤트)함
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub user_id: Uuid,
+    pub target_id: Uuid,
+    pub kind: BlockKind,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Block {
+    pub fn new(user_id: Uuid, target_id: Uuid, kind: BlockKind) -> Self {
+        Self {
+            user_id,
+            target_id,
+            kind,
+            created_at: Utc::now(),
+        }
+    }
+}
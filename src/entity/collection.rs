@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{Sort, Visibility};
+
+#[derive(Debug, Clone, Copy)]
+pub enum CollectionSortBy {
+    CreatedAt(Sort),
+    UpdatedAt(Sort),
+}
+
+impl Default for CollectionSortBy {
+    fn default() -> Self {
+        Self::UpdatedAt(Sort::Desc)
+    }
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct Collection {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub visibility: Visibility,
+    /// `Private`일 때는 항상 `None`
+    pub share_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Collection {
+    pub fn new(user_id: Uuid, name: String) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            visibility: Visibility::Private,
+            share_token: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct CollectionItem {
+    pub collection_id: Uuid,
+    pub book_id: u32,
+    /// 1부터 시작함, 추가할 때는 repository에서 마지막 순서로 정해줌
+    pub position: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CollectionItem {
+    pub fn new(collection_id: Uuid, book_id: u32) -> Self {
+        Self {
+            collection_id,
+            book_id,
+            position: 0,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::Sort;
+
+#[derive(Debug, Clone, Copy)]
+pub enum FollowSortBy {
+    CreatedAt(Sort),
+}
+
+impl Default for FollowSortBy {
+    fn default() -> Self {
+        Self::CreatedAt(Sort::Desc)
+    }
+}
+
+/// `follower_id`가 `followee_id`를 팔로우함
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct Follow {
+    pub follower_id: Uuid,
+    pub followee_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Follow {
+    pub fn new(follower_id: Uuid, followee_id: Uuid) -> Self {
+        Self {
+            follower_id,
+            followee_id,
+            created_at: Utc::now(),
+        }
+    }
+}
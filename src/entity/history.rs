@@ -12,10 +12,32 @@ pub enum HistoryKind {
 pub enum HistorySortBy {
     CreatedAt(Sort),
     UpdatedAt(Sort),
+    Title(Sort),
+    PublishedAt(Sort),
+    /// 작품을 좋아요한 사용자 수
+    Popularity(Sort),
     Random,
 }
 
-#[derive(Debug)]
+impl HistorySortBy {
+    /// 저장해둔 작품 정보로 정렬하는지
+    pub fn by_book(&self) -> bool {
+        matches!(
+            self,
+            Self::Title(_) | Self::PublishedAt(_) | Self::Popularity(_)
+        )
+    }
+}
+
+/// 조건이 없으면 거르지 않음
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
 pub enum History {
     Book {
         book_id: u32,
@@ -60,4 +82,11 @@ impl History {
             Self::Book { created_at, .. } => *created_at,
         }
     }
+
+    /// history.updated_at
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::Book { updated_at, .. } => *updated_at,
+        }
+    }
 }
@@ -12,15 +12,48 @@ pub enum LikeKind {
 #[derive(Debug, Clone, Copy)]
 pub enum LikeSortBy {
     CreatedAt(Sort),
+    /// 작품 태그 좋아요는 태그 이름으로 정렬됨
+    Title(Sort),
+    PublishedAt(Sort),
+    /// 작품을 좋아요한 사용자 수
+    Popularity(Sort),
     Random,
 }
 
+impl LikeSortBy {
+    /// 저장해둔 작품 정보로 정렬하는지
+    pub fn by_book(&self) -> bool {
+        matches!(
+            self,
+            Self::Title(_) | Self::PublishedAt(_) | Self::Popularity(_)
+        )
+    }
+}
+
 impl Default for LikeSortBy {
     fn default() -> Self {
         Self::CreatedAt(Sort::Desc)
     }
 }
 
+/// 조건이 없으면 거르지 않음
+#[derive(Debug, Clone, Default)]
+pub struct LikeFilter {
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    /// 작품 태그 좋아요에만 해당함
+    pub tag_kind: Option<String>,
+    /// 작품 태그 좋아요에만 해당함
+    pub tag_name: Option<String>,
+}
+
+impl LikeFilter {
+    /// 태그 조건이 있으면 작품 좋아요는 걸러짐
+    pub fn has_tag(&self) -> bool {
+        self.tag_kind.is_some() || self.tag_name.is_some()
+    }
+}
+
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug, Clone)]
 pub enum Like {
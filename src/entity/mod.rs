@@ -1,18 +1,85 @@
+pub mod block;
+pub mod collection;
 pub mod dislike;
 pub mod fcm_token;
+pub mod follow;
 pub mod history;
 pub mod like;
 pub mod notification;
+pub mod profile;
+pub mod rating;
+pub mod related_book;
+pub mod search;
 pub mod user;
 
+pub use block::{Block, BlockKind};
+pub use collection::{Collection, CollectionItem, CollectionSortBy};
 pub use dislike::{Dislike, DislikeKind, DislikeSortBy};
-pub use history::{History, HistoryKind, HistorySortBy};
-pub use like::{Like, LikeKind, LikeSortBy};
+pub use follow::{Follow, FollowSortBy};
+pub use history::{History, HistoryFilter, HistoryKind, HistorySortBy};
+pub use like::{Like, LikeFilter, LikeKind, LikeSortBy};
 pub use notification::{Notification, NotificationKind, NotificationSortBy};
+pub use profile::Profile;
+pub use rating::{Rating, RatingAggregate, RatingSortBy};
+pub use related_book::RelatedBook;
+pub use search::{BookTitle, SearchResult};
 pub use user::{User, UserRole};
 
+use uuid::Uuid;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Sort {
     Desc,
     Asc,
 }
+
+impl Sort {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Desc => "DESC",
+            Self::Asc => "ASC",
+        }
+    }
+}
+
+/// 컬렉션, 프로필의 공개 범위
+///
+/// `Unlisted`는 공유 토큰을 아는 사람만 볼 수 있고,
+/// `Public`은 공개 프로필에도 노출됨
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Private = 0,
+    Unlisted = 1,
+    Public = 2,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self::Private
+    }
+}
+
+impl From<u8> for Visibility {
+    fn from(visibility: u8) -> Self {
+        match visibility {
+            0 => Self::Private,
+            1 => Self::Unlisted,
+            2 => Self::Public,
+            _ => panic!(),
+        }
+    }
+}
+
+impl Visibility {
+    /// 공개 범위에 맞는 공유 토큰
+    ///
+    /// `Private`이면 토큰을 없애고, 아니면 기존 토큰을 유지하거나 새로 발급함
+    pub fn share_token(self, current: Option<String>) -> Option<String> {
+        match self {
+            Self::Private => None,
+            Self::Unlisted | Self::Public => {
+                Some(current.unwrap_or_else(|| Uuid::new_v4().to_simple().to_string()))
+            }
+        }
+    }
+}
@@ -6,6 +6,8 @@ use super::Sort;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NotificationKind {
     Book,
+    User,
+    BookSeries,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -22,6 +24,21 @@ pub enum Notification {
         user_id: Uuid,
         created_at: DateTime<Utc>,
     },
+    /// 읽었던 작품과 같은 시리즈의 작품이 올라옴
+    BookSeries {
+        book_id: u32,
+        series_name: String,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+    },
+    /// 팔로우한 사용자가 작품을 좋아함
+    User {
+        /// 팔로우한 사용자
+        followee_id: Uuid,
+        book_id: u32,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+    },
 }
 
 impl Notification {
@@ -34,21 +51,45 @@ impl Notification {
         }
     }
 
+    pub fn book_series(user_id: Uuid, book_id: u32, series_name: String) -> Self {
+        Self::BookSeries {
+            user_id,
+            book_id,
+            series_name,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn user(user_id: Uuid, followee_id: Uuid, book_id: u32) -> Self {
+        Self::User {
+            user_id,
+            followee_id,
+            book_id,
+            created_at: Utc::now(),
+        }
+    }
+
     pub fn kind(&self) -> NotificationKind {
         match self {
             Self::Book { .. } => NotificationKind::Book,
+            Self::BookSeries { .. } => NotificationKind::BookSeries,
+            Self::User { .. } => NotificationKind::User,
         }
     }
 
     pub fn user_id(&self) -> Uuid {
         match self {
-            Self::Book { user_id, .. } => *user_id,
+            Self::Book { user_id, .. }
+            | Self::BookSeries { user_id, .. }
+            | Self::User { user_id, .. } => *user_id,
         }
     }
 
     pub fn created_at(&self) -> DateTime<Utc> {
         match self {
-            Self::Book { created_at, .. } => *created_at,
+            Self::Book { created_at, .. }
+            | Self::BookSeries { created_at, .. }
+            | Self::User { created_at, .. } => *created_at,
         }
     }
 }
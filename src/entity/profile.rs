@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::Visibility;
+
+/// 좋아요 목록의 공개 설정
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub user_id: Uuid,
+    pub visibility: Visibility,
+    /// `Private`일 때는 항상 `None`
+    pub share_token: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Profile {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            visibility: Visibility::Private,
+            share_token: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::Sort;
+
+#[derive(Debug, Clone, Copy)]
+pub enum RatingSortBy {
+    CreatedAt(Sort),
+    UpdatedAt(Sort),
+    Score(Sort),
+}
+
+impl Default for RatingSortBy {
+    fn default() -> Self {
+        Self::UpdatedAt(Sort::Desc)
+    }
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct Rating {
+    pub user_id: Uuid,
+    pub book_id: u32,
+    /// 1 ~ 5
+    pub score: u8,
+    /// 본인만 볼 수 있음
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Rating {
+    pub fn new(user_id: Uuid, book_id: u32, score: u8, note: Option<String>) -> Self {
+        let now = Utc::now();
+
+        Self {
+            user_id,
+            book_id,
+            score,
+            note,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// 작품별 평점 통계
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct RatingAggregate {
+    pub book_id: u32,
+    pub average: f64,
+    pub count: usize,
+}
@@ -0,0 +1,9 @@
+/// 같이 좋아요를 받은 작품
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct RelatedBook {
+    pub book_id: u32,
+    pub related_book_id: u32,
+    /// 두 작품을 모두 좋아요한 사용자 수
+    pub support: usize,
+}
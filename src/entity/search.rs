@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Library에서 알림으로 받은 작품 제목과 올라온 날짜
+///
+/// 검색하거나 정렬할 때 Library에 요청하지 않으려고 따로 저장해둠
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub struct BookTitle {
+    pub book_id: u32,
+    pub title: String,
+    /// 없으면 처음 저장될 때의 시각을 씀
+    pub published_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BookTitle {
+    pub fn new(book_id: u32, title: String, published_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            book_id,
+            title,
+            published_at,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    LikeBook {
+        book_id: u32,
+        title: String,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+    },
+    LikeBookTag {
+        tag_kind: String,
+        tag_name: String,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+    },
+    History {
+        book_id: u32,
+        title: String,
+        user_id: Uuid,
+        updated_at: DateTime<Utc>,
+    },
+    Notification {
+        book_id: u32,
+        title: String,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+    },
+}
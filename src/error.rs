@@ -1,17 +1,30 @@
 use std::sync::Arc;
 
-use hyper::{Body, Request, Response, StatusCode};
+use hyper::{header, Body, Request, Response, StatusCode};
+use itertools::Itertools;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Value};
 use util::{body_parser, http::SetResponse};
 
 use crate::{
-    command::{has_book, has_book_tag},
+    command::{
+        get_books_by_ids, get_books_by_tags, has_book, has_book_tag, has_book_tags, has_books,
+        CommandSet,
+    },
     config::Config,
     model::Presenter,
-    payload,
+    payload, telemetry,
     usecase::{
-        create_like, create_notifications, create_or_update_fcm_token, create_or_update_history,
-        create_user, delete_history, delete_like, get_fcm_tokens, get_histories, get_histories_by,
-        get_likes, get_likes_by, get_notifications, get_user,
+        add_collection_item, create_block, create_collection, create_like, create_notifications,
+        create_or_update_fcm_token, create_or_update_history, create_or_update_rating, create_user,
+        delete_block, delete_collection, delete_collection_item, delete_history, delete_like,
+        delete_rating, follow_user, get_collection, get_collections, get_fcm_tokens, get_followers,
+        get_following, get_histories, get_histories_by, get_likes, get_likes_by, get_notifications,
+        get_rating_aggregates, get_ratings, get_recommendations, get_related_books,
+        get_search_results, get_shared, get_user, invalidate_library_cache,
+        reorder_collection_items, unfollow_user, update_collection, update_collection_visibility,
+        update_profile_visibility,
     },
 };
 
@@ -40,6 +53,13 @@ pub enum Error {
     ReadChunksFromBody(#[from] hyper::Error),
 }
 
+impl Error {
+    /// Library에 요청하다가 실패함
+    pub fn is_library_error(&self) -> bool {
+        matches!(self, Self::Command(_) | Self::LibrarySdk(_))
+    }
+}
+
 impl From<body_parser::Error> for Error {
     fn from(err: body_parser::Error) -> Self {
         match err {
@@ -81,6 +101,21 @@ pub enum CommandError {
 
     #[error("HasBookTag: {0}")]
     HasBookTag(#[from] has_book_tag::Error),
+
+    #[error("HasBooks: {0}")]
+    HasBooks(#[from] has_books::Error),
+
+    #[error("HasBookTags: {0}")]
+    HasBookTags(#[from] has_book_tags::Error),
+
+    #[error("GetBooksByIds: {0}")]
+    GetBooksByIds(#[from] get_books_by_ids::Error),
+
+    #[error("GetBooksByTags: {0}")]
+    GetBooksByTags(#[from] get_books_by_tags::Error),
+
+    #[error("Library is unavailable")]
+    LibraryUnavailable,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -119,111 +154,511 @@ pub enum UseCaseError {
     GetHistoriesBy(#[from] get_histories_by::Error),
     #[error("DeleteHistory: {0}")]
     DeleteHistory(#[from] delete_history::Error),
+
+    #[error("CreateCollection: {0}")]
+    CreateCollection(#[from] create_collection::Error),
+    #[error("GetCollections: {0}")]
+    GetCollections(#[from] get_collections::Error),
+    #[error("GetCollection: {0}")]
+    GetCollection(#[from] get_collection::Error),
+    #[error("UpdateCollection: {0}")]
+    UpdateCollection(#[from] update_collection::Error),
+    #[error("DeleteCollection: {0}")]
+    DeleteCollection(#[from] delete_collection::Error),
+    #[error("AddCollectionItem: {0}")]
+    AddCollectionItem(#[from] add_collection_item::Error),
+    #[error("DeleteCollectionItem: {0}")]
+    DeleteCollectionItem(#[from] delete_collection_item::Error),
+    #[error("ReorderCollectionItems: {0}")]
+    ReorderCollectionItems(#[from] reorder_collection_items::Error),
+
+    #[error("UpdateCollectionVisibility: {0}")]
+    UpdateCollectionVisibility(#[from] update_collection_visibility::Error),
+    #[error("UpdateProfileVisibility: {0}")]
+    UpdateProfileVisibility(#[from] update_profile_visibility::Error),
+    #[error("GetShared: {0}")]
+    GetShared(#[from] get_shared::Error),
+
+    #[error("CreateOrUpdateRating: {0}")]
+    CreateOrUpdateRating(#[from] create_or_update_rating::Error),
+    #[error("GetRatings: {0}")]
+    GetRatings(#[from] get_ratings::Error),
+    #[error("DeleteRating: {0}")]
+    DeleteRating(#[from] delete_rating::Error),
+    #[error("GetRatingAggregates: {0}")]
+    GetRatingAggregates(#[from] get_rating_aggregates::Error),
+
+    #[error("GetRelatedBooks: {0}")]
+    GetRelatedBooks(#[from] get_related_books::Error),
+
+    #[error("GetRecommendations: {0}")]
+    GetRecommendations(#[from] get_recommendations::Error),
+
+    #[error("FollowUser: {0}")]
+    FollowUser(#[from] follow_user::Error),
+    #[error("UnfollowUser: {0}")]
+    UnfollowUser(#[from] unfollow_user::Error),
+    #[error("GetFollowers: {0}")]
+    GetFollowers(#[from] get_followers::Error),
+    #[error("GetFollowing: {0}")]
+    GetFollowing(#[from] get_following::Error),
+
+    #[error("CreateBlock: {0}")]
+    CreateBlock(#[from] create_block::Error),
+    #[error("DeleteBlock: {0}")]
+    DeleteBlock(#[from] delete_block::Error),
+
+    #[error("GetSearchResults: {0}")]
+    GetSearchResults(#[from] get_search_results::Error),
+
+    #[error("InvalidateLibraryCache: {0}")]
+    InvalidateLibraryCache(#[from] invalidate_library_cache::Error),
 }
 
-#[async_trait::async_trait]
-impl Presenter for Error {
-    async fn set_response(
-        self,
-        _request: &mut Request<Body>,
-        resp: &mut Response<Body>,
-        _config: Arc<Config>,
-    ) -> crate::Result<()> {
+impl UseCaseError {
+    /// 클라이언트가 분기에 쓰는 코드
+    ///
+    /// 한 번 정한 코드는 바꾸지 않음
+    pub fn code(&self) -> &'static str {
+        use UseCaseError::*;
+
+        match self {
+            GetUser(err) => match err {
+                get_user::Error::NotFoundUser => "not_found_user",
+            },
+            CreateUser(err) => match err {
+                create_user::Error::InvalidName(_) => "invalid_name",
+                create_user::Error::InvalidEmail(_) => "invalid_email",
+                create_user::Error::InvalidRole(_) => "invalid_role",
+                create_user::Error::AlreadyExistsUser => "already_exists_user",
+            },
+            GetLikes(err) => match *err {},
+            GetLikesBy(err) => match *err {},
+            CreateLike(err) => match err {
+                create_like::Error::AlreadyExistsLike => "already_exists_like",
+                create_like::Error::NotFoundBook => "not_found_book",
+                create_like::Error::NotFoundBookTag => "not_found_book_tag",
+            },
+            DeleteLike(err) => match err {
+                delete_like::Error::NotFoundLike => "not_found_like",
+            },
+            CreateNotifications(err) => match *err {},
+            GetNotifications(err) => match *err {},
+            CreateOrUpdateFcmToken(err) => match *err {},
+            GetFcmTokens(err) => match *err {},
+            CreateOrUpdateHistory(err) => match err {
+                create_or_update_history::Error::NotFoundBook => "not_found_book",
+            },
+            GetHistories(err) => match *err {},
+            GetHistoriesBy(err) => match *err {},
+            DeleteHistory(err) => match err {
+                delete_history::Error::NotFoundHistory => "not_found_history",
+            },
+            CreateCollection(err) => match err {
+                create_collection::Error::InvalidName(_) => "invalid_name",
+                create_collection::Error::AlreadyExistsCollection => "already_exists_collection",
+            },
+            GetCollections(err) => match *err {},
+            GetCollection(err) => match err {
+                get_collection::Error::NotFoundCollection => "not_found_collection",
+            },
+            UpdateCollection(err) => match err {
+                update_collection::Error::InvalidName(_) => "invalid_name",
+                update_collection::Error::NotFoundCollection => "not_found_collection",
+                update_collection::Error::AlreadyExistsCollection => "already_exists_collection",
+            },
+            DeleteCollection(err) => match err {
+                delete_collection::Error::NotFoundCollection => "not_found_collection",
+            },
+            AddCollectionItem(err) => match err {
+                add_collection_item::Error::NotFoundCollection => "not_found_collection",
+                add_collection_item::Error::NotFoundBook => "not_found_book",
+                add_collection_item::Error::AlreadyExistsCollectionItem => {
+                    "already_exists_collection_item"
+                }
+            },
+            DeleteCollectionItem(err) => match err {
+                delete_collection_item::Error::NotFoundCollection => "not_found_collection",
+                delete_collection_item::Error::NotFoundCollectionItem => {
+                    "not_found_collection_item"
+                }
+            },
+            ReorderCollectionItems(err) => match err {
+                reorder_collection_items::Error::NotFoundCollection => "not_found_collection",
+                reorder_collection_items::Error::MismatchedItems => "mismatched_items",
+            },
+            UpdateCollectionVisibility(err) => match err {
+                update_collection_visibility::Error::NotFoundCollection => "not_found_collection",
+            },
+            UpdateProfileVisibility(err) => match *err {},
+            GetShared(err) => match err {
+                get_shared::Error::NotFoundShared => "not_found_shared",
+            },
+            CreateOrUpdateRating(err) => match err {
+                create_or_update_rating::Error::InvalidScore(_) => "invalid_score",
+                create_or_update_rating::Error::InvalidNote(_) => "invalid_note",
+                create_or_update_rating::Error::NotFoundBook => "not_found_book",
+            },
+            GetRatings(err) => match *err {},
+            DeleteRating(err) => match err {
+                delete_rating::Error::NotFoundRating => "not_found_rating",
+            },
+            GetRatingAggregates(err) => match *err {},
+            GetRelatedBooks(err) => match *err {},
+            GetRecommendations(err) => match *err {},
+            FollowUser(err) => match err {
+                follow_user::Error::CannotFollowYourself => "cannot_follow_yourself",
+                follow_user::Error::NotFoundUser => "not_found_user",
+                follow_user::Error::AlreadyExistsFollow => "already_exists_follow",
+            },
+            UnfollowUser(err) => match err {
+                unfollow_user::Error::NotFoundFollow => "not_found_follow",
+            },
+            GetFollowers(err) => match *err {},
+            GetFollowing(err) => match *err {},
+            CreateBlock(err) => match err {
+                create_block::Error::CannotBlockYourself => "cannot_block_yourself",
+                create_block::Error::NotFoundUser => "not_found_user",
+                create_block::Error::AlreadyExistsBlock => "already_exists_block",
+            },
+            DeleteBlock(err) => match err {
+                delete_block::Error::NotFoundBlock => "not_found_block",
+            },
+            GetSearchResults(err) => match err {
+                get_search_results::Error::InvalidQuery(_) => "invalid_query",
+            },
+            InvalidateLibraryCache(err) => match *err {},
+        }
+    }
+}
+
+impl CommandError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::HasBook(_) => "has_book_failed",
+            Self::HasBookTag(_) => "has_book_tag_failed",
+            Self::HasBooks(_) => "has_books_failed",
+            Self::HasBookTags(_) => "has_book_tags_failed",
+            Self::GetBooksByIds(_) => "get_books_by_ids_failed",
+            Self::GetBooksByTags(_) => "get_books_by_tags_failed",
+            Self::LibraryUnavailable => "library_unavailable",
+        }
+    }
+}
+
+/// 에러 응답의 body
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct ErrorBody {
+    pub code: &'static str,
+    pub message: String,
+    pub details: Option<Value>,
+    /// 요청 아이디, 로그에서 이 에러를 찾을 때 씀
+    pub correlation_id: String,
+}
+
+/// 5xx에서 내부 사정 대신 보내는 메시지
+const INTERNAL_MESSAGE: &str = "Internal server error";
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        use madome_sdk::api::{auth::Error as AuthError, BaseError};
+
+        match self {
+            Self::Msg(MsgError::NotFound) => "not_found",
+            Self::Msg(MsgError::MethodNotAllowed(_)) => "method_not_allowed",
+            Self::Msg(MsgError::Unauthorized) => "unauthorized",
+            Self::Command(err) => err.code(),
+            Self::UseCase(err) => err.code(),
+            Self::Payload(err) => err.code(),
+            Self::AuthSdk(AuthError::Base(BaseError::Unauthorized)) => "unauthorized",
+            Self::AuthSdk(AuthError::Base(BaseError::PermissionDenied)) => "permission_denied",
+            Self::AuthSdk(_) => "auth_failed",
+            Self::LibrarySdk(_) => "library_failed",
+            Self::Repository(_) | Self::ReadChunksFromBody(_) => "internal",
+        }
+    }
+
+    /// 상태 코드와 메시지
+    ///
+    /// 메시지는 JSON을 받지 않는 예전 클라이언트에게 그대로 보내므로 바꾸지 않음
+    fn status_and_message(&self) -> (StatusCode, String) {
         use crate::msg::Error::*;
+        use add_collection_item::Error::*;
         use create_like::Error::*;
         use create_user::Error::*;
+        use delete_collection_item::Error::*;
         use delete_history::Error::*;
         use delete_like::Error::*;
         use get_user::Error::*;
+        use reorder_collection_items::Error::*;
         use Error::*;
         use UseCaseError::*;
 
         match self {
-            Msg(NotFound) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body("Not found".into());
-            }
+            Msg(NotFound) => (StatusCode::NOT_FOUND, "Not found".to_string()),
+            Msg(MethodNotAllowed(_)) => (
+                StatusCode::METHOD_NOT_ALLOWED,
+                "Method not allowed".to_string(),
+            ),
+            Msg(err @ Unauthorized) => (StatusCode::UNAUTHORIZED, err.to_string()),
 
-            Payload(err) => {
-                resp.set_status(StatusCode::BAD_REQUEST).unwrap();
-                resp.set_body(err.to_string().into());
-            }
+            Payload(err) => (StatusCode::BAD_REQUEST, err.to_string()),
 
             UseCase(CreateUser(
                 err @ InvalidName(_) | err @ InvalidEmail(_) | err @ InvalidRole(_),
-            )) => {
-                resp.set_status(StatusCode::BAD_REQUEST).unwrap();
-                resp.set_body(err.to_string().into());
-            }
+            )) => (StatusCode::BAD_REQUEST, err.to_string()),
 
             UseCase(CreateUser(AlreadyExistsUser)) => {
-                resp.set_status(StatusCode::CONFLICT).unwrap();
-                resp.set_body("Already exist user".into());
-            }
-            UseCase(GetUser(NotFoundUser)) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body("Not found user".into());
+                (StatusCode::CONFLICT, "Already exist user".to_string())
             }
+            UseCase(GetUser(NotFoundUser)) => (StatusCode::NOT_FOUND, "Not found user".to_string()),
 
-            UseCase(CreateLike(err @ AlreadyExistsLike)) => {
-                resp.set_status(StatusCode::CONFLICT).unwrap();
-                resp.set_body(err.to_string().into());
-            }
+            UseCase(CreateLike(err @ AlreadyExistsLike)) => (StatusCode::CONFLICT, err.to_string()),
             UseCase(CreateLike(
                 err @ create_like::Error::NotFoundBook | err @ create_like::Error::NotFoundBookTag,
-            )) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body(err.to_string().into());
-            }
-            UseCase(DeleteLike(err @ NotFoundLike)) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body(err.to_string().into());
-            }
+            )) => (StatusCode::NOT_FOUND, err.to_string()),
+            UseCase(DeleteLike(err @ NotFoundLike)) => (StatusCode::NOT_FOUND, err.to_string()),
 
             UseCase(CreateOrUpdateHistory(err @ create_or_update_history::Error::NotFoundBook)) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body(err.to_string().into());
+                (StatusCode::NOT_FOUND, err.to_string())
             }
             UseCase(DeleteHistory(err @ NotFoundHistory)) => {
-                resp.set_status(StatusCode::NOT_FOUND).unwrap();
-                resp.set_body(err.to_string().into())
+                (StatusCode::NOT_FOUND, err.to_string())
             }
 
-            AuthSdk(ref err) => {
+            UseCase(CreateCollection(err @ create_collection::Error::InvalidName(_))) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            UseCase(CreateCollection(err @ create_collection::Error::AlreadyExistsCollection)) => {
+                (StatusCode::CONFLICT, err.to_string())
+            }
+            UseCase(GetCollection(err @ get_collection::Error::NotFoundCollection)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(UpdateCollection(err @ update_collection::Error::InvalidName(_))) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            UseCase(UpdateCollection(err @ update_collection::Error::NotFoundCollection)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(UpdateCollection(err @ update_collection::Error::AlreadyExistsCollection)) => {
+                (StatusCode::CONFLICT, err.to_string())
+            }
+            UseCase(DeleteCollection(err @ delete_collection::Error::NotFoundCollection)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(AddCollectionItem(err @ AlreadyExistsCollectionItem)) => {
+                (StatusCode::CONFLICT, err.to_string())
+            }
+            UseCase(AddCollectionItem(
+                err @ add_collection_item::Error::NotFoundCollection
+                | err @ add_collection_item::Error::NotFoundBook,
+            )) => (StatusCode::NOT_FOUND, err.to_string()),
+            UseCase(DeleteCollectionItem(
+                err @ delete_collection_item::Error::NotFoundCollection
+                | err @ NotFoundCollectionItem,
+            )) => (StatusCode::NOT_FOUND, err.to_string()),
+            UseCase(ReorderCollectionItems(err @ MismatchedItems)) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            UseCase(ReorderCollectionItems(
+                err @ reorder_collection_items::Error::NotFoundCollection,
+            )) => (StatusCode::NOT_FOUND, err.to_string()),
+
+            UseCase(UpdateCollectionVisibility(
+                err @ update_collection_visibility::Error::NotFoundCollection,
+            )) => (StatusCode::NOT_FOUND, err.to_string()),
+            UseCase(GetShared(err @ get_shared::Error::NotFoundShared)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+
+            UseCase(CreateOrUpdateRating(
+                err @ create_or_update_rating::Error::InvalidScore(_)
+                | err @ create_or_update_rating::Error::InvalidNote(_),
+            )) => (StatusCode::BAD_REQUEST, err.to_string()),
+            UseCase(CreateOrUpdateRating(err @ create_or_update_rating::Error::NotFoundBook)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(DeleteRating(err @ delete_rating::Error::NotFoundRating)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+
+            UseCase(FollowUser(err @ follow_user::Error::CannotFollowYourself)) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            UseCase(FollowUser(err @ follow_user::Error::NotFoundUser)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(FollowUser(err @ follow_user::Error::AlreadyExistsFollow)) => {
+                (StatusCode::CONFLICT, err.to_string())
+            }
+            UseCase(UnfollowUser(err @ unfollow_user::Error::NotFoundFollow)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+
+            UseCase(CreateBlock(err @ create_block::Error::CannotBlockYourself)) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            UseCase(CreateBlock(err @ create_block::Error::NotFoundUser)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+            UseCase(CreateBlock(err @ create_block::Error::AlreadyExistsBlock)) => {
+                (StatusCode::CONFLICT, err.to_string())
+            }
+            UseCase(DeleteBlock(err @ delete_block::Error::NotFoundBlock)) => {
+                (StatusCode::NOT_FOUND, err.to_string())
+            }
+
+            UseCase(GetSearchResults(err @ get_search_results::Error::InvalidQuery(_))) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+
+            Command(err @ CommandError::LibraryUnavailable) => {
+                (StatusCode::SERVICE_UNAVAILABLE, err.to_string())
+            }
+
+            AuthSdk(err) => {
                 use madome_sdk::api::{auth::Error as AuthError, BaseError};
 
                 match err {
                     AuthError::Base(err) => match err {
                         err @ BaseError::Unauthorized => {
-                            resp.set_status(StatusCode::UNAUTHORIZED).unwrap();
-                            resp.set_body(err.to_string().into());
+                            (StatusCode::UNAUTHORIZED, err.to_string())
                         }
                         err @ BaseError::PermissionDenied => {
-                            resp.set_status(StatusCode::FORBIDDEN).unwrap();
-                            resp.set_body(err.to_string().into());
-                        }
-                        BaseError::Undefined(code, body) => {
-                            resp.set_status(code).unwrap();
-                            resp.set_body(body.to_owned().into());
-                        }
-                        _ => {
-                            resp.set_status(StatusCode::INTERNAL_SERVER_ERROR).unwrap();
-                            resp.set_body(err.to_string().into());
+                            (StatusCode::FORBIDDEN, err.to_string())
                         }
+                        BaseError::Undefined(code, body) => (*code, body.to_owned()),
+                        _ => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
                     },
-                    _ => {
-                        resp.set_status(StatusCode::INTERNAL_SERVER_ERROR).unwrap();
-                        resp.set_body(err.to_string().into());
-                    }
+                    _ => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
                 }
             }
 
-            err => {
-                resp.set_status(StatusCode::INTERNAL_SERVER_ERROR).unwrap();
-                resp.set_body(err.to_string().into());
+            err => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        }
+    }
+
+    /// 어느 필드가 잘못되었는지처럼 메시지만으로는 알기 어려운 정보
+    fn details(&self, config: &Config) -> Option<Value> {
+        use UseCaseError::*;
+
+        let field = match self {
+            Self::Msg(MsgError::MethodNotAllowed(allow)) => {
+                let allow = allow.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+
+                return Some(json!({ "allow": allow }));
+            }
+            Self::Command(CommandError::LibraryUnavailable) => {
+                let retry_after = config.library_circuit_open().as_secs();
+
+                return Some(json!({ "retry_after": retry_after }));
+            }
+            Self::Payload(err) => err.field(),
+            Self::UseCase(CreateUser(create_user::Error::InvalidName(_))) => Some("name"),
+            Self::UseCase(CreateUser(create_user::Error::InvalidEmail(_))) => Some("email"),
+            Self::UseCase(CreateUser(create_user::Error::InvalidRole(_))) => Some("role"),
+            Self::UseCase(CreateCollection(create_collection::Error::InvalidName(_))) => {
+                Some("name")
             }
+            Self::UseCase(UpdateCollection(update_collection::Error::InvalidName(_))) => {
+                Some("name")
+            }
+            Self::UseCase(CreateOrUpdateRating(create_or_update_rating::Error::InvalidScore(
+                _,
+            ))) => Some("score"),
+            Self::UseCase(CreateOrUpdateRating(create_or_update_rating::Error::InvalidNote(_))) => {
+                Some("note")
+            }
+            Self::UseCase(GetSearchResults(get_search_results::Error::InvalidQuery(_))) => {
+                Some("q")
+            }
+            _ => None,
+        };
+
+        field.map(|field| json!({ "field": field }))
+    }
+}
+
+/// `Accept`에 JSON 없이 `text/plain`만 있으면 예전처럼 메시지만 보냄
+fn accepts_plain_text(request: &Request<Body>) -> bool {
+    let media_ranges = request
+        .headers()
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .flat_map(|x| x.split(','))
+        .map(|x| {
+            x.split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase()
+        })
+        .collect::<Vec<_>>();
+
+    let has = |xs: &[&str]| media_ranges.iter().any(|x| xs.contains(&x.as_str()));
+
+    has(&["text/plain", "text/*"]) && !has(&["application/json", "application/*"])
+}
+
+#[async_trait::async_trait]
+impl Presenter for Error {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let correlation_id = telemetry::request_id().unwrap_or_default();
+        let (status, message) = self.status_and_message();
+
+        let (message, details) = match status.is_server_error() {
+            // Library가 응답하지 않는다는 것은 알려줘야 다시 시도함
+            // 에러는 요청 아이디와 함께 로그에 남아 있음
+            true if !matches!(self, Self::Command(CommandError::LibraryUnavailable)) => {
+                (INTERNAL_MESSAGE.to_string(), None)
+            }
+            _ => (message, self.details(&config)),
         };
 
+        match &self {
+            Self::Msg(MsgError::MethodNotAllowed(allow)) => {
+                let allow = allow.iter().map(|x| x.as_str()).join(", ");
+
+                resp.headers_mut()
+                    .insert(header::ALLOW, allow.parse().unwrap());
+            }
+            Self::Command(CommandError::LibraryUnavailable) => {
+                let retry_after = config.library_circuit_open().as_secs();
+
+                resp.headers_mut()
+                    .insert(header::RETRY_AFTER, retry_after.into());
+            }
+            _ => {}
+        }
+
+        resp.set_status(status).unwrap();
+
+        if accepts_plain_text(request) {
+            resp.set_header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                .unwrap();
+            resp.set_body(message.into());
+        } else {
+            let serialized = serde_json::to_vec(&ErrorBody {
+                code: self.code(),
+                message,
+                details,
+                correlation_id,
+            })
+            .expect("json serialize");
+
+            resp.set_header(header::CONTENT_TYPE, "application/json")
+                .unwrap();
+            resp.set_body(serialized.into());
+        }
+
         Ok(())
     }
 }
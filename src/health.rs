@@ -0,0 +1,133 @@
+//! `/readyz`에서 확인하는 의존성
+//!
+//! `/healthz`는 프로세스가 응답하는지만 보므로 아무것도 확인하지 않음
+
+use std::time::Instant;
+
+use futures::future::{join_all, BoxFuture, FutureExt};
+use sai::{Component, ComponentLifecycle, Injected};
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::database::Migrator;
+use crate::repository::Backend;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(rename = "HealthStatus")]
+pub enum Status {
+    Ok,
+    Unavailable,
+}
+
+/// 의존성 하나의 상태
+#[derive(Debug, Serialize, JsonSchema)]
+#[schemars(rename = "HealthCheck")]
+pub struct Check {
+    pub status: Status,
+    pub latency_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Component)]
+#[lifecycle]
+pub struct Health<B: Backend> {
+    #[injected]
+    config: Injected<Config>,
+
+    #[injected]
+    database: Injected<B::Database>,
+
+    http: Option<reqwest::Client>,
+}
+
+#[async_trait::async_trait]
+impl<B: Backend> ComponentLifecycle for Health<B> {
+    async fn start(&mut self) {
+        let http = reqwest::Client::builder()
+            .timeout(self.config.health_check_timeout())
+            .build()
+            .expect("build health check http client");
+
+        self.http.replace(http);
+    }
+}
+
+impl<B: Backend> Health<B> {
+    fn http(&self) -> &reqwest::Client {
+        self.http.as_ref().unwrap()
+    }
+
+    /// 모든 의존성을 동시에 확인함
+    ///
+    /// Library와 Auth는 `HEALTH_CHECK_UPSTREAM`이 켜져 있을 때만 확인함,
+    /// 켜두면 Library나 Auth가 죽었을 때 모든 pod이 준비되지 않은 상태가 됨
+    pub async fn check(&self) -> Vec<(&'static str, Check)> {
+        let mut checks: Vec<(&'static str, BoxFuture<'_, Result<(), String>>)> = Vec::new();
+
+        checks.push(("postgres", self.postgres().boxed()));
+        checks.push(("migration", self.migration().boxed()));
+
+        if self.config.health_check_upstream() {
+            checks.push(("library", self.reachable(self.config.library_url()).boxed()));
+            checks.push(("auth", self.reachable(self.config.auth_url()).boxed()));
+        }
+
+        let timeout = self.config.health_check_timeout();
+
+        join_all(checks.into_iter().map(|(name, check)| async move {
+            let start = Instant::now();
+            let r = tokio::time::timeout(timeout, check)
+                .await
+                .unwrap_or_else(|_| Err("Timeout".to_string()));
+            let latency_ms = start.elapsed().as_micros() as f64 / 1000.0;
+
+            let check = match r {
+                Ok(_) => Check {
+                    status: Status::Ok,
+                    latency_ms,
+                    error: None,
+                },
+                Err(err) => Check {
+                    status: Status::Unavailable,
+                    latency_ms,
+                    error: Some(err),
+                },
+            };
+
+            (name, check)
+        }))
+        .await
+    }
+
+    /// 커넥션 풀에서 연결을 꺼내 쿼리를 보내봄
+    async fn postgres(&self) -> Result<(), String> {
+        self.database.ping().await.map_err(|err| err.to_string())
+    }
+
+    /// 다른 pod이 마이그레이션하는 중이면 준비되지 않은 것으로 봄
+    async fn migration(&self) -> Result<(), String> {
+        match self.database.pending_migrations().await {
+            Ok(0) => Ok(()),
+            Ok(pending) => Err(format!("{pending} pending migrations")),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    /// 응답만 오면 됨, 4xx도 연결된 것으로 봄
+    async fn reachable(&self, url: &str) -> Result<(), String> {
+        let resp = self
+            .http()
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| err.to_string())?;
+
+        match resp.status().is_server_error() {
+            true => Err(resp.status().to_string()),
+            false => Ok(()),
+        }
+    }
+}
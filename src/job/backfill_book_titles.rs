@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use sai::{Component, ComponentLifecycle, Injected};
+use tokio::sync::oneshot;
+
+use crate::{
+    command::CommandSet,
+    config::Config,
+    entity::BookTitle,
+    repository::{r#trait::SearchRepository, Backend, RepositorySet},
+};
+
+/// 좋아요나 기록에는 있지만 books_title에 없는 작품의 제목을 Library에서 가져와 채움
+///
+/// 알림을 받기 전에 좋아요하거나 읽은 작품은 제목이 없어서 정렬할 때 맨 뒤로 감
+#[derive(Component)]
+#[lifecycle]
+pub struct BackfillBookTitles<B: Backend> {
+    #[injected]
+    repository: Injected<RepositorySet<B>>,
+
+    #[injected]
+    command: Injected<CommandSet>,
+
+    #[injected]
+    config: Injected<Config>,
+
+    stop_sender: Option<oneshot::Sender<()>>,
+
+    stopped_reciever: Option<oneshot::Receiver<()>>,
+}
+
+#[async_trait::async_trait]
+impl<B: Backend> ComponentLifecycle for BackfillBookTitles<B> {
+    async fn start(&mut self) {
+        let (stop_tx, mut stop_rx) = oneshot::channel();
+        let (stopped_tx, stopped_rx) = oneshot::channel();
+
+        self.stop_sender.replace(stop_tx);
+        self.stopped_reciever.replace(stopped_rx);
+
+        let repository = Arc::clone(&self.repository);
+        let command = Arc::clone(&self.command);
+        let batch = self.config.book_titles_backfill_batch();
+        let mut interval = tokio::time::interval(self.config.book_titles_backfill_interval());
+
+        tokio::spawn(async move {
+            // Library에서 삭제된 작품은 계속 제목이 없으므로 건너뛰면서 진행함
+            let mut after = 0;
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = &mut stop_rx => break,
+                }
+
+                let book_ids = match repository
+                    .search()
+                    .get_untitled_book_ids(after, batch)
+                    .await
+                {
+                    Ok(x) => x,
+                    Err(err) => {
+                        log::error!("failed to get untitled books: {err}");
+                        continue;
+                    }
+                };
+
+                // 끝까지 봤으면 다음에는 처음부터 다시 봄
+                after = match book_ids.len() < batch {
+                    true => 0,
+                    false => book_ids.last().copied().unwrap_or_default(),
+                };
+
+                if book_ids.is_empty() {
+                    continue;
+                }
+
+                let len = book_ids.len();
+
+                let books = match command.get_books_by_ids(book_ids).await {
+                    Ok(x) => x,
+                    Err(err) => {
+                        log::error!("failed to get books to backfill: {err}");
+                        continue;
+                    }
+                };
+
+                let book_titles = books
+                    .iter()
+                    .map(|book| BookTitle::new(book.id, book.title.clone(), Some(book.created_at)))
+                    .collect::<Vec<_>>();
+                let saved = book_titles.len();
+
+                match repository.search().add_or_update_titles(book_titles).await {
+                    Ok(_) => log::info!("backfilled {saved}/{len} book titles"),
+                    Err(err) => log::error!("failed to backfill book titles: {err}"),
+                }
+            }
+
+            stopped_tx.send(()).unwrap();
+        });
+    }
+
+    async fn stop(&mut self) {
+        let stop_tx = self.stop_sender.take().unwrap();
+
+        stop_tx.send(()).unwrap();
+
+        let stopped_rx = self.stopped_reciever.take().unwrap();
+
+        stopped_rx.await.unwrap();
+    }
+}
@@ -0,0 +1,5 @@
+mod backfill_book_titles;
+mod refresh_related_books;
+
+pub use backfill_book_titles::BackfillBookTitles;
+pub use refresh_related_books::RefreshRelatedBooks;
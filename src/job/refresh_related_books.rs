@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use sai::{Component, ComponentLifecycle, Injected};
+use tokio::sync::oneshot;
+
+use crate::{
+    config::Config,
+    repository::{r#trait::RelatedBookRepository, Backend, RepositorySet},
+};
+
+/// likes_book_related 테이블을 주기적으로 다시 계산함
+#[derive(Component)]
+#[lifecycle]
+pub struct RefreshRelatedBooks<B: Backend> {
+    #[injected]
+    repository: Injected<RepositorySet<B>>,
+
+    #[injected]
+    config: Injected<Config>,
+
+    stop_sender: Option<oneshot::Sender<()>>,
+
+    stopped_reciever: Option<oneshot::Receiver<()>>,
+}
+
+#[async_trait::async_trait]
+impl<B: Backend> ComponentLifecycle for RefreshRelatedBooks<B> {
+    async fn start(&mut self) {
+        let (stop_tx, mut stop_rx) = oneshot::channel();
+        let (stopped_tx, stopped_rx) = oneshot::channel();
+
+        self.stop_sender.replace(stop_tx);
+        self.stopped_reciever.replace(stopped_rx);
+
+        let repository = Arc::clone(&self.repository);
+        let min_support = self.config.related_books_min_support();
+        let mut interval = tokio::time::interval(self.config.related_books_refresh_interval());
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = &mut stop_rx => break,
+                }
+
+                match repository.related_book().refresh(min_support).await {
+                    Ok(true) => log::info!("refreshed related books"),
+                    // 다른 인스턴스에서 계산 중
+                    Ok(false) => {}
+                    Err(err) => log::error!("failed to refresh related books: {err}"),
+                }
+            }
+
+            stopped_tx.send(()).unwrap();
+        });
+    }
+
+    async fn stop(&mut self) {
+        let stop_tx = self.stop_sender.take().unwrap();
+
+        stop_tx.send(()).unwrap();
+
+        let stopped_rx = self.stopped_reciever.take().unwrap();
+
+        stopped_rx.await.unwrap();
+    }
+}
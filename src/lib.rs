@@ -1,17 +1,27 @@
 mod app;
+mod cache;
 mod command;
 mod config;
 mod constant;
 mod database;
 mod entity;
 mod error;
+mod health;
+mod job;
+mod metrics;
 mod model;
 mod msg;
+mod openapi;
 mod payload;
 mod registry;
 mod repository;
+mod router;
+pub mod telemetry;
 mod usecase;
 
+#[cfg(test)]
+mod e2e;
+
 pub use registry::RootRegistry;
 
 use error::Error;
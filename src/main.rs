@@ -1,5 +1,4 @@
-use log::Level;
-use madome_user::{release, RootRegistry};
+use madome_user::{debug, telemetry, RootRegistry};
 use sai::System;
 use tokio::signal::{self, unix::SignalKind};
 
@@ -7,9 +6,9 @@ use tokio::signal::{self, unix::SignalKind};
 async fn main() {
     println!("Hello, world!");
 
-    let log_level = if release() { Level::Info } else { Level::Debug };
+    dotenv::dotenv().ok();
 
-    simple_logger::init_with_level(log_level).unwrap();
+    telemetry::init(debug());
 
     let mut system = System::<RootRegistry>::new();
 
@@ -25,6 +24,8 @@ async fn main() {
     system.stop().await;
 
     log::info!("gracefully shutdown the app");
+
+    telemetry::shutdown();
 }
 // Check TypeId
 /*
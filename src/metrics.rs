@@ -0,0 +1,279 @@
+//! Prometheus 지표
+//!
+//! `GET /metrics`로 응답하고, 지표를 남기는 컴포넌트나 usecase는 `Metrics`를 주입받아서 씀
+
+use std::time::Duration;
+
+use hyper::{Method, StatusCode};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramTimer, HistogramVec, IntCounterVec, Opts, Registry,
+    TextEncoder,
+};
+use sai::Component;
+
+use crate::entity::NotificationKind;
+
+const NAMESPACE: &str = "madome_user";
+
+/// 알림을 받는 사용자 수
+const FAN_OUT_BUCKETS: &[f64] = &[
+    0.0, 1.0, 5.0, 10.0, 50.0, 100.0, 500.0, 1000.0, 5000.0, 10000.0,
+];
+
+/// 다른 서비스
+#[derive(Debug, Clone, Copy)]
+pub enum Upstream {
+    Library,
+    Auth,
+}
+
+impl Upstream {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Library => "library",
+            Self::Auth => "auth",
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Metrics {
+    collectors: Collectors,
+}
+
+struct Collectors {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    db_query_duration: HistogramVec,
+    upstream_request_duration: HistogramVec,
+    upstream_errors: IntCounterVec,
+    notification_fan_out: HistogramVec,
+    fcm_sends: IntCounterVec,
+}
+
+impl Default for Collectors {
+    fn default() -> Self {
+        let registry =
+            Registry::new_custom(Some(NAMESPACE.to_string()), None).expect("create registry");
+
+        let http_requests = counter(
+            &registry,
+            "http_requests_total",
+            "HTTP requests",
+            &["method", "route", "status"],
+        );
+        let http_request_duration = histogram(
+            &registry,
+            "http_request_duration_seconds",
+            "HTTP request latency",
+            &["method", "route", "status"],
+            prometheus::DEFAULT_BUCKETS,
+        );
+        let db_query_duration = histogram(
+            &registry,
+            "db_query_duration_seconds",
+            "Database query latency per repository method",
+            &["method"],
+            prometheus::DEFAULT_BUCKETS,
+        );
+        let upstream_request_duration = histogram(
+            &registry,
+            "upstream_request_duration_seconds",
+            "Latency of requests to library and auth",
+            &["service", "operation"],
+            prometheus::DEFAULT_BUCKETS,
+        );
+        let upstream_errors = counter(
+            &registry,
+            "upstream_errors_total",
+            "Failed requests to library and auth",
+            &["service", "operation"],
+        );
+        let notification_fan_out = histogram(
+            &registry,
+            "notification_fan_out",
+            "Recipients per notification event",
+            &["kind"],
+            FAN_OUT_BUCKETS,
+        );
+        let fcm_sends = counter(
+            &registry,
+            "fcm_sends_total",
+            "FCM send outcomes",
+            &["outcome"],
+        );
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            db_query_duration,
+            upstream_request_duration,
+            upstream_errors,
+            notification_fan_out,
+            fcm_sends,
+        }
+    }
+}
+
+fn counter(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntCounterVec {
+    let counter = IntCounterVec::new(Opts::new(name, help), labels).expect("create counter");
+
+    registry
+        .register(Box::new(counter.clone()))
+        .expect("register counter");
+
+    counter
+}
+
+fn histogram(
+    registry: &Registry,
+    name: &str,
+    help: &str,
+    labels: &[&str],
+    buckets: &[f64],
+) -> HistogramVec {
+    let opts = HistogramOpts::new(name, help).buckets(buckets.to_vec());
+    let histogram = HistogramVec::new(opts, labels).expect("create histogram");
+
+    registry
+        .register(Box::new(histogram.clone()))
+        .expect("register histogram");
+
+    histogram
+}
+
+impl Metrics {
+    /// 경로 대신 라우트 테이블의 경로를 써서 사용자 아이디 같은 값이 라벨에 들어가지 않게 함
+    pub fn observe_request(
+        &self,
+        method: &Method,
+        route: &str,
+        status: StatusCode,
+        elapsed: Duration,
+    ) {
+        let labels = [method.as_str(), route, status.as_str()];
+
+        self.collectors
+            .http_requests
+            .with_label_values(&labels)
+            .inc();
+        self.collectors
+            .http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// 반환된 타이머가 drop될 때 기록됨
+    ///
+    /// `method`는 `LikeRepository::get_many`처럼 씀
+    pub fn query(&self, method: &str) -> HistogramTimer {
+        self.collectors
+            .db_query_duration
+            .with_label_values(&[method])
+            .start_timer()
+    }
+
+    /// `failed`는 응답을 받지 못했거나 5xx인 경우만 참이어야 함
+    pub fn observe_upstream(
+        &self,
+        upstream: Upstream,
+        operation: &str,
+        elapsed: Duration,
+        failed: bool,
+    ) {
+        let labels = [upstream.as_str(), operation];
+
+        self.collectors
+            .upstream_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+
+        if failed {
+            self.collectors
+                .upstream_errors
+                .with_label_values(&labels)
+                .inc();
+        }
+    }
+
+    pub fn observe_fan_out(&self, kind: NotificationKind, recipients: usize) {
+        let kind = match kind {
+            NotificationKind::Book => "book",
+            NotificationKind::User => "user",
+            NotificationKind::BookSeries => "book_series",
+        };
+
+        self.collectors
+            .notification_fan_out
+            .with_label_values(&[kind])
+            .observe(recipients as f64);
+    }
+
+    pub fn observe_fcm(&self, sent: bool) {
+        let outcome = match sent {
+            true => "success",
+            false => "failure",
+        };
+
+        self.collectors
+            .fcm_sends
+            .with_label_values(&[outcome])
+            .inc();
+    }
+
+    /// Prometheus text format
+    pub fn render(&self) -> String {
+        let mut buf = Vec::new();
+
+        TextEncoder::new()
+            .encode(&self.collectors.registry.gather(), &mut buf)
+            .expect("encode metrics");
+
+        String::from_utf8(buf).expect("metrics are utf-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hyper::{Method, StatusCode};
+
+    use crate::entity::NotificationKind;
+
+    use super::{Metrics, Upstream};
+
+    #[test]
+    fn render_text_format() {
+        let metrics = Metrics {
+            collectors: Default::default(),
+        };
+
+        metrics.observe_request(
+            &Method::GET,
+            "/users/@me",
+            StatusCode::OK,
+            Duration::from_millis(5),
+        );
+        metrics.observe_upstream(Upstream::Library, "has_book", Duration::ZERO, true);
+        metrics.observe_fan_out(NotificationKind::BookSeries, 3);
+        metrics.observe_fcm(false);
+        drop(metrics.query("LikeRepository::get_many"));
+
+        let text = metrics.render();
+
+        assert!(text.contains(
+            r#"madome_user_http_requests_total{method="GET",route="/users/@me",status="200"} 1"#
+        ));
+        assert!(text.contains(
+            r#"madome_user_upstream_errors_total{operation="has_book",service="library"} 1"#
+        ));
+        assert!(text.contains(r#"madome_user_notification_fan_out_sum{kind="book_series"} 3"#));
+        assert!(text.contains(r#"madome_user_fcm_sends_total{outcome="failure"} 1"#));
+        assert!(text.contains(
+            r#"madome_user_db_query_duration_seconds_count{method="LikeRepository::get_many"} 1"#
+        ));
+    }
+}
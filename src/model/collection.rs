@@ -0,0 +1,202 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use hyper::{header, Body, Request, Response, StatusCode};
+use madome_sdk::api::{header::take_origin_response, library};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+use uuid::Uuid;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::{share::Visibility, Presenter};
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Collection {
+    pub id: Uuid,
+    pub name: String,
+    pub visibility: Visibility,
+    pub share_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<entity::Collection> for Collection {
+    fn from(
+        entity::Collection {
+            id,
+            name,
+            visibility,
+            share_token,
+            created_at,
+            updated_at,
+            ..
+        }: entity::Collection,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            visibility: visibility.into(),
+            share_token,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<Collection> {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
+
+pub struct CollectionItem {
+    pub book_id: u32,
+    pub position: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<entity::CollectionItem> for CollectionItem {
+    fn from(
+        entity::CollectionItem {
+            book_id,
+            position,
+            created_at,
+            ..
+        }: entity::CollectionItem,
+    ) -> Self {
+        Self {
+            book_id,
+            position,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ReducedCollectionItem {
+    pub book_id: u32,
+    pub position: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CollectionItem> for ReducedCollectionItem {
+    fn from(
+        CollectionItem {
+            book_id,
+            position,
+            created_at,
+        }: CollectionItem,
+    ) -> Self {
+        Self {
+            book_id,
+            position,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ExtendedCollectionItem {
+    pub book_id: u32,
+    pub position: usize,
+    pub created_at: DateTime<Utc>,
+    #[schemars(with = "serde_json::Value")]
+    pub book: library::model::Book,
+}
+
+pub struct CollectionWithItems {
+    pub collection: Collection,
+    pub items: Vec<CollectionItem>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "CollectionWithItems_for_{T}")]
+pub(crate) struct Serialized<T> {
+    #[serde(flatten)]
+    collection: Collection,
+    items: Vec<T>,
+}
+
+#[async_trait::async_trait]
+impl Presenter for CollectionWithItems {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let Self { collection, items } = self;
+
+        let serialized = match take_origin_response(request.headers()) {
+            // for internal
+            true => {
+                let items = items
+                    .into_iter()
+                    .map(ReducedCollectionItem::from)
+                    .collect::<Vec<_>>();
+
+                serde_json::to_vec(&Serialized { collection, items }).expect("json serialize")
+            }
+            // for external
+            false => {
+                let book_ids = items.iter().map(|x| x.book_id).collect::<Vec<_>>();
+
+                let mut books = if book_ids.is_empty() {
+                    Vec::new()
+                } else {
+                    command.get_books_by_ids(book_ids).await?
+                }
+                .into_iter()
+                .map(|x| (x.id, x))
+                .collect::<HashMap<_, _>>();
+
+                let items = items
+                    .into_iter()
+                    // Library에서 가져올 수 있는 작품만 필터링함
+                    .filter_map(
+                        |CollectionItem {
+                             book_id,
+                             position,
+                             created_at,
+                         }| {
+                            let book = books.remove(&book_id)?;
+
+                            Some(ExtendedCollectionItem {
+                                book_id,
+                                position,
+                                created_at,
+                                book,
+                            })
+                        },
+                    )
+                    .collect::<Vec<_>>();
+
+                serde_json::to_vec(&Serialized { collection, items }).expect("json serialize")
+            }
+        };
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
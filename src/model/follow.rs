@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+use uuid::Uuid;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::Presenter;
+
+/// 팔로워 목록이면 팔로워, 팔로잉 목록이면 팔로우하는 사용자
+#[derive(Serialize, JsonSchema)]
+pub struct Follow {
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Follow {
+    pub fn follower(follow: entity::Follow) -> Self {
+        Self {
+            user_id: follow.follower_id,
+            created_at: follow.created_at,
+        }
+    }
+
+    pub fn followee(follow: entity::Follow) -> Self {
+        Self {
+            user_id: follow.followee_id,
+            created_at: follow.created_at,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<Follow> {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{
+    command::CommandSet,
+    config::Config,
+    health::{Check, Status},
+};
+
+use super::Presenter;
+
+/// 프로세스가 요청을 받을 수 있음
+pub struct Liveness;
+
+/// 의존성마다 확인한 결과
+///
+/// 하나라도 실패하면 503으로 응답해서 트래픽을 받지 않음
+pub struct Readiness(pub Vec<(&'static str, Check)>);
+
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "Health")]
+pub(crate) struct Serialized {
+    status: Status,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    checks: BTreeMap<&'static str, Check>,
+}
+
+fn set_health(resp: &mut Response<Body>, health: Serialized) {
+    let status = match health.status {
+        Status::Ok => StatusCode::OK,
+        Status::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    let serialized = serde_json::to_vec(&health).expect("json serialize");
+
+    resp.set_status(status).unwrap();
+    resp.set_header(header::CONTENT_TYPE, "application/json")
+        .unwrap();
+    resp.set_body(serialized.into());
+}
+
+#[async_trait::async_trait]
+impl Presenter for Liveness {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        set_health(
+            resp,
+            Serialized {
+                status: Status::Ok,
+                checks: BTreeMap::new(),
+            },
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Readiness {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let status = match self.0.iter().all(|(_, x)| x.status == Status::Ok) {
+            true => Status::Ok,
+            false => Status::Unavailable,
+        };
+
+        set_health(
+            resp,
+            Serialized {
+                status,
+                checks: self.0.into_iter().collect(),
+            },
+        );
+
+        Ok(())
+    }
+}
@@ -3,14 +3,15 @@ use std::{collections::HashMap, sync::Arc};
 use chrono::{DateTime, Utc};
 use hyper::{header, Body, Request, Response, StatusCode};
 use itertools::Itertools;
-use madome_sdk::api::{header::take_origin_response, library, Token};
+use madome_sdk::api::{header::take_origin_response, library};
+use schemars::JsonSchema;
 use serde::Serialize;
 use util::http::SetResponse;
 use uuid::Uuid;
 
-use crate::{config::Config, entity};
+use crate::{command::CommandSet, config::Config, entity};
 
-use super::Presenter;
+use super::{set_partial, Presenter};
 
 pub enum History {
     Book {
@@ -51,7 +52,7 @@ impl From<entity::History> for History {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ReducedHistory {
     Book {
@@ -78,13 +79,14 @@ impl From<History> for ReducedHistory {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ExtendedHistory {
     Book {
         book_id: u32,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
+        #[schemars(with = "serde_json::Value")]
         book: library::model::Book,
     },
 }
@@ -95,7 +97,8 @@ impl Presenter for Vec<History> {
         self,
         request: &mut Request<Body>,
         resp: &mut Response<Body>,
-        config: Arc<Config>,
+        _config: Arc<Config>,
+        command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         let serialized = match take_origin_response(request.headers()) {
             true => {
@@ -107,35 +110,51 @@ impl Presenter for Vec<History> {
                 // TODO: kind가 추가로 생기게 되면 like과 비슷하게 hashmap에서 값을 빼오는 형식으로 순서를 같게 하면 될 듯
                 let book_ids = self.iter().filter_map(|x| x.book_id()).collect::<Vec<_>>();
 
-                let mut books = if book_ids.is_empty() {
-                    Vec::new()
+                let books = if book_ids.is_empty() {
+                    Ok(Vec::new())
                 } else {
-                    library::def::get_books_by_ids(config.library_url(), Token::default(), book_ids)
-                        .await?
+                    command.get_books_by_ids(book_ids).await
+                };
+
+                match books {
+                    Ok(books) => {
+                        let mut books = books
+                            .into_iter()
+                            .map(|x| (x.id, x))
+                            .collect::<HashMap<_, _>>();
+
+                        let histories = self
+                            .into_iter()
+                            .filter_map(|x| x.book_id().and_then(|k| Some((x, books.remove(&k)?))))
+                            .map(|(x, book)| match x {
+                                History::Book {
+                                    book_id,
+                                    created_at,
+                                    updated_at,
+                                    ..
+                                } => ExtendedHistory::Book {
+                                    book_id,
+                                    created_at,
+                                    updated_at,
+                                    book,
+                                },
+                            })
+                            .collect::<Vec<_>>();
+
+                        serde_json::to_vec(&histories).expect("json serialize")
+                    }
+                    // Library가 응답하지 않아도 열람 기록은 보여줌
+                    Err(err) if err.is_library_error() => {
+                        log::warn!("library is unavailable, respond reduced histories: {err}");
+
+                        set_partial(resp);
+
+                        let histories =
+                            self.into_iter().map_into().collect::<Vec<ReducedHistory>>();
+                        serde_json::to_vec(&histories).expect("json serialize")
+                    }
+                    Err(err) => return Err(err),
                 }
-                .into_iter()
-                .map(|x| (x.id, x))
-                .collect::<HashMap<_, _>>();
-
-                let histories = self
-                    .into_iter()
-                    .filter_map(|x| x.book_id().and_then(|k| Some((x, books.remove(&k)?))))
-                    .map(|(x, book)| match x {
-                        History::Book {
-                            book_id,
-                            created_at,
-                            updated_at,
-                            ..
-                        } => ExtendedHistory::Book {
-                            book_id,
-                            created_at,
-                            updated_at,
-                            book,
-                        },
-                    })
-                    .collect::<Vec<_>>();
-
-                serde_json::to_vec(&histories).expect("json serialize")
             }
         };
 
@@ -2,14 +2,15 @@ use std::{collections::HashMap, sync::Arc};
 
 use chrono::{DateTime, Utc};
 use hyper::{header, Body, Request, Response, StatusCode};
-use madome_sdk::api::{header::take_origin_response, library, Token};
+use madome_sdk::api::{header::take_origin_response, library};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use util::http::SetResponse;
 use uuid::Uuid;
 
-use crate::{config::Config, entity};
+use crate::{command::CommandSet, config::Config, entity};
 
-use super::Presenter;
+use super::{set_partial, Presenter};
 
 /* #[derive(Serialize)]
 #[serde(tag = "kind", rename_all = "snake_case")] */
@@ -45,7 +46,7 @@ impl Like {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ReducedLike {
     Book {
@@ -59,18 +60,20 @@ pub enum ReducedLike {
     },
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ExtendedLike {
     Book {
         book_id: u32,
         created_at: DateTime<Utc>,
+        #[schemars(with = "serde_json::Value")]
         book: library::model::Book,
     },
     BookTag {
         tag_kind: String,
         tag_name: String,
         created_at: DateTime<Utc>,
+        #[schemars(with = "Vec<serde_json::Value>")]
         books: Vec<library::model::Book>,
     },
 }
@@ -123,13 +126,130 @@ impl ExtendedLike {
     } */
 }
 
+/// 태그 좋아요에 붙일 작품들의 쿼리
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub(crate) struct BooksPayload {
+    #[serde(rename = "books-per-page")]
+    per_page: Option<usize>,
+    #[serde(rename = "books-page")]
+    page: Option<usize>,
+    /// id-desc, id-asc, random
+    #[serde(rename = "books-sort-by")]
+    sort_by: Option<String>,
+}
+
+/// Library에서 작품 정보를 가져와서 붙임
+///
+/// Library에서 가져올 수 있는 작품, 태그만 남음
+pub async fn extend(
+    likes: &[Like],
+    request: &Request<Body>,
+    command: &CommandSet,
+) -> crate::Result<Vec<ExtendedLike>> {
+    let book_tags = likes
+        .iter()
+        .filter_map(|x| x.book_tag())
+        .map(|(x, y)| (x.to_owned(), y.to_owned()))
+        .collect::<Vec<_>>();
+
+    let book_ids = likes.iter().filter_map(|x| x.book_id()).collect::<Vec<_>>();
+
+    let (books, mut books_group_by_tags) =
+        futures::try_join!(async { command.get_books_by_ids(book_ids).await }, async {
+            if book_tags.is_empty() {
+                Ok(HashMap::new())
+            } else {
+                let qs = request.uri().query().unwrap_or_default();
+                let BooksPayload {
+                    per_page,
+                    page,
+                    sort_by,
+                } = serde_qs::from_str(qs).unwrap_or_default();
+
+                let sort_by = match sort_by.as_deref() {
+                    Some("id-desc") => Some(library::payload::BookSortBy::IdDesc),
+                    Some("id-asc") => Some(library::payload::BookSortBy::IdAsc),
+                    Some("random") => Some(library::payload::BookSortBy::Random),
+                    _ => None,
+                };
+
+                command
+                    .get_books_by_tags(book_tags, per_page.unwrap_or(3), page.unwrap_or(1), sort_by)
+                    .await
+            }
+        })?;
+    let mut books = books
+        .into_iter()
+        .map(|x| (x.id, x))
+        .collect::<HashMap<_, _>>();
+
+    let likes = likes
+        .iter()
+        // Library에서 가져올 수 있는 작품만 필터링함
+        .filter_map(|x| match x {
+            Like::Book {
+                book_id,
+                created_at,
+                ..
+            } => {
+                let book = books.remove(book_id);
+
+                book.map(|x| ExtendedLike::Book {
+                    book_id: *book_id,
+                    created_at: *created_at,
+                    book: x,
+                })
+
+                /* match book {
+                    Some(book) => Some(ExtendedLike::Book {
+                        book_id,
+                        created_at,
+                        book,
+                    }),
+                    None => {
+                        // TODO: LIKE을 추가할 때 있는 작품인지 검증을 하거나
+                        // 아니면 아예 응답을 줄 때 없는 작품이면 filter_map()으로 제외를 시켜버리자
+                        // panic!("why hasn't book {book_id}");
+                        None
+                    }
+                } */
+            }
+            Like::BookTag {
+                tag_kind,
+                tag_name,
+                created_at,
+                ..
+            } => {
+                let tag = (tag_kind.clone(), tag_name.clone());
+
+                // log::debug!("books = {books_group_by_tags:#?}");
+
+                // TODO: LIKE을 추가할 때 있는 태그인지 아닌지 검증을 하게 될 경우에는 panic!을 일으키는 게 맞을까?
+                // 여기서 panic!을 일으켜서 요청 자체가 죽어버리는 거 보다는
+                // 일단 있다가 없어질 가능성을 생각해서 빈 배열을 주는 게 맞다고 봄
+                let books = books_group_by_tags.remove(&tag); // .unwrap_or_default(); //.expect("why hasn't book?");
+
+                books.map(|xs| ExtendedLike::BookTag {
+                    tag_kind: tag.0,
+                    tag_name: tag.1,
+                    books: xs,
+                    created_at: *created_at,
+                })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(likes)
+}
+
 #[async_trait::async_trait]
 impl Presenter for Vec<Like> {
     async fn set_response(
         self,
         request: &mut Request<Body>,
         resp: &mut Response<Body>,
-        config: Arc<Config>,
+        _config: Arc<Config>,
+        command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         let serialized = match take_origin_response(request.headers()) {
             // for internal
@@ -138,130 +258,18 @@ impl Presenter for Vec<Like> {
                 serde_json::to_vec(&likes).expect("json serialize")
             }
             // for external
-            false => {
-                let book_tags = self
-                    .iter()
-                    .filter_map(|x| x.book_tag())
-                    .map(|(x, y)| (x.to_owned(), y.to_owned()))
-                    .collect::<Vec<_>>();
-
-                let book_ids = self.iter().filter_map(|x| x.book_id()).collect::<Vec<_>>();
-
-                let (books, mut books_group_by_tags) = futures::try_join!(
-                    async {
-                        if book_ids.is_empty() {
-                            Ok(Vec::new())
-                        } else {
-                            library::get_books_by_ids(
-                                config.library_url(),
-                                Token::default(),
-                                book_ids,
-                            )
-                            .await
-                        }
-                    },
-                    async {
-                        if book_tags.is_empty() {
-                            Ok(HashMap::new())
-                        } else {
-                            #[derive(Debug, Default, Deserialize)]
-                            struct Payload {
-                                #[serde(rename = "books-per-page")]
-                                per_page: Option<usize>,
-                                #[serde(rename = "books-page")]
-                                page: Option<usize>,
-                                #[serde(rename = "books-sort-by")]
-                                sort_by: Option<String>,
-                            }
-
-                            let qs = request.uri().query().unwrap_or_default();
-                            let Payload {
-                                per_page,
-                                page,
-                                sort_by,
-                            } = serde_qs::from_str(qs).unwrap_or_default();
-
-                            let sort_by = match sort_by.as_deref() {
-                                Some("id-desc") => Some(library::payload::BookSortBy::IdDesc),
-                                Some("id-asc") => Some(library::payload::BookSortBy::IdAsc),
-                                Some("random") => Some(library::payload::BookSortBy::Random),
-                                _ => None,
-                            };
-
-                            library::get_books_by_tags(
-                                config.library_url(),
-                                Token::default(),
-                                book_tags,
-                                per_page.unwrap_or(3),
-                                page.unwrap_or(1),
-                                sort_by,
-                            )
-                            .await
-                        }
-                    }
-                )?;
-                let mut books = books
-                    .into_iter()
-                    .map(|x| (x.id, x))
-                    .collect::<HashMap<_, _>>();
-
-                let likes = self
-                    .into_iter()
-                    // Library에서 가져올 수 있는 작품만 필터링함
-                    .filter_map(|x| match x {
-                        Like::Book {
-                            book_id,
-                            created_at,
-                            ..
-                        } => {
-                            let book = books.remove(&book_id);
-
-                            book.map(|x| ExtendedLike::Book {
-                                book_id,
-                                created_at,
-                                book: x,
-                            })
-
-                            /* match book {
-                                Some(book) => Some(ExtendedLike::Book {
-                                    book_id,
-                                    created_at,
-                                    book,
-                                }),
-                                None => {
-                                    // TODO: LIKE을 추가할 때 있는 작품인지 검증을 하거나
-                                    // 아니면 아예 응답을 줄 때 없는 작품이면 filter_map()으로 제외를 시켜버리자
-                                    // panic!("why hasn't book {book_id}");
-                                    None
-                                }
-                            } */
-                        }
-                        Like::BookTag {
-                            tag_kind,
-                            tag_name,
-                            created_at,
-                            ..
-                        } => {
-                            let tag = (tag_kind, tag_name);
-
-                            // log::debug!("books = {books_group_by_tags:#?}");
-
-                            // TODO: LIKE을 추가할 때 있는 태그인지 아닌지 검증을 하게 될 경우에는 panic!을 일으키는 게 맞을까?
-                            // 여기서 panic!을 일으켜서 요청 자체가 죽어버리는 거 보다는
-                            // 일단 있다가 없어질 가능성을 생각해서 빈 배열을 주는 게 맞다고 봄
-                            let books = books_group_by_tags.remove(&tag); // .unwrap_or_default(); //.expect("why hasn't book?");
-
-                            books.map(|xs| ExtendedLike::BookTag {
-                                tag_kind: tag.0,
-                                tag_name: tag.1,
-                                books: xs,
-                                created_at,
-                            })
-                        }
-                    })
-                    .collect::<Vec<_>>();
+            false => match extend(&self, request, &command).await {
+                Ok(likes) => serde_json::to_vec(&likes).expect("json serialize"),
+                // Library가 응답하지 않아도 좋아요 목록은 보여줌
+                Err(err) if err.is_library_error() => {
+                    log::warn!("library is unavailable, respond reduced likes: {err}");
 
-                serde_json::to_vec(&likes).expect("json serialize")
+                    set_partial(resp);
+
+                    let likes: Vec<ReducedLike> = self.into_iter().map(Into::into).collect();
+                    serde_json::to_vec(&likes).expect("json serialize")
+                }
+                Err(err) => return Err(err),
                 // library
 
                 // tag_type = if female || male -> tag
@@ -325,7 +333,7 @@ impl Presenter for Vec<Like> {
                     .await?;
 
                 serde_json::to_vec(&books).expect("json serialize") */
-            }
+            },
         };
 
         resp.set_status(StatusCode::OK).unwrap();
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config};
+
+use super::Presenter;
+
+/// `Metrics::render()`로 만든 Prometheus text format
+pub struct Metrics(pub String);
+
+#[async_trait::async_trait]
+impl Presenter for Metrics {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)
+            .unwrap();
+        resp.set_body(self.0.into());
+
+        Ok(())
+    }
+}
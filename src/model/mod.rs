@@ -1,27 +1,58 @@
-mod history;
-mod like;
-mod notification;
-mod user;
+pub(crate) mod collection;
+pub(crate) mod follow;
+pub(crate) mod health;
+pub(crate) mod history;
+pub(crate) mod like;
+mod metrics;
+pub(crate) mod notification;
+mod openapi;
+pub(crate) mod rating;
+pub(crate) mod recommendation;
+pub(crate) mod related_book;
+pub(crate) mod search;
+pub(crate) mod share;
+pub(crate) mod user;
 
 use std::sync::Arc;
 
+pub use collection::{Collection, CollectionWithItems};
+pub use follow::Follow;
+pub use health::{Liveness, Readiness};
 pub use history::History;
 pub use like::{Like, ReducedLike};
+pub use metrics::Metrics;
 pub use notification::Notification;
+pub use openapi::OpenApi;
+pub use rating::{Rating, RatingAggregate};
+pub use recommendation::{Reason, Recommendation};
+pub use related_book::RelatedBook;
+pub use search::SearchResult;
+pub use share::{Share, Shared, SharedProfile};
 pub use user::User;
 
 use hyper::{header, Body, Request, Response, StatusCode};
 use util::http::SetResponse;
 
 use crate::{
+    command::CommandSet,
     config::Config,
     into_model, model,
     usecase::{
-        create_like, create_notifications, create_or_update_fcm_token, create_or_update_history,
-        create_user, delete_history, delete_like, get_fcm_tokens,
+        add_collection_item, create_block, create_collection, create_like, create_notifications,
+        create_or_update_fcm_token, create_or_update_history, create_or_update_rating, create_user,
+        delete_block, delete_collection, delete_collection_item, delete_history, delete_like,
+        delete_rating, follow_user, get_fcm_tokens, invalidate_library_cache,
+        reorder_collection_items, unfollow_user, update_collection,
     },
 };
 
+/// Library가 응답하지 않아서 작품 정보 없이 응답했다는 표시
+const PARTIAL_WARNING: &str = "199 - \"library is unavailable, response is partial\"";
+
+pub(crate) fn set_partial(resp: &mut Response<Body>) {
+    resp.set_header(header::WARNING, PARTIAL_WARNING).unwrap();
+}
+
 into_model![
     (User, model::User),
     (CreateUser, create_user::Model),
@@ -38,7 +69,44 @@ into_model![
     //
     (Histories, Vec<model::History>),
     (CreateOrUpdateHistory, create_or_update_history::Model),
-    (DeleteHistory, delete_history::Model)
+    (DeleteHistory, delete_history::Model),
+    //
+    (Collections, Vec<model::Collection>),
+    (CollectionWithItems, model::CollectionWithItems),
+    (CreateCollection, create_collection::Model),
+    (UpdateCollection, update_collection::Model),
+    (DeleteCollection, delete_collection::Model),
+    (AddCollectionItem, add_collection_item::Model),
+    (DeleteCollectionItem, delete_collection_item::Model),
+    (ReorderCollectionItems, reorder_collection_items::Model),
+    //
+    (Share, model::Share),
+    (Shared, model::Shared),
+    //
+    (Ratings, Vec<model::Rating>),
+    (RatingAggregates, Vec<model::RatingAggregate>),
+    (CreateOrUpdateRating, create_or_update_rating::Model),
+    (DeleteRating, delete_rating::Model),
+    //
+    (Recommendations, Vec<model::Recommendation>),
+    //
+    (RelatedBooks, Vec<model::RelatedBook>),
+    //
+    (Follows, Vec<model::Follow>),
+    (FollowUser, follow_user::Model),
+    (UnfollowUser, unfollow_user::Model),
+    //
+    (CreateBlock, create_block::Model),
+    (DeleteBlock, delete_block::Model),
+    //
+    (SearchResults, Vec<model::SearchResult>),
+    //
+    (InvalidateLibraryCache, invalidate_library_cache::Model),
+    //
+    (OpenApi, model::OpenApi),
+    (Metrics, model::Metrics),
+    (Liveness, model::Liveness),
+    (Readiness, model::Readiness),
 ];
 
 #[async_trait::async_trait]
@@ -49,6 +117,7 @@ pub trait Presenter: Sized {
         request: &mut Request<Body>,
         response: &mut Response<Body>,
         config: Arc<Config>,
+        command: Arc<CommandSet>,
     ) -> crate::Result<()>;
 }
 
@@ -59,6 +128,7 @@ impl Presenter for create_user::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::CREATED).unwrap();
         response.set_body(Body::empty());
@@ -74,6 +144,7 @@ impl Presenter for create_like::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::CREATED).unwrap();
         response.set_body(Body::empty());
@@ -89,6 +160,7 @@ impl Presenter for delete_like::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::NO_CONTENT).unwrap();
         response.set_body(Body::empty());
@@ -104,6 +176,7 @@ impl Presenter for create_notifications::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::CREATED).unwrap();
         response.set_body(Body::empty());
@@ -119,6 +192,7 @@ impl Presenter for create_or_update_fcm_token::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::CREATED).unwrap();
         response.set_body(Body::empty());
@@ -134,6 +208,7 @@ impl Presenter for get_fcm_tokens::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         let serialized = serde_json::to_string(&self.0).expect("json serialize");
 
@@ -154,6 +229,7 @@ impl Presenter for create_or_update_history::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::CREATED).unwrap();
         response.set_body(Body::empty());
@@ -169,6 +245,220 @@ impl Presenter for delete_history::Model {
         _request: &mut Request<Body>,
         response: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for create_collection::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self.0).expect("json serialize");
+
+        response.set_status(StatusCode::CREATED).unwrap();
+        response
+            .set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        response.set_body(serialized.into());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for update_collection::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for delete_collection::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for add_collection_item::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::CREATED).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for delete_collection_item::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for reorder_collection_items::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for create_or_update_rating::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::CREATED).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for delete_rating::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for follow_user::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::CREATED).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for unfollow_user::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for create_block::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::CREATED).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for delete_block::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        response.set_status(StatusCode::NO_CONTENT).unwrap();
+        response.set_body(Body::empty());
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for invalidate_library_cache::Model {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        response: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         response.set_status(StatusCode::NO_CONTENT).unwrap();
         response.set_body(Body::empty());
@@ -197,12 +487,12 @@ macro_rules! into_model {
 
         #[async_trait::async_trait]
         impl Presenter for Model {
-            async fn set_response(self, request: &mut Request<Body>, response: &mut Response<Body>, config: Arc<Config>) -> crate::Result<()> {
+            async fn set_response(self, request: &mut Request<Body>, response: &mut Response<Body>, config: Arc<Config>, command: Arc<CommandSet>) -> crate::Result<()> {
                 use Model::*;
 
                 match self {
                     $(
-                        $member(model) => model.set_response(request, response, config).await,
+                        $member(model) => model.set_response(request, response, config, command).await,
                     )*
                 }
             }
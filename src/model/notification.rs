@@ -2,14 +2,16 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
 use serde::Serialize;
 use util::http::SetResponse;
+use uuid::Uuid;
 
-use crate::{config::Config, entity};
+use crate::{command::CommandSet, config::Config, entity};
 
 use super::Presenter;
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Notification {
     Book {
@@ -17,6 +19,17 @@ pub enum Notification {
         book_tags: Vec<(String, String)>,
         created_at: DateTime<Utc>,
     },
+    BookSeries {
+        book_id: u32,
+        series_name: String,
+        created_at: DateTime<Utc>,
+    },
+    User {
+        /// 작품을 좋아한 사용자
+        user_id: Uuid,
+        book_id: u32,
+        created_at: DateTime<Utc>,
+    },
 }
 
 #[async_trait::async_trait]
@@ -26,6 +39,7 @@ impl Presenter for Vec<Notification> {
         _request: &mut Request<Body>,
         resp: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         let serialized = serde_json::to_string(&self).expect("serialize json");
 
@@ -53,6 +67,26 @@ impl From<entity::Notification> for Notification {
                 book_tags,
                 created_at,
             },
+            BookSeries {
+                book_id,
+                series_name,
+                created_at,
+                ..
+            } => Notification::BookSeries {
+                book_id,
+                series_name,
+                created_at,
+            },
+            User {
+                followee_id,
+                book_id,
+                created_at,
+                ..
+            } => Notification::User {
+                user_id: followee_id,
+                book_id,
+                created_at,
+            },
         }
     }
 }
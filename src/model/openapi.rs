@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use serde_json::Value;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config};
+
+use super::Presenter;
+
+/// `openapi::document()`에서 만든 문서
+pub struct OpenApi(pub &'static Value);
+
+#[async_trait::async_trait]
+impl Presenter for OpenApi {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(self.0).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
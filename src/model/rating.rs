@@ -0,0 +1,141 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use hyper::{header, Body, Request, Response, StatusCode};
+use madome_sdk::api::{header::take_origin_response, library};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::Presenter;
+
+#[derive(Serialize, JsonSchema)]
+pub struct Rating {
+    pub book_id: u32,
+    pub score: u8,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<entity::Rating> for Rating {
+    fn from(
+        entity::Rating {
+            book_id,
+            score,
+            note,
+            created_at,
+            updated_at,
+            ..
+        }: entity::Rating,
+    ) -> Self {
+        Self {
+            book_id,
+            score,
+            note,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct ExtendedRating {
+    #[serde(flatten)]
+    pub rating: Rating,
+    #[schemars(with = "serde_json::Value")]
+    pub book: library::model::Book,
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<Rating> {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = match take_origin_response(request.headers()) {
+            // for internal
+            true => serde_json::to_vec(&self).expect("json serialize"),
+            // for external
+            false => {
+                let book_ids = self.iter().map(|x| x.book_id).collect::<Vec<_>>();
+
+                let mut books = if book_ids.is_empty() {
+                    Vec::new()
+                } else {
+                    command.get_books_by_ids(book_ids).await?
+                }
+                .into_iter()
+                .map(|x| (x.id, x))
+                .collect::<HashMap<_, _>>();
+
+                let ratings = self
+                    .into_iter()
+                    // Library에서 가져올 수 있는 작품만 필터링함
+                    .filter_map(|rating| {
+                        let book = books.remove(&rating.book_id)?;
+
+                        Some(ExtendedRating { rating, book })
+                    })
+                    .collect::<Vec<_>>();
+
+                serde_json::to_vec(&ratings).expect("json serialize")
+            }
+        };
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct RatingAggregate {
+    pub book_id: u32,
+    pub average: f64,
+    pub count: usize,
+}
+
+impl From<entity::RatingAggregate> for RatingAggregate {
+    fn from(
+        entity::RatingAggregate {
+            book_id,
+            average,
+            count,
+        }: entity::RatingAggregate,
+    ) -> Self {
+        Self {
+            book_id,
+            average,
+            count,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<RatingAggregate> {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use madome_sdk::api::{header::take_origin_response, library};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config};
+
+use super::Presenter;
+
+/// 추천한 이유
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Reason {
+    /// because you liked book
+    LikedBook { book_id: u32 },
+    /// because you liked tag
+    LikedBookTag { tag_kind: String, tag_name: String },
+    /// because you read book
+    ReadBook { book_id: u32 },
+}
+
+pub struct Recommendation {
+    pub book_id: u32,
+    pub score: u32,
+    pub reason: Reason,
+    #[schemars(with = "serde_json::Value")]
+    pub book: library::model::Book,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReducedRecommendation {
+    Book {
+        book_id: u32,
+        score: u32,
+        reason: Reason,
+    },
+}
+
+impl From<Recommendation> for ReducedRecommendation {
+    fn from(
+        Recommendation {
+            book_id,
+            score,
+            reason,
+            ..
+        }: Recommendation,
+    ) -> Self {
+        Self::Book {
+            book_id,
+            score,
+            reason,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExtendedRecommendation {
+    Book {
+        book_id: u32,
+        score: u32,
+        reason: Reason,
+        #[schemars(with = "serde_json::Value")]
+        book: library::model::Book,
+    },
+}
+
+impl From<Recommendation> for ExtendedRecommendation {
+    fn from(
+        Recommendation {
+            book_id,
+            score,
+            reason,
+            book,
+        }: Recommendation,
+    ) -> Self {
+        Self::Book {
+            book_id,
+            score,
+            reason,
+            book,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<Recommendation> {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = match take_origin_response(request.headers()) {
+            // for internal
+            true => {
+                let recommendations: Vec<ReducedRecommendation> =
+                    self.into_iter().map(Into::into).collect();
+
+                serde_json::to_vec(&recommendations).expect("json serialize")
+            }
+            // for external
+            false => {
+                let recommendations: Vec<ExtendedRecommendation> =
+                    self.into_iter().map(Into::into).collect();
+
+                serde_json::to_vec(&recommendations).expect("json serialize")
+            }
+        };
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
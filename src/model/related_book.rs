@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::Presenter;
+
+/// internal 전용이라 작품 정보는 붙이지 않음
+#[derive(Serialize, JsonSchema)]
+pub struct RelatedBook {
+    pub book_id: u32,
+    pub support: usize,
+}
+
+impl From<entity::RelatedBook> for RelatedBook {
+    fn from(
+        entity::RelatedBook {
+            related_book_id,
+            support,
+            ..
+        }: entity::RelatedBook,
+    ) -> Self {
+        Self {
+            book_id: related_book_id,
+            support,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<RelatedBook> {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::Presenter;
+
+/// 제목은 저장해둔 것을 쓰기 때문에 Library에서 작품 정보를 가져오지 않음
+#[derive(Serialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SearchResult {
+    LikeBook {
+        book_id: u32,
+        title: String,
+        created_at: DateTime<Utc>,
+    },
+    LikeBookTag {
+        tag_kind: String,
+        tag_name: String,
+        created_at: DateTime<Utc>,
+    },
+    History {
+        book_id: u32,
+        title: String,
+        updated_at: DateTime<Utc>,
+    },
+    Notification {
+        book_id: u32,
+        title: String,
+        created_at: DateTime<Utc>,
+    },
+}
+
+impl From<entity::SearchResult> for SearchResult {
+    fn from(result: entity::SearchResult) -> Self {
+        use entity::SearchResult::*;
+
+        match result {
+            LikeBook {
+                book_id,
+                title,
+                created_at,
+                ..
+            } => Self::LikeBook {
+                book_id,
+                title,
+                created_at,
+            },
+            LikeBookTag {
+                tag_kind,
+                tag_name,
+                created_at,
+                ..
+            } => Self::LikeBookTag {
+                tag_kind,
+                tag_name,
+                created_at,
+            },
+            History {
+                book_id,
+                title,
+                updated_at,
+                ..
+            } => Self::History {
+                book_id,
+                title,
+                updated_at,
+            },
+            Notification {
+                book_id,
+                title,
+                created_at,
+                ..
+            } => Self::Notification {
+                book_id,
+                title,
+                created_at,
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Presenter for Vec<SearchResult> {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use hyper::{header, Body, Request, Response, StatusCode};
+use madome_sdk::api::header::take_origin_response;
+use schemars::JsonSchema;
+use serde::Serialize;
+use util::http::SetResponse;
+
+use crate::{command::CommandSet, config::Config, entity};
+
+use super::{
+    like::{self, ReducedLike},
+    set_partial, Collection, CollectionWithItems, Like, Presenter,
+};
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Private,
+    Unlisted,
+    Public,
+}
+
+impl From<entity::Visibility> for Visibility {
+    fn from(visibility: entity::Visibility) -> Self {
+        match visibility {
+            entity::Visibility::Private => Self::Private,
+            entity::Visibility::Unlisted => Self::Unlisted,
+            entity::Visibility::Public => Self::Public,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct Share {
+    pub visibility: Visibility,
+    pub share_token: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl Presenter for Share {
+    async fn set_response(
+        self,
+        _request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        _command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let serialized = serde_json::to_vec(&self).expect("json serialize");
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
+
+/// 공유된 프로필
+///
+/// 로그인하지 않은 사용자도 볼 수 있으므로 이메일이나 사용자 아이디는 포함하지 않음
+pub struct SharedProfile {
+    pub name: String,
+    pub likes: Vec<Like>,
+    /// `Public`인 컬렉션만
+    pub collections: Vec<Collection>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "SharedProfile_for_{T}")]
+pub(crate) struct Serialized<T> {
+    name: String,
+    likes: Vec<T>,
+    collections: Vec<Collection>,
+}
+
+#[async_trait::async_trait]
+impl Presenter for SharedProfile {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        _config: Arc<Config>,
+        command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        let Self {
+            name,
+            likes,
+            collections,
+        } = self;
+
+        let serialized = match take_origin_response(request.headers()) {
+            // for internal
+            true => {
+                let likes = likes.into_iter().map(ReducedLike::from).collect::<Vec<_>>();
+
+                serde_json::to_vec(&Serialized {
+                    name,
+                    likes,
+                    collections,
+                })
+                .expect("json serialize")
+            }
+            // for external
+            false => match like::extend(&likes, request, &command).await {
+                Ok(likes) => serde_json::to_vec(&Serialized {
+                    name,
+                    likes,
+                    collections,
+                })
+                .expect("json serialize"),
+                Err(err) if err.is_library_error() => {
+                    log::warn!("library is unavailable, respond reduced likes: {err}");
+
+                    set_partial(resp);
+
+                    let likes = likes.into_iter().map(ReducedLike::from).collect::<Vec<_>>();
+
+                    serde_json::to_vec(&Serialized {
+                        name,
+                        likes,
+                        collections,
+                    })
+                    .expect("json serialize")
+                }
+                Err(err) => return Err(err),
+            },
+        };
+
+        resp.set_status(StatusCode::OK).unwrap();
+        resp.set_header(header::CONTENT_TYPE, "application/json")
+            .unwrap();
+        resp.set_body(serialized.into());
+
+        Ok(())
+    }
+}
+
+/// 공유 토큰으로 찾은 컬렉션 또는 프로필
+pub enum Shared {
+    Collection(CollectionWithItems),
+    Profile(SharedProfile),
+}
+
+#[async_trait::async_trait]
+impl Presenter for Shared {
+    async fn set_response(
+        self,
+        request: &mut Request<Body>,
+        resp: &mut Response<Body>,
+        config: Arc<Config>,
+        command: Arc<CommandSet>,
+    ) -> crate::Result<()> {
+        match self {
+            Self::Collection(collection) => {
+                collection
+                    .set_response(request, resp, config, command)
+                    .await
+            }
+            Self::Profile(profile) => profile.set_response(request, resp, config, command).await,
+        }
+    }
+}
@@ -2,14 +2,15 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use hyper::{header, Body, Request, Response, StatusCode};
+use schemars::JsonSchema;
 use serde::Serialize;
 use util::http::SetResponse;
 
-use crate::{config::Config, entity};
+use crate::{command::CommandSet, config::Config, entity};
 
 use super::Presenter;
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
 pub struct User {
     pub id: String,
     pub email: String,
@@ -26,6 +27,7 @@ impl Presenter for User {
         _request: &mut Request<Body>,
         resp: &mut Response<Body>,
         _config: Arc<Config>,
+        _command: Arc<CommandSet>,
     ) -> crate::Result<()> {
         let serialized = serde_json::to_vec(&self).expect("json serialize");
 
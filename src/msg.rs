@@ -1,23 +1,26 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use hyper::{Body, Method, Request, Response};
 
-use madome_sdk::api::{auth, cookie::MADOME_ACCESS_TOKEN, Token};
-use util::{
-    http::{
-        url::{is_path_variable, PathVariable},
-        Cookie,
-    },
-    BodyParser, ToPayload,
-};
+use madome_sdk::api::{auth, cookie::MADOME_ACCESS_TOKEN, BaseError, Token};
+use util::{http::Cookie, BodyParser, ToPayload};
 use uuid::Uuid;
 
 use crate::{
     config::Config,
+    entity::BlockKind,
+    metrics::{Metrics, Upstream},
+    router::{self, Access, Endpoint},
     usecase::{
-        create_like, create_notifications, create_or_update_fcm_token, create_or_update_history,
-        create_user, delete_history, delete_like, get_fcm_tokens, get_histories, get_histories_by,
-        get_likes, get_likes_by, get_notifications, get_user,
+        add_collection_item, create_block, create_collection, create_like, create_notifications,
+        create_or_update_fcm_token, create_or_update_history, create_or_update_rating, create_user,
+        delete_block, delete_collection, delete_collection_item, delete_history, delete_like,
+        delete_rating, follow_user, get_collection, get_collections, get_fcm_tokens, get_followers,
+        get_following, get_histories, get_histories_by, get_likes, get_likes_by, get_notifications,
+        get_rating_aggregates, get_ratings, get_recommendations, get_related_books,
+        get_search_results, get_shared, get_user, invalidate_library_cache,
+        reorder_collection_items, unfollow_user, update_collection, update_collection_visibility,
+        update_profile_visibility,
     },
 };
 
@@ -25,6 +28,10 @@ use crate::{
 pub enum Error {
     #[error("Not found")]
     NotFound,
+    #[error("Method not allowed")]
+    MethodNotAllowed(Vec<Method>),
+    #[error("Unauthorized")]
+    Unauthorized,
 }
 
 /// Msg의 Payload는 같은 이름의 usecase의 Payload와는 관계가 없음
@@ -41,6 +48,7 @@ pub enum Msg {
     GetLikes(get_likes::Payload),
     GetLikesBy(get_likes_by::Payload),
     DeleteLike(delete_like::Payload),
+    GetRelatedBooks(get_related_books::Payload),
 
     CreateNotifications(create_notifications::Payload),
     GetNotifications(get_notifications::Payload),
@@ -52,60 +60,87 @@ pub enum Msg {
     GetHistories(get_histories::Payload),
     GetHistoriesBy(get_histories_by::Payload),
     DeleteHistory(delete_history::Payload),
+
+    CreateCollection(create_collection::Payload),
+    GetCollections(get_collections::Payload),
+    GetCollection(get_collection::Payload),
+    UpdateCollection(update_collection::Payload),
+    DeleteCollection(delete_collection::Payload),
+    AddCollectionItem(add_collection_item::Payload),
+    DeleteCollectionItem(delete_collection_item::Payload),
+    ReorderCollectionItems(reorder_collection_items::Payload),
+
+    UpdateCollectionVisibility(update_collection_visibility::Payload),
+    UpdateProfileVisibility(update_profile_visibility::Payload),
+    GetShared(get_shared::Payload),
+
+    CreateOrUpdateRating(create_or_update_rating::Payload),
+    GetRatings(get_ratings::Payload),
+    DeleteRating(delete_rating::Payload),
+    GetRatingAggregates(get_rating_aggregates::Payload),
+
+    GetRecommendations(get_recommendations::Payload),
+
+    FollowUser(follow_user::Payload),
+    UnfollowUser(unfollow_user::Payload),
+    GetFollowers(get_followers::Payload),
+    GetFollowing(get_following::Payload),
+
+    CreateBlock(create_block::Payload),
+    DeleteBlock(delete_block::Payload),
+
+    GetSearchResults(get_search_results::Payload),
+
+    InvalidateLibraryCache(invalidate_library_cache::Payload),
+
+    GetOpenApi,
+    GetMetrics,
+    GetLiveness,
+    GetReadiness,
 }
 
 impl Msg {
     pub async fn http(
         request: &mut Request<Body>,
-        _resp: &mut Response<Body>,
+        resp: &mut Response<Body>,
         config: Arc<Config>,
+        metrics: &Metrics,
     ) -> crate::Result<Self> {
-        let headers = request.headers();
-
-        /* let cookie = Cookie::from(headers);
-
-        let access_token = cookie.get(MADOME_ACCESS_TOKEN).unwrap_or_default();
-        let refresh_token = cookie.get(MADOME_REFRESH_TOKEN).unwrap_or_default(); */
-
-        // let resp = RwLock::new(resp);
+        let (version, route, params) = router::route(request.method(), request.uri().path())?;
 
-        let user_id = match auth::check_internal(headers) {
-            Ok(_) => Uuid::nil(),
-            Err(_) => {
-                // 사용자 토큰 추가
-                /* if let Some(cookie) = headers.get(header::COOKIE).cloned() {
-                    let mut resp = resp.write();
-                    resp.set_header(header::COOKIE, cookie).unwrap();
-                } */
+        // Presenter에서 버전에 맞는 모양으로 응답하도록 남겨둠
+        request.extensions_mut().insert(version);
+        // 지표의 라벨로 씀
+        request.extensions_mut().insert(route);
 
-                let access_token = Cookie::from(headers)
-                    .take(MADOME_ACCESS_TOKEN)
-                    .unwrap_or_default();
-
-                auth::check_access_token(config.auth_url(), Token::from(access_token), 0)
-                    .await?
-                    .user_id
+        if let Some(deprecation) = route.deprecation {
+            deprecation.set_response(resp);
+        }
 
-                /* auth::check_and_refresh_token_pair(config.auth_url(), Token::Store(&resp), 0)
-                .await?
-                .user_id */
-            }
+        let user_id = match route.access {
+            Access::Public => authenticate(request, &config, metrics).await?,
+            // 내부 라우트는 있는지도 알려주지 않음
+            Access::Internal => match auth::check_internal(request.headers()) {
+                Ok(_) => Uuid::nil(),
+                Err(_) => return Err(Error::NotFound.into()),
+            },
+            // 로그인했으면 차단 여부를 확인하기 위해 사용자를 알아냄
+            Access::Either => match Cookie::from(request.headers()).take(MADOME_ACCESS_TOKEN) {
+                Some(_) => authenticate(request, &config, metrics)
+                    .await
+                    .unwrap_or_else(|_| Uuid::nil()),
+                None => Uuid::nil(),
+            },
         };
 
-        let method = request.method().clone();
-        let path = request.uri().path();
-        let user_checked = !user_id.is_nil();
-
-        let msg = match (method, path, user_checked) {
-            /* Public */
-            (Method::POST, "/users", true) => {
+        let msg = match route.endpoint {
+            Endpoint::CreateUser => {
                 let p = request.body_parse().await?;
 
                 Msg::CreateUser(p)
             }
 
-            /* Public */
-            (Method::GET, "/users/@me", true) => {
+            Endpoint::GetMe => {
                 let p = get_user::Payload {
                     id_or_email: user_id.to_string(),
                 };
@@ -113,145 +148,278 @@ impl Msg {
                 Msg::GetUser(p)
             }
 
-            /* Public */
-            (Method::POST, "/users/@me/likes", true) => {
+            Endpoint::GetUser => {
+                let p = get_user::Payload {
+                    id_or_email: params.get("user_id_or_email")?,
+                };
+
+                Msg::GetUser(p)
+            }
+
+            Endpoint::CreateLike => {
                 let p: create_like::Payload = request.to_payload(user_id).await?;
 
                 Msg::CreateLike(p)
             }
 
-            /* Public */
-            (Method::GET, "/users/@me/likes", true) => {
+            Endpoint::GetLikes => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::GetLikes(p)
             }
 
-            /* Public */
-            (Method::DELETE, "/users/@me/likes", true) => {
+            Endpoint::DeleteLike => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::DeleteLike(p)
             }
 
-            /* Public */
-            (Method::POST, "/users/@me/dislikes", true) => {
-                todo!()
+            Endpoint::GetLikesBy => {
+                let user_id = params.get::<Uuid>("user_id")?;
+
+                let p = request.to_payload(user_id).await?;
+
+                Msg::GetLikesBy(p)
             }
 
-            /* Public */
-            (Method::GET, "/users/@me/dislikes", true) => {
-                todo!()
+            Endpoint::GetRelatedBooks => {
+                let p = request.try_into()?;
+
+                Msg::GetRelatedBooks(p)
             }
 
-            /* Public */
-            (Method::DELETE, "/users/@me/dislikes", true) => {
-                todo!()
+            Endpoint::CreateNotifications => {
+                let p = request.body_parse().await?;
+
+                Msg::CreateNotifications(p)
             }
 
-            /* Public */
-            (Method::GET, "/users/@me/notifications", true) => {
+            Endpoint::GetNotifications => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::GetNotifications(p)
             }
 
-            /* Public */
-            (Method::POST, "/users/@me/fcm-token", true) => {
+            Endpoint::CreateOrUpdateFcmToken => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::CreateOrUpdateFcmToken(p)
             }
 
-            /* Public */
-            (Method::POST, "/users/@me/histories", true) => {
+            Endpoint::GetFcmTokens => {
+                let p = request.try_into()?;
+
+                Msg::GetFcmTokens(p)
+            }
+
+            Endpoint::CreateOrUpdateHistory => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::CreateOrUpdateHistory(p)
             }
 
-            /* Public */
-            (Method::DELETE, "/users/@me/histories", true) => {
+            Endpoint::GetHistories => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::GetHistories(p)
+            }
+
+            Endpoint::DeleteHistory => {
                 let p = request.to_payload(user_id).await?;
 
                 Msg::DeleteHistory(p)
             }
 
-            /* Public */
-            (Method::GET, "/users/@me/histories", true) => {
+            Endpoint::GetHistoriesBy => {
+                let user_id = params.get::<Uuid>("user_id")?;
+
                 let p = request.to_payload(user_id).await?;
 
-                Msg::GetHistories(p)
+                Msg::GetHistoriesBy(p)
             }
 
-            /* Internal */
-            (Method::POST, "/users/notifications", false) => {
-                let p = request.body_parse().await?;
+            Endpoint::CreateCollection => {
+                let p = request.to_payload(user_id).await?;
 
-                Msg::CreateNotifications(p)
+                Msg::CreateCollection(p)
+            }
+
+            Endpoint::GetCollections => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::GetCollections(p)
+            }
+
+            Endpoint::GetCollection => {
+                let p = get_collection::Payload {
+                    user_id,
+                    collection_id: params.get("collection_id")?,
+                };
+
+                Msg::GetCollection(p)
+            }
+
+            Endpoint::UpdateCollection => {
+                let collection_id = params.get::<Uuid>("collection_id")?;
+
+                let p = request.to_payload((user_id, collection_id)).await?;
+
+                Msg::UpdateCollection(p)
+            }
+
+            Endpoint::DeleteCollection => {
+                let p = delete_collection::Payload {
+                    user_id,
+                    collection_id: params.get("collection_id")?,
+                };
+
+                Msg::DeleteCollection(p)
+            }
+
+            Endpoint::AddCollectionItem => {
+                let collection_id = params.get::<Uuid>("collection_id")?;
+
+                let p = request.to_payload((user_id, collection_id)).await?;
+
+                Msg::AddCollectionItem(p)
             }
 
-            /* Internal */
-            (Method::GET, "/users/fcm-token", false) => {
+            Endpoint::DeleteCollectionItem => {
+                let collection_id = params.get::<Uuid>("collection_id")?;
+
+                let p = request.to_payload((user_id, collection_id)).await?;
+
+                Msg::DeleteCollectionItem(p)
+            }
+
+            Endpoint::ReorderCollectionItems => {
+                let collection_id = params.get::<Uuid>("collection_id")?;
+
+                let p = request.to_payload((user_id, collection_id)).await?;
+
+                Msg::ReorderCollectionItems(p)
+            }
+
+            Endpoint::UpdateCollectionVisibility => {
+                let collection_id = params.get::<Uuid>("collection_id")?;
+
+                let p = request.to_payload((user_id, collection_id)).await?;
+
+                Msg::UpdateCollectionVisibility(p)
+            }
+
+            Endpoint::UpdateProfileVisibility => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::UpdateProfileVisibility(p)
+            }
+
+            Endpoint::GetShared => {
+                let share_token = params.get::<String>("share_token")?;
+                let viewer_id = (!user_id.is_nil()).then_some(user_id);
+
+                let p = request.to_payload((share_token, viewer_id)).await?;
+
+                Msg::GetShared(p)
+            }
+
+            Endpoint::CreateOrUpdateRating => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::CreateOrUpdateRating(p)
+            }
+
+            Endpoint::GetRatings => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::GetRatings(p)
+            }
+
+            Endpoint::DeleteRating => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::DeleteRating(p)
+            }
+
+            Endpoint::GetRatingAggregates => {
                 let p = request.try_into()?;
 
-                Msg::GetFcmTokens(p)
+                Msg::GetRatingAggregates(p)
             }
 
-            /* Internal */
-            /* (Method::GET, "/users/likes", false) => {
-                let p = request.to_payload(Uuid::nil()).await?;
+            Endpoint::GetRecommendations => {
+                let p = request.to_payload(user_id).await?;
 
-                Msg::GetLikesBy(p)
-            } */
+                Msg::GetRecommendations(p)
+            }
 
-            /* Internal */
-            (Method::GET, path, false) if matcher(path, "/users/:user_id/likes") => {
-                let user_id = PathVariable::from((path, "/users/:user_id/likes"))
-                    .next_variable::<Uuid>()
-                    .unwrap_or_default();
+            Endpoint::FollowUser => {
+                let p = request.to_payload(user_id).await?;
 
+                Msg::FollowUser(p)
+            }
+
+            Endpoint::GetFollowing => {
                 let p = request.to_payload(user_id).await?;
 
-                Msg::GetLikesBy(p)
+                Msg::GetFollowing(p)
             }
 
-            (Method::GET, "/users/likes", false) => {
-                todo!()
+            Endpoint::UnfollowUser => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::UnfollowUser(p)
             }
 
-            /* Internal */
-            (Method::GET, path, false) if matcher(path, "/users/:user_id/dislikes") => {
-                todo!()
+            Endpoint::GetFollowers => {
+                let p = request.to_payload(user_id).await?;
+
+                Msg::GetFollowers(p)
             }
 
-            /* Internal */
-            /* (Method::GET, "/users/histories", false) => {
-                let p = request.to_payload(Uuid::nil());
+            Endpoint::CreateBlock => {
+                let p = request.to_payload((user_id, BlockKind::Block)).await?;
+
+                Msg::CreateBlock(p)
+            }
+
+            Endpoint::DeleteBlock => {
+                let p = request.to_payload((user_id, BlockKind::Block)).await?;
+
+                Msg::DeleteBlock(p)
+            }
+
+            Endpoint::CreateMute => {
+                let p = request.to_payload((user_id, BlockKind::Mute)).await?;
+
+                Msg::CreateBlock(p)
+            }
 
-                todo!()
-            } */
+            Endpoint::DeleteMute => {
+                let p = request.to_payload((user_id, BlockKind::Mute)).await?;
 
-            /* Internal */
-            (Method::GET, path, false) if matcher(path, "/users/:user_id/histories") => {
-                let user_id = PathVariable::from((path, "/users/:user_id/histories"))
-                    .next_variable::<Uuid>()
-                    .unwrap_or_default();
+                Msg::DeleteBlock(p)
+            }
 
+            Endpoint::GetSearchResults => {
                 let p = request.to_payload(user_id).await?;
 
-                Msg::GetHistoriesBy(p)
+                Msg::GetSearchResults(p)
             }
 
-            /* Internal */
-            (Method::GET, path, false) if matcher(path, "/users/:user_id_or_email") => {
-                let p: get_user::Payload =
-                    PathVariable::from((path, "/users/:user_id_or_email")).into();
+            Endpoint::InvalidateLibraryCache => {
+                let p = request.body_parse().await?;
 
-                Msg::GetUser(p)
+                Msg::InvalidateLibraryCache(p)
             }
 
-            _ => return Err(Error::NotFound.into()),
+            Endpoint::GetOpenApi => Msg::GetOpenApi,
+
+            Endpoint::GetMetrics => Msg::GetMetrics,
+
+            Endpoint::GetLiveness => Msg::GetLiveness,
+
+            Endpoint::GetReadiness => Msg::GetReadiness,
         };
 
         log::info!("{msg:?}");
@@ -260,21 +428,46 @@ impl Msg {
     }
 }
 
-fn matcher(req_path: &str, pattern: &str) -> bool {
-    let mut origin = req_path.split('/');
-    let pats = pattern.split('/');
-
-    for pat in pats {
-        if let Some(origin) = origin.next() {
-            if !is_path_variable(pat) && pat != origin {
-                return false;
-            }
-        } else {
-            return false;
-        }
+/// 로그인한 사용자를 알아냄
+///
+/// 다른 서비스의 요청에는 사용자가 없으므로 로그인하지 않은 것으로 봄
+#[tracing::instrument(skip_all)]
+async fn authenticate(
+    request: &Request<Body>,
+    config: &Config,
+    metrics: &Metrics,
+) -> crate::Result<Uuid> {
+    let headers = request.headers();
+
+    if auth::check_internal(headers).is_ok() {
+        return Err(Error::Unauthorized.into());
     }
 
-    origin.next().is_none()
+    let access_token = Cookie::from(headers)
+        .take(MADOME_ACCESS_TOKEN)
+        .unwrap_or_default();
+
+    // sdk에서 만드는 요청에는 요청 아이디를 붙일 수 없음
+    let start = Instant::now();
+    let r = auth::check_access_token(config.auth_url(), Token::from(access_token), 0).await;
+
+    metrics.observe_upstream(
+        Upstream::Auth,
+        "check_access_token",
+        start.elapsed(),
+        r.as_ref().err().map(is_auth_failure).unwrap_or(false),
+    );
+
+    Ok(r?.user_id)
+}
+
+/// 토큰이 유효하지 않은 것은 Auth의 실패가 아님
+fn is_auth_failure(err: &auth::Error) -> bool {
+    match err {
+        auth::Error::Base(BaseError::Undefined(code, _)) => code.is_server_error(),
+        auth::Error::Base(BaseError::Unauthorized | BaseError::PermissionDenied) => false,
+        _ => true,
+    }
 }
 
 /*
@@ -0,0 +1,540 @@
+//! 라우트 테이블과 usecase의 Payload, model로 OpenAPI 3 문서를 만듦
+//!
+//! `GET /users/openapi.json`으로 응답함
+
+use std::sync::OnceLock;
+
+use hyper::StatusCode;
+use madome_sdk::api::cookie::MADOME_ACCESS_TOKEN;
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{Schema, SchemaObject},
+    visit::Visitor,
+    JsonSchema,
+};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    error::ErrorBody,
+    model::{
+        collection, follow, health, history, like, notification, rating, recommendation, share,
+    },
+    model::{RatingAggregate, RelatedBook, SearchResult, User},
+    router::{self, Access, Endpoint, Version},
+    usecase::{
+        add_collection_item, create_block, create_collection, create_like, create_notifications,
+        create_or_update_fcm_token, create_or_update_history, create_or_update_rating, create_user,
+        delete_block, delete_collection_item, delete_history, delete_like, delete_rating,
+        follow_user, get_collections, get_fcm_tokens, get_followers, get_following, get_histories,
+        get_histories_by, get_likes, get_likes_by, get_notifications, get_rating_aggregates,
+        get_ratings, get_recommendations, get_related_books, get_search_results,
+        invalidate_library_cache, reorder_collection_items, unfollow_user, update_collection,
+        update_collection_visibility, update_profile_visibility,
+    },
+};
+
+const SECURITY_SCHEME: &str = "accessToken";
+
+static DOCUMENT: OnceLock<Value> = OnceLock::new();
+
+/// 라우트 테이블이 바뀌지 않으므로 한 번만 만듦
+pub fn document() -> &'static Value {
+    DOCUMENT.get_or_init(build)
+}
+
+/// 엔드포인트 하나의 요청과 응답
+struct Operation {
+    summary: &'static str,
+    /// 쿼리스트링으로 받는 Payload
+    query: Vec<Schema>,
+    /// JSON body로 받는 Payload
+    body: Option<Schema>,
+    status: StatusCode,
+    /// 없으면 빈 응답
+    response: Option<Schema>,
+    content_type: &'static str,
+}
+
+impl Operation {
+    fn new(summary: &'static str, status: StatusCode) -> Self {
+        Self {
+            summary,
+            query: Vec::new(),
+            body: None,
+            status,
+            response: None,
+            content_type: "application/json",
+        }
+    }
+
+    fn query<T: JsonSchema>(mut self, gen: &mut SchemaGenerator) -> Self {
+        self.query.push(T::json_schema(gen));
+        self
+    }
+
+    /// usecase의 Payload는 모두 이름이 `Payload`라서 components에 넣지 않고 그대로 씀
+    fn body<T: JsonSchema>(mut self, gen: &mut SchemaGenerator) -> Self {
+        self.body = Some(T::json_schema(gen));
+        self
+    }
+
+    fn response<T: JsonSchema>(mut self, gen: &mut SchemaGenerator) -> Self {
+        self.response = Some(gen.subschema_for::<T>());
+        self
+    }
+
+    /// 외부 요청에는 작품 정보가 붙은 모양으로, 내부 요청이나 Library가 응답하지 않을 때는 줄인 모양으로 응답함
+    fn extended_or_reduced<E: JsonSchema, R: JsonSchema>(self, gen: &mut SchemaGenerator) -> Self {
+        self.any_of(vec![gen.subschema_for::<E>(), gen.subschema_for::<R>()])
+    }
+
+    /// JSON이 아닌 문자열로 응답함
+    fn text(mut self, gen: &mut SchemaGenerator) -> Self {
+        self.response = Some(gen.subschema_for::<String>());
+        self.content_type = "text/plain";
+        self
+    }
+
+    fn any_of(mut self, schemas: Vec<Schema>) -> Self {
+        let mut schema = SchemaObject::default();
+        schema.subschemas().any_of = Some(schemas);
+
+        self.response = Some(schema.into());
+        self
+    }
+}
+
+fn operation(endpoint: Endpoint, gen: &mut SchemaGenerator) -> Operation {
+    use Endpoint::*;
+
+    let created = |summary| Operation::new(summary, StatusCode::CREATED);
+    let no_content = |summary| Operation::new(summary, StatusCode::NO_CONTENT);
+    let ok = |summary| Operation::new(summary, StatusCode::OK);
+
+    match endpoint {
+        CreateUser => created("사용자를 만듦").body::<create_user::Payload>(gen),
+        GetMe => ok("로그인한 사용자").response::<User>(gen),
+        GetUser => ok("아이디나 이메일로 사용자를 찾음").response::<User>(gen),
+
+        CreateLike => created("좋아요").body::<create_like::Payload>(gen),
+        GetLikes => ok("좋아요 목록")
+            .query::<get_likes::Payload>(gen)
+            .query::<like::BooksPayload>(gen)
+            .extended_or_reduced::<Vec<like::ExtendedLike>, Vec<like::ReducedLike>>(gen),
+        DeleteLike => no_content("좋아요 취소").body::<delete_like::Payload>(gen),
+        GetLikesBy => ok("작품이나 태그를 좋아요한 목록")
+            .query::<get_likes_by::Temp>(gen)
+            .response::<Vec<like::ReducedLike>>(gen),
+        GetRelatedBooks => ok("함께 좋아요한 작품")
+            .query::<get_related_books::Payload>(gen)
+            .response::<Vec<RelatedBook>>(gen),
+        CreateNotifications => created("알림을 보냄").body::<create_notifications::Payload>(gen),
+        GetNotifications => ok("알림 목록")
+            .query::<get_notifications::Payload>(gen)
+            .response::<Vec<notification::Notification>>(gen),
+
+        CreateOrUpdateFcmToken => {
+            created("FCM 토큰을 등록함").body::<create_or_update_fcm_token::Payload>(gen)
+        }
+        GetFcmTokens => ok("사용자들의 FCM 토큰")
+            .query::<get_fcm_tokens::Payload>(gen)
+            .response::<Vec<String>>(gen),
+
+        CreateOrUpdateHistory => created("열람 기록").body::<create_or_update_history::Payload>(gen),
+        GetHistories => ok("열람 기록 목록")
+            .query::<get_histories::Payload>(gen)
+            .extended_or_reduced::<Vec<history::ExtendedHistory>, Vec<history::ReducedHistory>>(
+                gen,
+            ),
+        DeleteHistory => no_content("열람 기록을 지움").body::<delete_history::Payload>(gen),
+        GetHistoriesBy => ok("작품들의 열람 기록")
+            .query::<get_histories_by::Payload>(gen)
+            .response::<Vec<history::ReducedHistory>>(gen),
+
+        CreateCollection => created("컬렉션을 만듦")
+            .body::<create_collection::Payload>(gen)
+            .response::<collection::Collection>(gen),
+        GetCollections => ok("컬렉션 목록")
+            .query::<get_collections::Payload>(gen)
+            .response::<Vec<collection::Collection>>(gen),
+        GetCollection => ok("컬렉션과 작품들").extended_or_reduced::<
+            collection::Serialized<collection::ExtendedCollectionItem>,
+            collection::Serialized<collection::ReducedCollectionItem>,
+        >(gen),
+        UpdateCollection => no_content("컬렉션을 고침").body::<update_collection::Payload>(gen),
+        DeleteCollection => no_content("컬렉션을 지움"),
+        AddCollectionItem => {
+            created("컬렉션에 작품을 넣음").body::<add_collection_item::Payload>(gen)
+        }
+        DeleteCollectionItem => {
+            no_content("컬렉션에서 작품을 뺌").body::<delete_collection_item::Payload>(gen)
+        }
+        ReorderCollectionItems => {
+            no_content("컬렉션의 작품 순서를 바꿈").body::<reorder_collection_items::Payload>(gen)
+        }
+        UpdateCollectionVisibility => ok("컬렉션의 공개 범위를 바꿈")
+            .body::<update_collection_visibility::Payload>(gen)
+            .response::<share::Share>(gen),
+
+        UpdateProfileVisibility => ok("프로필의 공개 범위를 바꿈")
+            .body::<update_profile_visibility::Payload>(gen)
+            .response::<share::Share>(gen),
+        GetShared => {
+            let schemas = vec![
+                gen.subschema_for::<collection::Serialized<collection::ExtendedCollectionItem>>(),
+                gen.subschema_for::<collection::Serialized<collection::ReducedCollectionItem>>(),
+                gen.subschema_for::<share::Serialized<like::ExtendedLike>>(),
+                gen.subschema_for::<share::Serialized<like::ReducedLike>>(),
+            ];
+
+            ok("공유 토큰으로 컬렉션이나 프로필을 봄")
+                .query::<get_likes::Payload>(gen)
+                .query::<like::BooksPayload>(gen)
+                .any_of(schemas)
+        }
+
+        CreateOrUpdateRating => created("평점").body::<create_or_update_rating::Payload>(gen),
+        GetRatings => ok("평점 목록")
+            .query::<get_ratings::Payload>(gen)
+            .extended_or_reduced::<Vec<rating::ExtendedRating>, Vec<rating::Rating>>(gen),
+        DeleteRating => no_content("평점을 지움").body::<delete_rating::Payload>(gen),
+        GetRatingAggregates => ok("작품들의 평점 통계")
+            .query::<get_rating_aggregates::Payload>(gen)
+            .response::<Vec<RatingAggregate>>(gen),
+
+        GetRecommendations => ok("추천 작품")
+            .query::<get_recommendations::Payload>(gen)
+            .extended_or_reduced::<
+                Vec<recommendation::ExtendedRecommendation>,
+                Vec<recommendation::ReducedRecommendation>,
+            >(gen),
+
+        FollowUser => created("팔로우").body::<follow_user::Payload>(gen),
+        GetFollowing => ok("팔로우한 사용자 목록")
+            .query::<get_following::Payload>(gen)
+            .response::<Vec<follow::Follow>>(gen),
+        UnfollowUser => no_content("팔로우 취소").body::<unfollow_user::Payload>(gen),
+        GetFollowers => ok("팔로워 목록")
+            .query::<get_followers::Payload>(gen)
+            .response::<Vec<follow::Follow>>(gen),
+
+        CreateBlock => created("사용자를 차단함").body::<create_block::Target>(gen),
+        DeleteBlock => no_content("차단을 풂").body::<delete_block::Target>(gen),
+        CreateMute => created("사용자를 숨김").body::<create_block::Target>(gen),
+        DeleteMute => no_content("숨김을 풂").body::<delete_block::Target>(gen),
+
+        GetSearchResults => ok("좋아요, 컬렉션, 열람 기록에서 찾음")
+            .query::<get_search_results::Payload>(gen)
+            .response::<Vec<SearchResult>>(gen),
+
+        InvalidateLibraryCache => {
+            no_content("Library 캐시를 비움").body::<invalidate_library_cache::Payload>(gen)
+        }
+
+        GetOpenApi => ok("이 문서").response::<Value>(gen),
+        GetMetrics => ok("Prometheus 지표").text(gen),
+
+        GetLiveness => ok("프로세스가 살아있음").response::<health::Serialized>(gen),
+        GetReadiness => ok("의존성마다 확인한 결과, 하나라도 실패하면 503")
+            .response::<health::Serialized>(gen),
+    }
+}
+
+/// 변수가 있는 라우트의 경로를 OpenAPI의 모양으로 바꿈
+///
+/// `/users/:user_id/likes` -> `/users/{user_id}/likes`
+fn path(route_path: &str) -> (String, Vec<&str>) {
+    let mut params = Vec::new();
+
+    let path = route_path
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => {
+                params.push(name);
+                format!("{{{name}}}")
+            }
+            None => segment.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (path, params)
+}
+
+/// 스키마의 속성을 `(이름, 스키마, 필수)`로 펼침
+///
+/// 태그가 있는 enum은 variant마다 속성을 모으고, 모든 variant에 필수인 속성만 필수로 봄
+fn properties(schema: &Schema) -> Vec<(String, Schema, bool)> {
+    let schema = match schema {
+        Schema::Object(x) => x,
+        Schema::Bool(_) => return Vec::new(),
+    };
+
+    let mut r = match &schema.object {
+        Some(object) => object
+            .properties
+            .iter()
+            .map(|(name, x)| (name.clone(), x.clone(), object.required.contains(name)))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let variants = schema
+        .subschemas
+        .as_ref()
+        .and_then(|x| x.one_of.as_ref().or(x.any_of.as_ref()));
+
+    if let Some(variants) = variants {
+        let variants = variants.iter().map(properties).collect::<Vec<_>>();
+
+        for (name, x, _) in variants.iter().flatten() {
+            if r.iter().any(|(y, _, _)| y == name) {
+                continue;
+            }
+
+            let required = variants
+                .iter()
+                .all(|xs| xs.iter().any(|(y, _, required)| y == name && *required));
+
+            r.push((name.clone(), x.clone(), required));
+        }
+    }
+
+    r
+}
+
+fn visit(gen: &mut SchemaGenerator, mut schema: Schema) -> Schema {
+    for visitor in gen.visitors_mut() {
+        visitor.visit_schema(&mut schema);
+    }
+
+    schema
+}
+
+fn to_value(gen: &mut SchemaGenerator, schema: Schema) -> Value {
+    serde_json::to_value(visit(gen, schema)).expect("json serialize")
+}
+
+fn build() -> Value {
+    let mut gen = SchemaSettings::openapi3().into_generator();
+    let mut paths = Map::new();
+
+    let error = gen.subschema_for::<ErrorBody>();
+    let error = to_value(&mut gen, error);
+
+    for route in router::routes(Version::V1) {
+        let (path, path_params) = path(route.path);
+
+        let Operation {
+            summary,
+            query,
+            body,
+            status,
+            response,
+            content_type,
+        } = operation(route.endpoint, &mut gen);
+
+        let mut parameters = path_params
+            .into_iter()
+            .map(|name| {
+                let schema = match name.ends_with("_id") {
+                    true => json!({ "type": "string", "format": "uuid" }),
+                    false => json!({ "type": "string" }),
+                };
+
+                json!({ "name": name, "in": "path", "required": true, "schema": schema })
+            })
+            .collect::<Vec<_>>();
+
+        for (name, schema, required) in query.iter().flat_map(properties) {
+            let schema = to_value(&mut gen, schema);
+
+            parameters.push(json!({
+                "name": name,
+                "in": "query",
+                "required": required,
+                "schema": schema,
+            }));
+        }
+
+        let mut operation = json!({
+            "operationId": format!("{:?}", route.endpoint),
+            "summary": summary,
+            "x-access": format!("{:?}", route.access).to_lowercase(),
+            "parameters": parameters,
+        });
+
+        if let Some(body) = body {
+            operation["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": to_value(&mut gen, body) } },
+            });
+        }
+
+        let mut resp = json!({
+            "description": status.canonical_reason().unwrap_or_default(),
+        });
+
+        if let Some(schema) = response {
+            resp["content"] = json!({ content_type: { "schema": to_value(&mut gen, schema) } });
+        }
+
+        operation["responses"] = json!({
+            status.as_str(): resp,
+            "default": {
+                "description": "Error",
+                "content": { "application/json": { "schema": error } },
+            },
+        });
+
+        match route.access {
+            Access::Public => operation["security"] = json!([{ SECURITY_SCHEME: [] }]),
+            // 로그인하지 않아도 됨
+            Access::Either => operation["security"] = json!([{}, { SECURITY_SCHEME: [] }]),
+            // 게이트웨이를 거치지 않은 요청만 받음
+            Access::Internal => operation["security"] = json!([]),
+        }
+
+        if route.deprecation.is_some() {
+            operation["deprecated"] = json!(true);
+        }
+
+        let method = route.method.as_str().to_lowercase();
+
+        paths
+            .entry(path)
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap()
+            .insert(method, operation);
+    }
+
+    let mut schemas = gen.take_definitions();
+
+    for schema in schemas.values_mut() {
+        *schema = visit(&mut gen, schema.clone());
+    }
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "user.madome.app",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        // 버전이 없는 경로는 v1으로 봄
+        "servers": [{ "url": "/" }, { "url": Version::V1.prefix() }],
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "securitySchemes": {
+                SECURITY_SCHEME: {
+                    "type": "apiKey",
+                    "in": "cookie",
+                    "name": MADOME_ACCESS_TOKEN,
+                },
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::router::{self, Version};
+
+    use super::{document, path};
+
+    fn refs<'a>(value: &'a Value, r: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(object) => {
+                for (key, x) in object {
+                    match (key.as_str(), x) {
+                        ("$ref", Value::String(x)) => r.push(x),
+                        _ => refs(x, r),
+                    }
+                }
+            }
+            Value::Array(xs) => xs.iter().for_each(|x| refs(x, r)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn every_route_documented() {
+        let doc = document();
+
+        for route in router::routes(Version::V1) {
+            let (path, params) = path(route.path);
+            let method = route.method.as_str().to_lowercase();
+
+            let operation = &doc["paths"][&path][&method];
+            assert!(operation.is_object(), "{method} {path} is not documented");
+
+            let access = format!("{:?}", route.access).to_lowercase();
+            assert_eq!(operation["x-access"], access, "{method} {path}");
+
+            let documented = operation["parameters"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|x| x["in"] == "path")
+                .map(|x| x["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(documented, params, "{method} {path}");
+        }
+    }
+
+    #[test]
+    fn no_stale_operation() {
+        let routes = router::routes(Version::V1)
+            .iter()
+            .map(|x| (path(x.path).0, x.method.as_str().to_lowercase()))
+            .collect::<Vec<_>>();
+
+        for (path, operations) in document()["paths"].as_object().unwrap() {
+            for method in operations.as_object().unwrap().keys() {
+                assert!(
+                    routes.contains(&(path.clone(), method.clone())),
+                    "{method} {path} is not routed"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_ref_resolves() {
+        let doc = document();
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+
+        let mut r = Vec::new();
+        refs(doc, &mut r);
+        assert!(!r.is_empty());
+
+        for x in r {
+            let name = x.strip_prefix("#/components/schemas/").unwrap();
+            assert!(schemas.contains_key(name), "{x} is not in components");
+        }
+    }
+
+    #[test]
+    fn server_filled_fields_hidden() {
+        let doc = document();
+
+        let parameters = doc["paths"]["/users/@me/likes"]["get"]["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|x| x["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert!(parameters.contains(&"per-page"));
+        assert!(parameters.contains(&"books-per-page"));
+        assert!(!parameters.contains(&"user-id"));
+        assert!(!parameters.contains(&"user_id"));
+
+        let body = &doc["paths"]["/users/@me/collections/{collection_id}/items"]["post"]
+            ["requestBody"]["content"]["application/json"]["schema"];
+        assert!(body["properties"].get("user_id").is_none());
+        assert!(body["properties"].get("collection_id").is_none());
+    }
+}
@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::entity;
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum CollectionSortBy {
+    CreatedAtDesc,
+    CreatedAtAsc,
+    UpdatedAtDesc,
+    UpdatedAtAsc,
+}
+
+impl From<CollectionSortBy> for entity::CollectionSortBy {
+    fn from(sort_by: CollectionSortBy) -> Self {
+        use entity::CollectionSortBy::*;
+        use entity::Sort::*;
+
+        match sort_by {
+            CollectionSortBy::CreatedAtDesc => CreatedAt(Desc),
+            CollectionSortBy::CreatedAtAsc => CreatedAt(Asc),
+            CollectionSortBy::UpdatedAtDesc => UpdatedAt(Desc),
+            CollectionSortBy::UpdatedAtAsc => UpdatedAt(Asc),
+        }
+    }
+}
@@ -13,10 +13,48 @@ pub enum Error {
 
     #[error("book-id: {0}")]
     InvalidBookId(number::Error<u32>),
+    #[error("book-ids: length {0}")]
+    InvalidBookIds(number::Error<usize>),
     #[error("per-page: {0}")]
     InvalidPerPage(number::Error<usize>),
     #[error("page: {0}")]
     InvalidPage(number::Error<usize>),
     #[error("sort-by: {0}")]
     InvalidSortBy(String),
+    #[error("created-after: should be earlier than created-before")]
+    InvalidDateRange,
+}
+
+impl Error {
+    /// 클라이언트가 분기에 쓰는 코드
+    ///
+    /// 한 번 정한 코드는 바꾸지 않음
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotSupportedContentType(_) => "not_supported_content_type",
+            Self::JsonDeserialize(_) => "invalid_json",
+            Self::QuerystringDeserialize(_) => "invalid_querystring",
+            Self::InvalidBookId(_) => "invalid_book_id",
+            Self::InvalidBookIds(_) => "invalid_book_ids",
+            Self::InvalidPerPage(_) => "invalid_per_page",
+            Self::InvalidPage(_) => "invalid_page",
+            Self::InvalidSortBy(_) => "invalid_sort_by",
+            Self::InvalidDateRange => "invalid_date_range",
+        }
+    }
+
+    /// 잘못된 쿼리스트링의 이름
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidBookId(_) => Some("book-id"),
+            Self::InvalidBookIds(_) => Some("book-ids"),
+            Self::InvalidPerPage(_) => Some("per-page"),
+            Self::InvalidPage(_) => Some("page"),
+            Self::InvalidSortBy(_) => Some("sort-by"),
+            Self::InvalidDateRange => Some("created-after"),
+            Self::NotSupportedContentType(_)
+            | Self::JsonDeserialize(_)
+            | Self::QuerystringDeserialize(_) => None,
+        }
+    }
 }
@@ -0,0 +1,24 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::entity;
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum FollowSortBy {
+    CreatedAtDesc,
+    CreatedAtAsc,
+}
+
+impl From<FollowSortBy> for entity::FollowSortBy {
+    fn from(sort_by: FollowSortBy) -> Self {
+        use entity::FollowSortBy::*;
+        use entity::Sort::*;
+
+        match sort_by {
+            FollowSortBy::CreatedAtDesc => CreatedAt(Desc),
+            FollowSortBy::CreatedAtAsc => CreatedAt(Asc),
+        }
+    }
+}
@@ -1,9 +1,10 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::entity;
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum HistoryKind {
     Book,
@@ -20,13 +21,19 @@ impl From<HistoryKind> for entity::HistoryKind {
 }
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum HistorySortBy {
     CreatedAtDesc,
     CreatedAtAsc,
     UpdatedAtDesc,
     UpdatedAtAsc,
+    TitleAsc,
+    TitleDesc,
+    PublishedAtDesc,
+    PublishedAtAsc,
+    PopularityDesc,
+    PopularityAsc,
     Random,
 }
 
@@ -40,6 +47,12 @@ impl From<HistorySortBy> for entity::HistorySortBy {
             HistorySortBy::CreatedAtAsc => CreatedAt(Asc),
             HistorySortBy::UpdatedAtDesc => UpdatedAt(Desc),
             HistorySortBy::UpdatedAtAsc => UpdatedAt(Asc),
+            HistorySortBy::TitleAsc => Title(Asc),
+            HistorySortBy::TitleDesc => Title(Desc),
+            HistorySortBy::PublishedAtDesc => PublishedAt(Desc),
+            HistorySortBy::PublishedAtAsc => PublishedAt(Asc),
+            HistorySortBy::PopularityDesc => Popularity(Desc),
+            HistorySortBy::PopularityAsc => Popularity(Asc),
             HistorySortBy::Random => Random,
         }
     }
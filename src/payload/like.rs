@@ -1,9 +1,10 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::entity;
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum LikeKind {
     Book,
@@ -22,11 +23,17 @@ impl From<LikeKind> for entity::LikeKind {
 }
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum LikeSortBy {
     CreatedAtDesc,
     CreatedAtAsc,
+    TitleAsc,
+    TitleDesc,
+    PublishedAtDesc,
+    PublishedAtAsc,
+    PopularityDesc,
+    PopularityAsc,
     Random,
 }
 
@@ -38,6 +45,12 @@ impl From<LikeSortBy> for entity::LikeSortBy {
         match sort_by {
             LikeSortBy::CreatedAtDesc => CreatedAt(Desc),
             LikeSortBy::CreatedAtAsc => CreatedAt(Asc),
+            LikeSortBy::TitleAsc => Title(Asc),
+            LikeSortBy::TitleDesc => Title(Desc),
+            LikeSortBy::PublishedAtDesc => PublishedAt(Desc),
+            LikeSortBy::PublishedAtAsc => PublishedAt(Asc),
+            LikeSortBy::PopularityDesc => Popularity(Desc),
+            LikeSortBy::PopularityAsc => Popularity(Asc),
             LikeSortBy::Random => Random,
         }
     }
@@ -1,6 +1,10 @@
+pub mod collection;
 mod error;
+pub mod follow;
 pub mod history;
 pub mod like;
 pub mod notification;
+pub mod rating;
+pub mod share;
 
 pub use error::Error;
@@ -1,12 +1,15 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::entity;
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum NotificationKind {
     Book,
+    User,
+    BookSeries,
 }
 
 impl From<NotificationKind> for entity::NotificationKind {
@@ -15,12 +18,14 @@ impl From<NotificationKind> for entity::NotificationKind {
 
         match kind {
             NotificationKind::Book => Book,
+            NotificationKind::User => User,
+            NotificationKind::BookSeries => BookSeries,
         }
     }
 }
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum NotificationSortBy {
     CreatedAtDesc,
@@ -40,7 +45,7 @@ impl From<NotificationSortBy> for entity::NotificationSortBy {
 }
 
 /* #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct NotificationBook {
     pub book_id: u32,
     pub book_tags: Vec<(String, String)>,
@@ -0,0 +1,32 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::entity;
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RatingSortBy {
+    CreatedAtDesc,
+    CreatedAtAsc,
+    UpdatedAtDesc,
+    UpdatedAtAsc,
+    ScoreDesc,
+    ScoreAsc,
+}
+
+impl From<RatingSortBy> for entity::RatingSortBy {
+    fn from(sort_by: RatingSortBy) -> Self {
+        use entity::RatingSortBy::*;
+        use entity::Sort::*;
+
+        match sort_by {
+            RatingSortBy::CreatedAtDesc => CreatedAt(Desc),
+            RatingSortBy::CreatedAtAsc => CreatedAt(Asc),
+            RatingSortBy::UpdatedAtDesc => UpdatedAt(Desc),
+            RatingSortBy::UpdatedAtAsc => UpdatedAt(Asc),
+            RatingSortBy::ScoreDesc => Score(Desc),
+            RatingSortBy::ScoreAsc => Score(Asc),
+        }
+    }
+}
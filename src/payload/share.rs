@@ -0,0 +1,25 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::entity;
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+/// 응답의 `Visibility`와 이름이 겹치지 않도록 스키마 이름을 바꿈
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "VisibilityPayload")]
+pub enum Visibility {
+    Private,
+    Unlisted,
+    Public,
+}
+
+impl From<Visibility> for entity::Visibility {
+    fn from(visibility: Visibility) -> Self {
+        match visibility {
+            Visibility::Private => Self::Private,
+            Visibility::Unlisted => Self::Unlisted,
+            Visibility::Public => Self::Public,
+        }
+    }
+}
@@ -5,19 +5,35 @@ mod root_registry {
 
     use crate::{
         app::{HttpServer, Resolver},
+        cache::LibraryCache,
         command::{
-            has_book::HasBook, has_book_tag::HasBookTag, send_notification::SendNotification,
+            client::LibraryClient, get_books_by_ids::GetBooksByIds,
+            get_books_by_tags::GetBooksByTags, has_book::HasBook, has_book_tag::HasBookTag,
+            has_book_tags::HasBookTags, has_books::HasBooks, send_notification::SendNotification,
             CommandSet,
         },
         config::Config,
         database::DatabaseSet,
+        health::Health,
+        job::{BackfillBookTitles, RefreshRelatedBooks},
+        metrics::Metrics,
         repository::{
-            PostgresqlDislikeRepository, PostgresqlFcmTokenRepository, PostgresqlHistoryRepository,
-            PostgresqlLikeRepository, PostgresqlNotificationRepository, PostgresqlUserRepository,
-            RepositorySet,
+            PostgresqlBackend, PostgresqlBlockRepository, PostgresqlCollectionRepository,
+            PostgresqlDislikeRepository, PostgresqlFcmTokenRepository, PostgresqlFollowRepository,
+            PostgresqlHistoryRepository, PostgresqlLikeRepository,
+            PostgresqlNotificationRepository, PostgresqlProfileRepository,
+            PostgresqlRatingRepository, PostgresqlRelatedBookRepository,
+            PostgresqlSearchRepository, PostgresqlUserRepository, RepositorySet,
         },
     };
 
+    type Server = HttpServer<PostgresqlBackend>;
+    type Controller = Resolver<PostgresqlBackend>;
+    type Repository = RepositorySet<PostgresqlBackend>;
+    type RelatedBooksJob = RefreshRelatedBooks<PostgresqlBackend>;
+    type BookTitlesJob = BackfillBookTitles<PostgresqlBackend>;
+    type Readiness = Health<PostgresqlBackend>;
+
     combine_component_registry!(
         RootRegistry,
         [
@@ -25,72 +41,206 @@ mod root_registry {
             ControllerRegistry,
             RepositoryRegistry,
             CommandRegistry,
+            CacheRegistry,
+            MetricsRegistry,
+            HealthRegistry,
+            JobRegistry,
             ConfigRegistry
         ]
     );
 
-    component_registry!(ServerRegistry, [HttpServer]);
+    component_registry!(ServerRegistry, [Server]);
 
-    component_registry!(ControllerRegistry, [Resolver]);
+    component_registry!(ControllerRegistry, [Controller]);
 
     component_registry!(
         RepositoryRegistry,
         [
             DatabaseSet,
-            RepositorySet,
+            Repository,
             PostgresqlUserRepository,
             PostgresqlLikeRepository,
             PostgresqlDislikeRepository,
             PostgresqlNotificationRepository,
             PostgresqlFcmTokenRepository,
-            PostgresqlHistoryRepository
+            PostgresqlHistoryRepository,
+            PostgresqlCollectionRepository,
+            PostgresqlProfileRepository,
+            PostgresqlRatingRepository,
+            PostgresqlRelatedBookRepository,
+            PostgresqlFollowRepository,
+            PostgresqlBlockRepository,
+            PostgresqlSearchRepository
         ]
     );
 
     component_registry!(
         CommandRegistry,
-        [CommandSet, SendNotification, HasBook, HasBookTag]
+        [
+            CommandSet,
+            SendNotification,
+            HasBook,
+            HasBookTag,
+            HasBooks,
+            HasBookTags,
+            GetBooksByIds,
+            GetBooksByTags,
+            LibraryClient
+        ]
     );
 
+    component_registry!(CacheRegistry, [LibraryCache]);
+
+    component_registry!(MetricsRegistry, [Metrics]);
+
+    component_registry!(HealthRegistry, [Readiness]);
+
+    component_registry!(JobRegistry, [RelatedBooksJob, BookTitlesJob]);
+
     component_registry!(ConfigRegistry, [Config]);
 }
-/*
 #[cfg(test)]
 pub mod tests {
-    use sai::{combine_component_registry, component_registry, Component};
+    use sai::{combine_component_registry, component_registry, Component, System};
 
     use crate::{
         app::{HttpServer, Resolver},
+        cache::LibraryCache,
+        command::{
+            client::LibraryClient, get_books_by_ids::GetBooksByIds,
+            get_books_by_tags::GetBooksByTags, has_book::HasBook, has_book_tag::HasBookTag,
+            has_book_tags::HasBookTags, has_books::HasBooks, send_notification::SendNotification,
+            CommandSet,
+        },
         config::Config,
-        repository::{InMemoryLikeRepository, InMemoryUserRepository, RepositorySet},
+        database::InMemoryDatabase,
+        health::Health,
+        metrics::Metrics,
+        repository::{
+            InMemoryBackend, InMemoryBlockRepository, InMemoryCollectionRepository,
+            InMemoryDislikeRepository, InMemoryFcmTokenRepository, InMemoryFollowRepository,
+            InMemoryHistoryRepository, InMemoryLikeRepository, InMemoryNotificationRepository,
+            InMemoryProfileRepository, InMemoryRatingRepository, InMemoryRelatedBookRepository,
+            InMemorySearchRepository, InMemoryUserRepository, RepositorySet,
+        },
     };
 
+    type Server = HttpServer<InMemoryBackend>;
+    type Controller = Resolver<InMemoryBackend>;
+    type Repository = RepositorySet<InMemoryBackend>;
+    type Readiness = Health<InMemoryBackend>;
+
+    /// Postgres 없이 Resolver를 띄움
+    ///
+    /// HttpServer는 포트를 열어야 해서 빠져있음
     combine_component_registry!(
-        RootRegistry,
+        InMemoryRegistry,
+        [
+            ControllerRegistry,
+            RepositoryRegistry,
+            CommandRegistry,
+            CacheRegistry,
+            MetricsRegistry,
+            HealthRegistry,
+            ConfigRegistry
+        ]
+    );
+
+    /// HttpServer까지 띄워서 실제로 요청을 보낼 때 씀
+    combine_component_registry!(
+        E2eRegistry,
         [
             ServerRegistry,
             ControllerRegistry,
             RepositoryRegistry,
-            // CommandRegistry,
+            CommandRegistry,
+            CacheRegistry,
+            MetricsRegistry,
+            HealthRegistry,
             ConfigRegistry
         ]
     );
 
-    component_registry!(ServerRegistry, [HttpServer]);
+    component_registry!(ServerRegistry, [Server]);
 
-    component_registry!(ControllerRegistry, [Resolver]);
+    component_registry!(ControllerRegistry, [Controller]);
 
     component_registry!(
         RepositoryRegistry,
         [
-            RepositorySet,
+            InMemoryDatabase,
+            Repository,
             InMemoryUserRepository,
-            InMemoryLikeRepository
+            InMemoryLikeRepository,
+            InMemoryDislikeRepository,
+            InMemoryNotificationRepository,
+            InMemoryFcmTokenRepository,
+            InMemoryHistoryRepository,
+            InMemoryCollectionRepository,
+            InMemoryProfileRepository,
+            InMemoryRatingRepository,
+            InMemoryRelatedBookRepository,
+            InMemoryFollowRepository,
+            InMemoryBlockRepository,
+            InMemorySearchRepository
         ]
     );
 
-    // component_registry!(CommandRegistry, [CommandSet]);
+    component_registry!(
+        CommandRegistry,
+        [
+            CommandSet,
+            SendNotification,
+            HasBook,
+            HasBookTag,
+            HasBooks,
+            HasBookTags,
+            GetBooksByIds,
+            GetBooksByTags,
+            LibraryClient
+        ]
+    );
+
+    component_registry!(CacheRegistry, [LibraryCache]);
+
+    component_registry!(MetricsRegistry, [Metrics]);
+
+    component_registry!(HealthRegistry, [Readiness]);
 
     component_registry!(ConfigRegistry, [Config]);
+
+    /// Config에서 꼭 필요한 환경 변수가 없으면 채워넣음
+    pub fn set_env() {
+        for (key, value) in [
+            ("PORT", "3112"),
+            ("POSTGRES_PORT", "5432"),
+            ("POSTGRES_HOST", "localhost"),
+            ("POSTGRES_USER", "postgres"),
+            ("POSTGRES_PW", "postgres"),
+            ("POSTGRES_DB", "madome_user"),
+            ("MADOME_AUTH_URL", "http://localhost:3111"),
+            ("MADOME_LIBRARY_URL", "http://localhost:3113"),
+        ] {
+            if std::env::var(key).is_err() {
+                std::env::set_var(key, value);
+            }
+        }
+    }
+
+    pub async fn system() -> System<InMemoryRegistry> {
+        set_env();
+
+        let mut system = System::<InMemoryRegistry>::new();
+
+        system.start().await;
+
+        system
+    }
+
+    #[tokio::test]
+    async fn start_without_postgres() {
+        let mut system = system().await;
+
+        system.stop().await;
+    }
 }
- */
@@ -0,0 +1,121 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{Block, BlockKind},
+    repository::r#trait::BlockRepository,
+};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryBlockRepository {
+    inner: RwLock<HashMap<(Uuid, Uuid, BlockKind), Block>>,
+}
+
+impl InMemoryBlockRepository {
+    /// `retain_unblocked`와 같은 조건, 다른 저장소에서 목록을 가져올 때도 씀
+    pub(super) fn unblocked(&self, user_id: Uuid, other: Uuid, exclude_muted: bool) -> bool {
+        let inner = self.inner.read().unwrap();
+
+        let blocked = inner.contains_key(&(user_id, other, BlockKind::Block))
+            || inner.contains_key(&(other, user_id, BlockKind::Block));
+        let muted = exclude_muted && inner.contains_key(&(other, user_id, BlockKind::Mute));
+
+        !blocked && !muted
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockRepository for InMemoryBlockRepository {
+    async fn add(&self, block: Block) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let key = (block.user_id, block.target_id, block.kind);
+
+        if inner.contains_key(&key) {
+            return Ok(false);
+        }
+
+        inner.insert(key, block);
+
+        Ok(true)
+    }
+
+    async fn remove(&self, user_id: Uuid, target_id: Uuid, kind: BlockKind) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        Ok(inner.remove(&(user_id, target_id, kind)).is_some())
+    }
+
+    async fn retain_unblocked(
+        &self,
+        user_id: Uuid,
+        user_ids: Vec<Uuid>,
+        exclude_muted: bool,
+    ) -> crate::Result<Vec<Uuid>> {
+        let r = user_ids
+            .into_iter()
+            .filter(|x| self.unblocked(user_id, *x, exclude_muted))
+            .collect();
+
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Block, BlockKind},
+        repository::r#trait::BlockRepository,
+    };
+
+    use super::InMemoryBlockRepository;
+
+    #[tokio::test]
+    async fn retain_unblocked() {
+        let repository = InMemoryBlockRepository::default();
+
+        let me = Uuid::new_v4();
+        let blocked = Uuid::new_v4();
+        let blocking = Uuid::new_v4();
+        let muting = Uuid::new_v4();
+        let other = Uuid::new_v4();
+
+        let blocks = [
+            Block::new(me, blocked, BlockKind::Block),
+            Block::new(blocking, me, BlockKind::Block),
+            Block::new(muting, me, BlockKind::Mute),
+        ];
+
+        for block in blocks {
+            assert!(repository.add(block).await.unwrap());
+        }
+
+        let user_ids = vec![blocked, blocking, muting, other];
+
+        let r = repository
+            .retain_unblocked(me, user_ids.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(r, vec![muting, other]);
+
+        let r = repository
+            .retain_unblocked(me, user_ids, true)
+            .await
+            .unwrap();
+        assert_eq!(r, vec![other]);
+
+        assert!(repository
+            .remove(muting, me, BlockKind::Mute)
+            .await
+            .unwrap());
+        assert!(!repository
+            .remove(muting, me, BlockKind::Block)
+            .await
+            .unwrap());
+    }
+}
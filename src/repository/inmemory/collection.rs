@@ -0,0 +1,283 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use itertools::Itertools;
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{Collection, CollectionItem, CollectionSortBy, Sort, Visibility},
+    repository::r#trait::CollectionRepository,
+};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryCollectionRepository {
+    inner: RwLock<HashMap<Uuid, (Collection, Vec<CollectionItem>)>>,
+}
+
+#[async_trait::async_trait]
+impl CollectionRepository for InMemoryCollectionRepository {
+    async fn get(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<Option<Collection>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .get(&collection_id)
+            .filter(|(x, _)| x.user_id == user_id)
+            .map(|(x, _)| x.clone());
+
+        Ok(r)
+    }
+
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Collection>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .map(|(x, _)| x)
+            .find(|x| {
+                x.visibility != Visibility::Private
+                    && x.share_token.as_deref() == Some(share_token.as_str())
+            })
+            .cloned();
+
+        Ok(r)
+    }
+
+    async fn get_many_public(&self, user_id: Uuid) -> crate::Result<Vec<Collection>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .map(|(x, _)| x)
+            .filter(|x| x.user_id == user_id && x.visibility == Visibility::Public)
+            .sorted_by(|a, b| b.updated_at.cmp(&a.updated_at))
+            .cloned()
+            .collect();
+
+        Ok(r)
+    }
+
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: CollectionSortBy,
+    ) -> crate::Result<Vec<Collection>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .map(|(x, _)| x)
+            .filter(|x| x.user_id == user_id);
+
+        let r = match sort_by {
+            CollectionSortBy::CreatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.created_at.cmp(&a.created_at))
+            }
+            CollectionSortBy::CreatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.created_at.cmp(&b.created_at))
+            }
+            CollectionSortBy::UpdatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.updated_at.cmp(&a.updated_at))
+            }
+            CollectionSortBy::UpdatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.updated_at.cmp(&b.updated_at))
+            }
+        }
+        .skip(per_page * (page - 1))
+        .take(per_page)
+        .cloned()
+        .collect();
+
+        Ok(r)
+    }
+
+    async fn get_by_name(&self, user_id: Uuid, name: String) -> crate::Result<Option<Collection>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .map(|(x, _)| x)
+            .find(|x| x.user_id == user_id && x.name == name)
+            .cloned();
+
+        Ok(r)
+    }
+
+    async fn add(&self, collection: Collection) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let exists = inner.values().any(|(x, _)| {
+            x.id == collection.id || (x.user_id == collection.user_id && x.name == collection.name)
+        });
+
+        if exists {
+            return Ok(false);
+        }
+
+        inner.insert(collection.id, (collection, Vec::new()));
+
+        Ok(true)
+    }
+
+    async fn update(&self, collection: Collection) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        match inner.get_mut(&collection.id) {
+            Some((exists, _)) if exists.user_id == collection.user_id => {
+                exists.name = collection.name;
+                exists.visibility = collection.visibility;
+                exists.share_token = collection.share_token;
+                exists.updated_at = collection.updated_at;
+
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    async fn remove(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let owned = matches!(inner.get(&collection_id), Some((x, _)) if x.user_id == user_id);
+
+        if !owned {
+            return Ok(false);
+        }
+
+        inner.remove(&collection_id);
+
+        Ok(true)
+    }
+
+    async fn get_items(&self, collection_id: Uuid) -> crate::Result<Vec<CollectionItem>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .get(&collection_id)
+            .map(|(_, items)| {
+                items
+                    .iter()
+                    .sorted_by_key(|x| x.position)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(r)
+    }
+
+    async fn add_item(&self, mut item: CollectionItem) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let items = match inner.get_mut(&item.collection_id) {
+            Some((_, items)) => items,
+            None => return Ok(false),
+        };
+
+        if items.iter().any(|x| x.book_id == item.book_id) {
+            return Ok(false);
+        }
+
+        item.position = items.iter().map(|x| x.position).max().unwrap_or(0) + 1;
+
+        items.push(item);
+
+        Ok(true)
+    }
+
+    async fn remove_item(&self, collection_id: Uuid, book_id: u32) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let items = match inner.get_mut(&collection_id) {
+            Some((_, items)) => items,
+            None => return Ok(false),
+        };
+
+        let position = items.iter().position(|x| x.book_id == book_id);
+
+        match position {
+            Some(position) => {
+                items.remove(position);
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn reorder_items(&self, collection_id: Uuid, book_ids: Vec<u32>) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        if let Some((_, items)) = inner.get_mut(&collection_id) {
+            for (i, book_id) in book_ids.into_iter().enumerate() {
+                if let Some(item) = items.iter_mut().find(|x| x.book_id == book_id) {
+                    item.position = i + 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Collection, CollectionItem},
+        repository::r#trait::CollectionRepository,
+    };
+
+    use super::InMemoryCollectionRepository;
+
+    #[tokio::test]
+    async fn add_and_reorder_items() {
+        let repository = InMemoryCollectionRepository::default();
+
+        let user_id = Uuid::new_v4();
+        let collection = Collection::new(user_id, "to read".to_string());
+        let collection_id = collection.id;
+
+        assert!(repository.add(collection).await.unwrap());
+
+        for book_id in [3, 1, 2] {
+            let item = CollectionItem::new(collection_id, book_id);
+
+            assert!(repository.add_item(item).await.unwrap());
+        }
+
+        // duplicated
+        let item = CollectionItem::new(collection_id, 1);
+        assert!(!repository.add_item(item).await.unwrap());
+
+        let book_ids = |items: Vec<CollectionItem>| -> Vec<u32> {
+            items.into_iter().map(|x| x.book_id).collect()
+        };
+
+        let r = book_ids(repository.get_items(collection_id).await.unwrap());
+        assert_eq!(r, vec![3, 1, 2]);
+
+        repository
+            .reorder_items(collection_id, vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        let r = book_ids(repository.get_items(collection_id).await.unwrap());
+        assert_eq!(r, vec![1, 2, 3]);
+
+        // another user can't remove
+        assert!(!repository
+            .remove(Uuid::new_v4(), collection_id)
+            .await
+            .unwrap());
+        assert!(repository.remove(user_id, collection_id).await.unwrap());
+        assert!(repository
+            .get_items(collection_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
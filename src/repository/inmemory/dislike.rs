@@ -0,0 +1,117 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use itertools::Itertools;
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{Dislike, DislikeKind, DislikeSortBy, Sort},
+    repository::r#trait::{DislikeBy, DislikeRepository},
+};
+
+use super::{paginate, shuffled};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryDislikeRepository {
+    inner: RwLock<HashMap<Uuid, Vec<Dislike>>>,
+}
+
+fn is_same(a: &Dislike, b: &Dislike) -> bool {
+    match (a, b) {
+        (Dislike::Book { book_id: a, .. }, Dislike::Book { book_id: b, .. }) => a == b,
+        (
+            Dislike::BookTag {
+                tag_kind: a_kind,
+                tag_name: a_name,
+                ..
+            },
+            Dislike::BookTag {
+                tag_kind: b_kind,
+                tag_name: b_name,
+                ..
+            },
+        ) => (a_kind, a_name) == (b_kind, b_name),
+        _ => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl DislikeRepository for InMemoryDislikeRepository {
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        kind: Option<DislikeKind>,
+        per_page: usize,
+        page: usize,
+        sort_by: DislikeSortBy,
+    ) -> crate::Result<Vec<Dislike>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = match inner.get(&user_id) {
+            Some(r) => r
+                .iter()
+                .filter(|x| kind.map(|kind| x.kind() == kind).unwrap_or(true)),
+            None => return Ok(Vec::new()),
+        };
+
+        let r: Vec<&Dislike> = match sort_by {
+            DislikeSortBy::CreatedAt(Sort::Desc) => r
+                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+                .collect(),
+            DislikeSortBy::CreatedAt(Sort::Asc) => r
+                .sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
+                .collect(),
+            DislikeSortBy::Random => shuffled(r.collect()),
+        };
+
+        Ok(paginate(r, per_page, page).into_iter().cloned().collect())
+    }
+
+    async fn get_many_by(&self, user_id: Uuid, by: DislikeBy) -> crate::Result<Vec<Dislike>> {
+        let inner = self.inner.read().unwrap();
+
+        let dislikes = inner.get(&user_id).into_iter().flatten();
+
+        let r = match by {
+            DislikeBy::Book { ids } => dislikes
+                .filter(|x| matches!(x, Dislike::Book { book_id, .. } if ids.contains(book_id)))
+                .cloned()
+                .collect(),
+        };
+
+        Ok(r)
+    }
+
+    async fn add(&self, dislike: Dislike) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let dislikes = inner.entry(dislike.user_id()).or_default();
+
+        if dislikes.iter().any(|x| is_same(x, &dislike)) {
+            return Ok(false);
+        }
+
+        dislikes.push(dislike);
+
+        Ok(true)
+    }
+
+    async fn remove(&self, dislike: Dislike) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let dislikes = match inner.get_mut(&dislike.user_id()) {
+            Some(dislikes) => dislikes,
+            None => return Ok(false),
+        };
+
+        match dislikes.iter().position(|x| is_same(x, &dislike)) {
+            Some(position) => {
+                dislikes.remove(position);
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
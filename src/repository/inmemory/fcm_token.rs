@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use chrono::{DateTime, Duration, Utc};
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{entity::fcm_token::FcmToken, repository::r#trait::FcmTokenRepository};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryFcmTokenRepository {
+    /// udid -> (user_id, fcm_token, updated_at)
+    inner: RwLock<HashMap<Uuid, (Uuid, String, DateTime<Utc>)>>,
+}
+
+#[async_trait::async_trait]
+impl FcmTokenRepository for InMemoryFcmTokenRepository {
+    async fn add_or_update(
+        &self,
+        FcmToken {
+            udid,
+            user_id,
+            fcm_token,
+        }: FcmToken,
+    ) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        match inner.get_mut(&udid) {
+            Some((_, exists, _)) => {
+                *exists = fcm_token;
+            }
+            None => {
+                inner.insert(udid, (user_id, fcm_token, Utc::now()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_many(&self, user_ids: Vec<Uuid>) -> crate::Result<Vec<String>> {
+        let inner = self.inner.read().unwrap();
+
+        // 30일 넘게 갱신되지 않은 토큰은 무시함
+        let expired_at = Utc::now() - Duration::days(30);
+
+        let r = inner
+            .values()
+            .filter(|(user_id, _, updated_at)| {
+                user_ids.contains(user_id) && *updated_at > expired_at
+            })
+            .map(|(_, fcm_token, _)| fcm_token.clone())
+            .collect();
+
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{entity::fcm_token::FcmToken, repository::r#trait::FcmTokenRepository};
+
+    use super::InMemoryFcmTokenRepository;
+
+    #[tokio::test]
+    async fn add_or_update() {
+        let repository = InMemoryFcmTokenRepository::default();
+        let (udid, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        for fcm_token in ["a", "b"] {
+            repository
+                .add_or_update(FcmToken::new(udid, user_id, fcm_token.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let r = repository
+            .get_many(vec![user_id, Uuid::new_v4()])
+            .await
+            .unwrap();
+
+        assert_eq!(r, vec!["b".to_string()]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use itertools::Itertools;
+use sai::{Component, Injected};
+use uuid::Uuid;
+
+use crate::{
+    entity::{Follow, FollowSortBy, Sort},
+    repository::r#trait::FollowRepository,
+};
+
+use super::InMemoryBlockRepository;
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryFollowRepository {
+    #[injected]
+    block_repository: Injected<InMemoryBlockRepository>,
+
+    /// (follower_id, followee_id)
+    inner: RwLock<HashMap<(Uuid, Uuid), Follow>>,
+}
+
+impl InMemoryFollowRepository {
+    fn get_many(
+        &self,
+        filter: impl Fn(&Follow) -> bool,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> Vec<Follow> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner.values().filter(|x| filter(x));
+
+        match sort_by {
+            FollowSortBy::CreatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.created_at.cmp(&a.created_at))
+            }
+            FollowSortBy::CreatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.created_at.cmp(&b.created_at))
+            }
+        }
+        .skip(per_page * (page - 1))
+        .take(per_page)
+        .cloned()
+        .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl FollowRepository for InMemoryFollowRepository {
+    async fn get_followers(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>> {
+        // 차단 관계인 사용자는 보이지 않음
+        let filter = |x: &Follow| {
+            x.followee_id == user_id
+                && self
+                    .block_repository
+                    .unblocked(user_id, x.follower_id, false)
+        };
+
+        Ok(self.get_many(filter, per_page, page, sort_by))
+    }
+
+    async fn get_following(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>> {
+        let filter = |x: &Follow| {
+            x.follower_id == user_id
+                && self
+                    .block_repository
+                    .unblocked(user_id, x.followee_id, false)
+        };
+
+        Ok(self.get_many(filter, per_page, page, sort_by))
+    }
+
+    async fn get_follower_ids(&self, user_id: Uuid) -> crate::Result<Vec<Uuid>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .filter(|x| x.followee_id == user_id)
+            .map(|x| x.follower_id)
+            .collect();
+
+        Ok(r)
+    }
+
+    async fn add(&self, follow: Follow) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        let key = (follow.follower_id, follow.followee_id);
+
+        if inner.contains_key(&key) {
+            return Ok(false);
+        }
+
+        inner.insert(key, follow);
+
+        Ok(true)
+    }
+
+    async fn remove(&self, follower_id: Uuid, followee_id: Uuid) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        Ok(inner.remove(&(follower_id, followee_id)).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Block, BlockKind, Follow, FollowSortBy},
+        repository::r#trait::{BlockRepository, FollowRepository},
+    };
+
+    use super::{InMemoryBlockRepository, InMemoryFollowRepository};
+
+    #[tokio::test]
+    async fn exclude_blocked() {
+        let block_repository = Arc::new(InMemoryBlockRepository::default());
+        let repository = InMemoryFollowRepository {
+            block_repository: Arc::clone(&block_repository),
+            inner: Default::default(),
+        };
+
+        let a = Uuid::new_v4();
+        let blocked = Uuid::new_v4();
+        let other = Uuid::new_v4();
+
+        assert!(repository.add(Follow::new(blocked, a)).await.unwrap());
+        assert!(repository.add(Follow::new(other, a)).await.unwrap());
+        assert!(block_repository
+            .add(Block::new(blocked, a, BlockKind::Block))
+            .await
+            .unwrap());
+
+        let followers = repository
+            .get_followers(a, 1, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            followers
+                .into_iter()
+                .map(|x| x.follower_id)
+                .collect::<Vec<_>>(),
+            vec![other]
+        );
+    }
+
+    #[tokio::test]
+    async fn followers_and_following() {
+        let repository = InMemoryFollowRepository::default();
+
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+
+        assert!(repository.add(Follow::new(b, a)).await.unwrap());
+        assert!(repository.add(Follow::new(c, a)).await.unwrap());
+        assert!(repository.add(Follow::new(a, b)).await.unwrap());
+        // duplicated
+        assert!(!repository.add(Follow::new(b, a)).await.unwrap());
+
+        let mut follower_ids = repository.get_follower_ids(a).await.unwrap();
+        follower_ids.sort();
+        let mut expected = vec![b, c];
+        expected.sort();
+        assert_eq!(follower_ids, expected);
+
+        let followers = repository
+            .get_followers(a, 1, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(followers.len(), 1);
+
+        let following = repository
+            .get_following(a, 25, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(following.len(), 1);
+        assert_eq!(following[0].followee_id, b);
+
+        assert!(repository.remove(b, a).await.unwrap());
+        assert!(!repository.remove(b, a).await.unwrap());
+        assert_eq!(repository.get_follower_ids(a).await.unwrap(), vec![c]);
+    }
+}
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use itertools::Itertools;
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{History, HistoryFilter, HistoryKind, HistorySortBy, Sort},
+    repository::r#trait::{HistoryBy, HistoryRepository},
+};
+
+use super::{paginate, shuffled};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryHistoryRepository {
+    /// (user_id, book_id)
+    inner: RwLock<HashMap<(Uuid, u32), History>>,
+}
+
+impl InMemoryHistoryRepository {
+    /// 모든 사용자의 열람 기록
+    pub(super) fn all(&self) -> Vec<History> {
+        let inner = self.inner.read().unwrap();
+
+        inner.values().cloned().collect()
+    }
+}
+
+fn key(history: &History) -> (Uuid, u32) {
+    match history {
+        History::Book {
+            user_id, book_id, ..
+        } => (*user_id, *book_id),
+    }
+}
+
+fn filter_by(history: &History, filter: &HistoryFilter) -> bool {
+    filter
+        .created_after
+        .map(|x| history.created_at() > x)
+        .unwrap_or(true)
+        && filter
+            .created_before
+            .map(|x| history.created_at() < x)
+            .unwrap_or(true)
+        && filter
+            .updated_after
+            .map(|x| history.updated_at() > x)
+            .unwrap_or(true)
+}
+
+#[async_trait::async_trait]
+impl HistoryRepository for InMemoryHistoryRepository {
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        kind: Option<HistoryKind>,
+        filter: HistoryFilter,
+        per_page: usize,
+        page: usize,
+        sort_by: HistorySortBy,
+    ) -> crate::Result<Vec<History>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .filter(|x| x.user_id() == user_id)
+            .filter(|x| kind.map(|kind| x.kind() == kind).unwrap_or(true))
+            .filter(|x| filter_by(x, &filter));
+
+        let r: Vec<&History> = match sort_by {
+            HistorySortBy::CreatedAt(Sort::Desc) => r
+                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+                .collect(),
+            HistorySortBy::CreatedAt(Sort::Asc) => r
+                .sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
+                .collect(),
+            HistorySortBy::UpdatedAt(Sort::Desc) => r
+                .sorted_by(|a, b| b.updated_at().cmp(&a.updated_at()))
+                .collect(),
+            HistorySortBy::UpdatedAt(Sort::Asc) => r
+                .sorted_by(|a, b| a.updated_at().cmp(&b.updated_at()))
+                .collect(),
+            // 작품 정보가 없으므로 Postgres에서 작품 정보가 없을 때처럼 정렬됨
+            HistorySortBy::Title(_)
+            | HistorySortBy::PublishedAt(_)
+            | HistorySortBy::Popularity(_) => r
+                .sorted_by(|a, b| b.updated_at().cmp(&a.updated_at()))
+                .collect(),
+            HistorySortBy::Random => shuffled(r.collect()),
+        };
+
+        Ok(paginate(r, per_page, page).into_iter().cloned().collect())
+    }
+
+    async fn get_many_by(&self, user_id: Uuid, by: HistoryBy) -> crate::Result<Vec<History>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = match by {
+            HistoryBy::Book { ids } => ids
+                .into_iter()
+                .filter_map(|book_id| inner.get(&(user_id, book_id)).cloned())
+                .collect(),
+        };
+
+        Ok(r)
+    }
+
+    async fn get_reader_ids(&self, book_ids: Vec<u32>) -> crate::Result<Vec<Uuid>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .keys()
+            .filter(|(_, book_id)| book_ids.contains(book_id))
+            .map(|(user_id, _)| *user_id)
+            .unique()
+            .collect();
+
+        Ok(r)
+    }
+
+    async fn add_or_update(&self, history: History) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        match inner.get_mut(&key(&history)) {
+            Some(History::Book {
+                page, updated_at, ..
+            }) => {
+                let History::Book {
+                    page: new_page,
+                    updated_at: new_updated_at,
+                    ..
+                } = history;
+
+                *page = new_page;
+                *updated_at = new_updated_at;
+            }
+            None => {
+                inner.insert(key(&history), history);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, history: History) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        Ok(inner.remove(&key(&history)).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{History, HistoryFilter, HistorySortBy, Sort},
+        repository::r#trait::{HistoryBy, HistoryRepository},
+    };
+
+    use super::InMemoryHistoryRepository;
+
+    #[tokio::test]
+    async fn add_or_update() {
+        let repository = InMemoryHistoryRepository::default();
+        let user_id = Uuid::new_v4();
+
+        repository
+            .add_or_update(History::book(1, 3, user_id))
+            .await
+            .unwrap();
+        repository
+            .add_or_update(History::book(1, 7, user_id))
+            .await
+            .unwrap();
+
+        let r = repository
+            .get_many_by(user_id, HistoryBy::Book { ids: vec![1, 2] })
+            .await
+            .unwrap();
+
+        assert_eq!(r.len(), 1);
+        assert!(matches!(r[0], History::Book { page: 7, .. }));
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let repository = InMemoryHistoryRepository::default();
+        let (user_id, another_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now();
+
+        for book_id in 0..10 {
+            let at = now - Duration::minutes(book_id as i64);
+
+            repository
+                .add_or_update(History::Book {
+                    book_id,
+                    page: 1,
+                    user_id,
+                    created_at: at,
+                    updated_at: at,
+                })
+                .await
+                .unwrap();
+        }
+        repository
+            .add_or_update(History::book(0, 1, another_user_id))
+            .await
+            .unwrap();
+
+        let filter = HistoryFilter {
+            created_after: Some(now - Duration::minutes(5)),
+            ..Default::default()
+        };
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                filter,
+                3,
+                2,
+                HistorySortBy::UpdatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+
+        let book_ids = r
+            .iter()
+            .map(|History::Book { book_id, .. }| *book_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(book_ids, vec![1, 0]);
+
+        let mut r = repository.get_reader_ids(vec![0]).await.unwrap();
+        r.sort();
+
+        let mut e = vec![user_id, another_user_id];
+        e.sort();
+
+        assert_eq!(r, e);
+    }
+}
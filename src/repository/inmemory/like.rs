@@ -5,75 +5,165 @@ use sai::Component;
 use uuid::Uuid;
 
 use crate::{
-    entity::{like::LikeSortBy, Like, LikeKind, Sort},
-    repository::r#trait::DislikeRepository,
+    entity::{like::LikeSortBy, Like, LikeFilter, LikeKind, Sort},
+    repository::r#trait::{LikeBy, LikeRepository},
 };
 
+use super::{cmp_nulls_last, paginate, shuffled};
+
 #[cfg_attr(test, derive(Default))]
 #[derive(Component)]
 pub struct InMemoryLikeRepository {
     inner: RwLock<HashMap<Uuid, Vec<Like>>>,
 }
 
+impl InMemoryLikeRepository {
+    /// 모든 사용자의 좋아요
+    pub(super) fn all(&self) -> Vec<Like> {
+        let inner = self.inner.read().unwrap();
+
+        inner.values().flatten().cloned().collect()
+    }
+
+    /// 작품을 좋아요한 사용자 수
+    fn popularity(inner: &HashMap<Uuid, Vec<Like>>, book_id: u32) -> usize {
+        inner
+            .values()
+            .flatten()
+            .filter(|x| matches!(x, Like::Book { book_id: exists, .. } if *exists == book_id))
+            .count()
+    }
+}
+
+fn is_same(a: &Like, b: &Like) -> bool {
+    match (a, b) {
+        (Like::Book { book_id: a, .. }, Like::Book { book_id: b, .. }) => a == b,
+        (
+            Like::BookTag {
+                tag_kind: a_kind,
+                tag_name: a_name,
+                ..
+            },
+            Like::BookTag {
+                tag_kind: b_kind,
+                tag_name: b_name,
+                ..
+            },
+        ) => (a_kind, a_name) == (b_kind, b_name),
+        _ => false,
+    }
+}
+
+fn filter_by(like: &Like, kind: Option<LikeKind>, filter: &LikeFilter) -> bool {
+    let by_kind = kind.map(|kind| like.kind() == kind).unwrap_or(true);
+
+    let by_created_at = filter
+        .created_after
+        .map(|x| like.created_at() > x)
+        .unwrap_or(true)
+        && filter
+            .created_before
+            .map(|x| like.created_at() < x)
+            .unwrap_or(true);
+
+    // 태그 조건이 있으면 작품 좋아요는 걸러짐
+    let by_tag = match like {
+        Like::Book { .. } => !filter.has_tag(),
+        Like::BookTag {
+            tag_kind, tag_name, ..
+        } => {
+            filter
+                .tag_kind
+                .as_ref()
+                .map(|x| x == tag_kind)
+                .unwrap_or(true)
+                && filter
+                    .tag_name
+                    .as_ref()
+                    .map(|x| x == tag_name)
+                    .unwrap_or(true)
+        }
+    };
+
+    by_kind && by_created_at && by_tag
+}
+
 #[async_trait::async_trait]
-impl DislikeRepository for InMemoryLikeRepository {
+impl LikeRepository for InMemoryLikeRepository {
     async fn get_many(
         &self,
         user_id: Uuid,
         kind: Option<LikeKind>,
+        filter: LikeFilter,
         per_page: usize,
         page: usize,
         sort_by: LikeSortBy,
     ) -> crate::Result<Vec<Like>> {
         let inner = self.inner.read().unwrap();
 
-        let filter_by_kind = |x: &&Like| -> bool {
-            match kind {
-                Some(LikeKind::Book) => matches!(x.kind(), LikeKind::Book),
-                Some(LikeKind::BookTag) => matches!(x.kind(), LikeKind::BookTag),
-                None => true,
-            }
+        let r = match inner.get(&user_id) {
+            Some(r) => r.iter().filter(|x| filter_by(x, kind, &filter)),
+            None => return Ok(Vec::new()),
         };
 
-        match inner.get(&user_id) {
-            Some(r) => {
-                let r = r.iter().filter(filter_by_kind);
-
-                let r = match sort_by {
-                    LikeSortBy::CreatedAt(Sort::Desc) => {
-                        r.sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
-                    }
-                    LikeSortBy::CreatedAt(Sort::Asc) => {
-                        r.sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
-                    }
-                    LikeSortBy::Random => unimplemented!(
-                        "unimplemented sort by random in InMemoryLikeRepository::get_many()"
-                    ),
-                }
-                .skip(per_page * (page - 1))
-                .take(per_page)
-                .cloned()
-                .collect();
+        // 작품 제목과 올라온 날짜는 검색 저장소에만 있어서
+        // 작품 좋아요는 Postgres에서 작품 정보가 없을 때처럼 뒤로 정렬됨
+        let r: Vec<&Like> = match sort_by {
+            LikeSortBy::CreatedAt(Sort::Desc) => r
+                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+                .collect(),
+            LikeSortBy::CreatedAt(Sort::Asc) => r
+                .sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
+                .collect(),
+            LikeSortBy::Title(sort) => r
+                .sorted_by(|a, b| {
+                    let title = |x: &Like| match x {
+                        Like::Book { .. } => None,
+                        Like::BookTag { tag_name, .. } => Some(tag_name.clone()),
+                    };
+
+                    cmp_nulls_last(title(a), title(b), sort)
+                        .then(b.created_at().cmp(&a.created_at()))
+                })
+                .collect(),
+            LikeSortBy::PublishedAt(_) => r
+                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+                .collect(),
+            LikeSortBy::Popularity(sort) => r
+                .sorted_by(|a, b| {
+                    let popularity = |x: &Like| match x {
+                        Like::Book { book_id, .. } => Some(Self::popularity(&inner, *book_id)),
+                        Like::BookTag { .. } => None,
+                    };
+
+                    cmp_nulls_last(popularity(a), popularity(b), sort)
+                        .then(b.created_at().cmp(&a.created_at()))
+                })
+                .collect(),
+            LikeSortBy::Random => shuffled(r.collect()),
+        };
 
-                Ok(r)
-            }
-            None => Ok(Vec::new()),
-        }
+        Ok(paginate(r, per_page, page).into_iter().cloned().collect())
     }
 
-    // TODO: 일단 정렬안함
-    async fn get_many_by_book_tags(
-        &self,
-        book_tags: Vec<(String, String)>,
-    ) -> crate::Result<Vec<Like>> {
+    async fn get_many_by(&self, user_id: Option<Uuid>, by: LikeBy) -> crate::Result<Vec<Like>> {
         let inner = self.inner.read().unwrap();
 
         let r = inner
-            .values()
-            .flatten()
-            .filter(|x| {
-                matches!(x, Like::BookTag { tag_kind, tag_name, .. }
-                    if book_tags.contains(&(tag_kind.to_owned(), tag_name.to_owned())))
+            .iter()
+            .filter(|(k, _)| user_id.map(|x| x == **k).unwrap_or(true))
+            .flat_map(|(_, v)| v)
+            .filter(|x| match (&by, x) {
+                (LikeBy::Book { ids }, Like::Book { book_id, .. }) => ids.contains(book_id),
+                (
+                    LikeBy::BookTag { tags },
+                    Like::BookTag {
+                        tag_kind, tag_name, ..
+                    },
+                ) => tags
+                    .iter()
+                    .any(|(kind, name)| (kind, name) == (tag_kind, tag_name)),
+                _ => false,
             })
             .cloned()
             .collect();
@@ -84,47 +174,13 @@ impl DislikeRepository for InMemoryLikeRepository {
     async fn add(&self, like: Like) -> crate::Result<bool> {
         let mut inner = self.inner.write().unwrap();
 
-        let user_id = like.user_id();
+        let likes = inner.entry(like.user_id()).or_default();
 
-        let likes = inner.get(&user_id);
-
-        let already_exists = match (&like, likes) {
-            (Like::Book { book_id, .. }, Some(likes)) => likes.iter().any(|x| {
-                matches!(x, Like::Book { book_id: exists, .. }
-                    if exists == book_id)
-            }),
-
-            (
-                Like::BookTag {
-                    tag_kind, tag_name, ..
-                },
-                Some(likes),
-            ) => {
-                let exists = (tag_kind, tag_name);
-
-                likes.iter().any(|x| {
-                    matches!(x, Like::BookTag { tag_kind, tag_name, .. }
-                        if exists == (tag_kind, tag_name))
-                })
-            }
-            _ => false,
-        };
-
-        if already_exists {
+        if likes.iter().any(|x| is_same(x, &like)) {
             return Ok(false);
         }
 
-        let likes = inner.get_mut(&user_id);
-
-        match likes {
-            Some(likes) => {
-                likes.push(like);
-            }
-
-            None => {
-                inner.insert(user_id.to_owned(), vec![like]);
-            }
-        }
+        likes.push(like);
 
         Ok(true)
     }
@@ -132,42 +188,13 @@ impl DislikeRepository for InMemoryLikeRepository {
     async fn remove(&self, like: Like) -> crate::Result<bool> {
         let mut inner = self.inner.write().unwrap();
 
-        let user_id = like.user_id();
-
-        let likes = inner.get(&user_id);
-
-        let position = match (&like, likes) {
-            // Book
-            (Like::Book { book_id, .. }, Some(likes)) => likes
-                .iter()
-                .enumerate()
-                .find(|(_, x)| {
-                    matches!(x, Like::Book { book_id: exists, .. }
-                        if exists == book_id)
-                })
-                .map(|(i, _)| i),
-
-            // BookTag
-            (
-                Like::BookTag {
-                    tag_kind, tag_name, ..
-                },
-                Some(likes),
-            ) => likes
-                .iter()
-                .enumerate()
-                .find(|(_, x)| {
-                    matches!(x, Like::BookTag { tag_kind: exi, tag_name: sts, .. }
-                        if (exi, sts) == (tag_kind, tag_name))
-                })
-                .map(|(i, _)| i),
-            _ => None,
+        let likes = match inner.get_mut(&like.user_id()) {
+            Some(likes) => likes,
+            None => return Ok(false),
         };
 
-        match position {
+        match likes.iter().position(|x| is_same(x, &like)) {
             Some(position) => {
-                let likes = inner.get_mut(&user_id).unwrap();
-
                 likes.remove(position);
 
                 Ok(true)
@@ -178,195 +205,177 @@ impl DislikeRepository for InMemoryLikeRepository {
 }
 
 #[cfg(test)]
-pub mod get_many {
-    use chrono::Utc;
+mod tests {
+    use chrono::{Duration, Utc};
     use itertools::Itertools;
-    use rand::{prelude::SliceRandom, thread_rng};
-    use sai::{Component, System};
-    use util::test_registry;
     use uuid::Uuid;
 
     use crate::{
-        entity::{like::LikeSortBy, Like, LikeKind, Sort},
-        repository::r#trait::DislikeRepository,
+        entity::{like::LikeSortBy, Like, LikeFilter, LikeKind, Sort},
+        repository::r#trait::{LikeBy, LikeRepository},
     };
 
     use super::InMemoryLikeRepository;
 
+    async fn repository(
+        user_id: Uuid,
+        another_user_id: Uuid,
+    ) -> (InMemoryLikeRepository, Vec<Like>) {
+        let repository = InMemoryLikeRepository::default();
+        let now = Utc::now();
+
+        let likes = (0..25)
+            .map(|x| Like::Book {
+                user_id,
+                book_id: x,
+                created_at: now - Duration::minutes(x as i64),
+            })
+            .chain((25..50).map(|x| Like::BookTag {
+                user_id,
+                tag_kind: "artist".to_string(),
+                tag_name: x.to_string(),
+                created_at: now - Duration::minutes(x as i64),
+            }))
+            .chain((0..10).map(|x| Like::Book {
+                user_id: another_user_id,
+                book_id: x,
+                created_at: now,
+            }))
+            .collect::<Vec<_>>();
+
+        for like in likes.iter() {
+            assert!(repository.add(like.clone()).await.unwrap(), "{like:?}");
+        }
+
+        (repository, likes)
+    }
+
     #[tokio::test]
-    async fn success() {
-        let mut test = System::<TestRegistry>::new();
-
-        test.start().await;
-
-        test_registry!(
-        [repository: InMemoryLikeRepository] ->
-        [likes: Vec<Like>, user_id: Uuid, another_user_id: Uuid] ->
-        {
-            user_id = Uuid::new_v4();
-            another_user_id = Uuid::new_v4();
-
-            likes.append(
-                &mut (0..25)
-                    .into_iter()
-                    .map(|x| Like::Book {
-                        user_id,
-                        book_id: x,
-                        created_at: Utc::now()
-                    })
-                    .collect()
-            );
-
-            // push another user_id
-            {
-                likes.append(
-                    &mut (0..25)
-                        .into_iter()
-                        .map(|x| Like::Book {
-                            user_id: another_user_id,
-                            book_id: x,
-                            created_at: Utc::now()
-                        })
-                        .collect()
-                );
-
-                likes.append(
-                    &mut (25..50)
-                        .into_iter()
-                        .map(|x| Like::BookTag {
-                            user_id: another_user_id,
-                            tag_kind: (x * x).to_string(),
-                            tag_name: (x * x * x).to_string(),
-                            created_at: Utc::now()
-                        })
-                        .collect()
-                );
-            }
+    async fn get_many() {
+        let (user_id, another_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (repository, likes) = repository(user_id, another_user_id).await;
 
-            likes.append(
-                &mut (25..50)
-                    .into_iter()
-                    .map(|x| Like::BookTag {
-                        user_id,
-                        tag_kind: (x * x).to_string(),
-                        tag_name: (x * x * x).to_string(),
-                        created_at: Utc::now()
-                    })
-                    .collect()
-            );
-
-            likes.shuffle(&mut thread_rng());
-
-            for like in likes.iter() {
-                let saved = repository.add(like.clone()).await.unwrap();
-
-                if !saved {
-                    panic!("not saved like: {like:?}");
-                }
-            }
-        },
-        {
-            // Book
-            let r = repository.get_many(user_id, Some(LikeKind::Book), 25, 1, LikeSortBy::CreatedAt(Sort::Desc)).await.unwrap();
-            let e = likes
+        let expected = |kind: Option<LikeKind>, take: usize| {
+            likes
                 .iter()
                 .filter(|x| x.user_id() == user_id)
-                .filter(|x| x.kind() == LikeKind::Book)
+                .filter(|x| kind.map(|k| x.kind() == k).unwrap_or(true))
                 .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
-                .take(25)
+                .take(take)
                 .cloned()
-                .collect::<Vec<_>>();
+                .collect::<Vec<_>>()
+        };
 
-            assert_eq!(r, e, "{user_id}");
+        let r = repository
+            .get_many(
+                user_id,
+                Some(LikeKind::Book),
+                LikeFilter::default(),
+                10,
+                1,
+                LikeSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(r, expected(Some(LikeKind::Book), 10));
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                LikeFilter::default(),
+                75,
+                1,
+                LikeSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(r, expected(None, 75));
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                LikeFilter::default(),
+                75,
+                1,
+                LikeSortBy::Random,
+            )
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 50);
+    }
 
-            // BookTag
-            let r = repository.get_many(user_id, Some(LikeKind::BookTag), 25, 1, LikeSortBy::CreatedAt(Sort::Desc)).await.unwrap();
-            let e = likes
-                .iter()
-                .filter(|x| x.user_id() == user_id)
-                .filter(|x| x.kind() == LikeKind::BookTag)
-                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
-                .take(25)
-                .cloned()
-                .collect::<Vec<_>>();
+    #[tokio::test]
+    async fn filter() {
+        let (user_id, another_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (repository, _likes) = repository(user_id, another_user_id).await;
 
-            assert_eq!(r, e);
+        let filter = LikeFilter {
+            tag_name: Some("30".to_string()),
+            ..Default::default()
+        };
 
-            // All
-            let r = repository.get_many(user_id, None, 75, 1, LikeSortBy::CreatedAt(Sort::Desc)).await.unwrap();
-            let e = likes
-                .iter()
-                .filter(|x| x.user_id() == user_id)
-                .sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
-                .take(75)
-                .cloned()
-                .collect::<Vec<_>>();
+        let r = repository
+            .get_many(user_id, None, filter, 25, 1, LikeSortBy::default())
+            .await
+            .unwrap();
 
-            assert_eq!(r, e);
-        });
+        assert_eq!(r.len(), 1);
+        assert!(matches!(&r[0], Like::BookTag { tag_name, .. } if tag_name == "30"));
     }
-}
-
-#[cfg(test)]
-pub mod get_many_by_book_tags {
-    use chrono::Utc;
-    use rand::{prelude::SliceRandom, thread_rng};
-    use sai::{Component, System};
-    use util::test_registry;
-    use uuid::Uuid;
 
-    use crate::{entity::Like, repository::r#trait::DislikeRepository};
+    #[tokio::test]
+    async fn sort_by_popularity() {
+        let (user_id, another_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (repository, _likes) = repository(user_id, another_user_id).await;
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                LikeFilter::default(),
+                50,
+                1,
+                LikeSortBy::Popularity(Sort::Desc),
+            )
+            .await
+            .unwrap();
+
+        // 두 사용자가 좋아요한 작품이 먼저 오고, 태그는 맨 뒤로 감
+        assert!(r[..10]
+            .iter()
+            .all(|x| matches!(x, Like::Book { book_id, .. } if *book_id < 10)));
+        assert!(r[25..].iter().all(|x| x.kind() == LikeKind::BookTag));
+    }
 
-    use super::InMemoryLikeRepository;
+    #[tokio::test]
+    async fn get_many_by() {
+        let (user_id, another_user_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let (repository, _likes) = repository(user_id, another_user_id).await;
+
+        let r = repository
+            .get_many_by(None, LikeBy::Book { ids: vec![1, 20] })
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 3);
+
+        let r = repository
+            .get_many_by(Some(another_user_id), LikeBy::Book { ids: vec![1, 20] })
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 1);
+    }
 
     #[tokio::test]
-    async fn success() {
-        let mut test = System::<TestRegistry>::new();
-
-        test.start().await;
-
-        test_registry!(
-        [repository: InMemoryLikeRepository] ->
-        [user_id: Uuid, likes: Vec<Like>] ->
-        {
-            user_id = Uuid::new_v4();
-
-            likes.append(
-                &mut (0..30)
-                    .map(|x| Like::BookTag {
-                        user_id,
-                        tag_kind: (x * x).to_string(),
-                        tag_name: (x * x * x).to_string(),
-                        created_at: Utc::now()
-                    })
-                    .collect()
-            );
-
-            likes.shuffle(&mut thread_rng());
-
-            for like in likes.iter() {
-                let saved = repository.add(like.clone()).await.unwrap();
-
-                if !saved {
-                    panic!("not saved like: {like:?}");
-                }
-            }
-        },
-        {
-            let book_tags = (10..25)
-                .map(|x| ((x * x).to_string(), (x * x * x).to_string()))
-                .collect::<Vec<_>>();
-
-            let r = repository.get_many_by_book_tags(book_tags.clone()).await.unwrap();
-            let e = likes
-                .into_iter()
-                .filter(|x| {
-                    matches!(x, Like::BookTag { tag_kind, tag_name , .. }
-                        if book_tags.contains(&(tag_kind.to_owned(), tag_name.to_owned())))
-                })
-                .collect::<Vec<_>>();
+    async fn add_and_remove() {
+        let repository = InMemoryLikeRepository::default();
+        let user_id = Uuid::new_v4();
+
+        assert!(repository.add(Like::book(user_id, 1)).await.unwrap());
+        assert!(!repository.add(Like::book(user_id, 1)).await.unwrap());
 
-            assert_eq!(r, e);
-        });
+        assert!(repository.remove(Like::book(user_id, 1)).await.unwrap());
+        assert!(!repository.remove(Like::book(user_id, 1)).await.unwrap());
     }
 }
@@ -1,5 +1,104 @@
-// mod like;
+mod block;
+mod collection;
+mod dislike;
+mod fcm_token;
+mod follow;
+mod history;
+mod like;
+mod notification;
+mod profile;
+mod rating;
+mod related_book;
+mod search;
 mod user;
 
-// pub use like::InMemoryLikeRepository;
+pub use block::InMemoryBlockRepository;
+pub use collection::InMemoryCollectionRepository;
+pub use dislike::InMemoryDislikeRepository;
+pub use fcm_token::InMemoryFcmTokenRepository;
+pub use follow::InMemoryFollowRepository;
+pub use history::InMemoryHistoryRepository;
+pub use like::InMemoryLikeRepository;
+pub use notification::InMemoryNotificationRepository;
+pub use profile::InMemoryProfileRepository;
+pub use rating::InMemoryRatingRepository;
+pub use related_book::InMemoryRelatedBookRepository;
+pub use search::InMemorySearchRepository;
 pub use user::InMemoryUserRepository;
+
+use std::cmp::Ordering;
+
+use rand::{prelude::SliceRandom, thread_rng};
+
+use crate::entity::Sort;
+
+/// 테스트에서 Postgres 없이 쓰는 저장소
+pub struct InMemoryBackend;
+
+impl super::Backend for InMemoryBackend {
+    type Database = crate::database::InMemoryDatabase;
+    type User = InMemoryUserRepository;
+    type Like = InMemoryLikeRepository;
+    type Dislike = InMemoryDislikeRepository;
+    type Notification = InMemoryNotificationRepository;
+    type FcmToken = InMemoryFcmTokenRepository;
+    type History = InMemoryHistoryRepository;
+    type Collection = InMemoryCollectionRepository;
+    type Profile = InMemoryProfileRepository;
+    type Rating = InMemoryRatingRepository;
+    type Follow = InMemoryFollowRepository;
+    type Block = InMemoryBlockRepository;
+    type RelatedBook = InMemoryRelatedBookRepository;
+    type Search = InMemorySearchRepository;
+}
+
+/// Postgres의 `NULLS LAST`처럼 값이 없으면 정렬 방향과 상관없이 뒤로 보냄
+fn cmp_nulls_last<T: Ord>(a: Option<T>, b: Option<T>, sort: Sort) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match sort {
+            Sort::Desc => b.cmp(&a),
+            Sort::Asc => a.cmp(&b),
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// `ORDER BY RANDOM()`
+fn shuffled<T>(mut xs: Vec<T>) -> Vec<T> {
+    xs.shuffle(&mut thread_rng());
+    xs
+}
+
+fn paginate<T>(xs: impl IntoIterator<Item = T>, per_page: usize, page: usize) -> Vec<T> {
+    xs.into_iter()
+        .skip(per_page * (page - 1))
+        .take(per_page)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::entity::Sort;
+
+    use super::cmp_nulls_last;
+
+    #[test]
+    fn nulls_last() {
+        assert_eq!(
+            cmp_nulls_last(Some(1), Some(2), Sort::Desc),
+            Ordering::Greater
+        );
+        assert_eq!(cmp_nulls_last(Some(1), Some(2), Sort::Asc), Ordering::Less);
+
+        assert_eq!(cmp_nulls_last(Some(1), None, Sort::Desc), Ordering::Less);
+        assert_eq!(cmp_nulls_last(Some(1), None, Sort::Asc), Ordering::Less);
+        assert_eq!(
+            cmp_nulls_last(None::<u32>, None, Sort::Asc),
+            Ordering::Equal
+        );
+    }
+}
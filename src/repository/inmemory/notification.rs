@@ -0,0 +1,180 @@
+use std::sync::RwLock;
+
+use itertools::Itertools;
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{notification::NotificationSortBy, Notification, NotificationKind, Sort},
+    repository::r#trait::NotificationRepository,
+};
+
+use super::paginate;
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryNotificationRepository {
+    inner: RwLock<Vec<Notification>>,
+}
+
+impl InMemoryNotificationRepository {
+    /// 모든 사용자의 알림
+    pub(super) fn all(&self) -> Vec<Notification> {
+        let inner = self.inner.read().unwrap();
+
+        inner.clone()
+    }
+}
+
+/// Postgres에서 같은 id가 만들어지는 알림
+fn is_same(a: &Notification, b: &Notification) -> bool {
+    match (a, b) {
+        (
+            Notification::Book {
+                user_id: a_user_id,
+                book_id: a_book_id,
+                ..
+            },
+            Notification::Book {
+                user_id: b_user_id,
+                book_id: b_book_id,
+                ..
+            },
+        )
+        | (
+            Notification::BookSeries {
+                user_id: a_user_id,
+                book_id: a_book_id,
+                ..
+            },
+            Notification::BookSeries {
+                user_id: b_user_id,
+                book_id: b_book_id,
+                ..
+            },
+        ) => (a_user_id, a_book_id) == (b_user_id, b_book_id),
+        (
+            Notification::User {
+                user_id: a_user_id,
+                followee_id: a_followee_id,
+                book_id: a_book_id,
+                ..
+            },
+            Notification::User {
+                user_id: b_user_id,
+                followee_id: b_followee_id,
+                book_id: b_book_id,
+                ..
+            },
+        ) => (a_user_id, a_followee_id, a_book_id) == (b_user_id, b_followee_id, b_book_id),
+        _ => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationRepository for InMemoryNotificationRepository {
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        kind: Option<NotificationKind>,
+        per_page: usize,
+        page: usize,
+        sort_by: NotificationSortBy,
+    ) -> crate::Result<Vec<Notification>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .iter()
+            .filter(|x| x.user_id() == user_id)
+            .filter(|x| kind.map(|kind| x.kind() == kind).unwrap_or(true));
+
+        let r = match sort_by {
+            NotificationSortBy::CreatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+            }
+            NotificationSortBy::CreatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
+            }
+        };
+
+        Ok(paginate(r, per_page, page).into_iter().cloned().collect())
+    }
+
+    /// 이미 있는 알림은 무시함
+    async fn add_many(
+        &self,
+        _kind: NotificationKind,
+        notifications: Vec<Notification>,
+    ) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        for notification in notifications {
+            if !inner.iter().any(|x| is_same(x, &notification)) {
+                inner.push(notification);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{notification::NotificationSortBy, Notification, NotificationKind, Sort},
+        repository::r#trait::NotificationRepository,
+    };
+
+    use super::InMemoryNotificationRepository;
+
+    #[tokio::test]
+    async fn add_many() {
+        let repository = InMemoryNotificationRepository::default();
+        let (user_id, followee_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        repository
+            .add_many(
+                NotificationKind::Book,
+                vec![
+                    Notification::book(user_id, 1, vec![("artist".into(), "a".into())]),
+                    Notification::book(user_id, 1, vec![("artist".into(), "a".into())]),
+                    Notification::book(user_id, 2, vec![("artist".into(), "a".into())]),
+                ],
+            )
+            .await
+            .unwrap();
+        repository
+            .add_many(
+                NotificationKind::User,
+                vec![Notification::user(user_id, followee_id, 1)],
+            )
+            .await
+            .unwrap();
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                10,
+                1,
+                NotificationSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 3);
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(NotificationKind::User),
+                10,
+                1,
+                NotificationSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 1);
+    }
+}
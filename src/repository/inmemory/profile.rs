@@ -0,0 +1,46 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{Profile, Visibility},
+    repository::r#trait::ProfileRepository,
+};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryProfileRepository {
+    inner: RwLock<HashMap<Uuid, Profile>>,
+}
+
+#[async_trait::async_trait]
+impl ProfileRepository for InMemoryProfileRepository {
+    async fn get(&self, user_id: Uuid) -> crate::Result<Option<Profile>> {
+        let inner = self.inner.read().unwrap();
+
+        Ok(inner.get(&user_id).cloned())
+    }
+
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Profile>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .values()
+            .find(|x| {
+                x.visibility != Visibility::Private
+                    && x.share_token.as_deref() == Some(share_token.as_str())
+            })
+            .cloned();
+
+        Ok(r)
+    }
+
+    async fn add_or_update(&self, profile: Profile) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        inner.insert(profile.user_id, profile);
+
+        Ok(())
+    }
+}
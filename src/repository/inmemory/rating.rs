@@ -0,0 +1,157 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use itertools::Itertools;
+use sai::Component;
+use uuid::Uuid;
+
+use crate::{
+    entity::{Rating, RatingAggregate, RatingSortBy, Sort},
+    repository::r#trait::RatingRepository,
+};
+
+#[cfg_attr(test, derive(Default))]
+#[derive(Component)]
+pub struct InMemoryRatingRepository {
+    inner: RwLock<HashMap<(Uuid, u32), Rating>>,
+}
+
+#[async_trait::async_trait]
+impl RatingRepository for InMemoryRatingRepository {
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: RatingSortBy,
+    ) -> crate::Result<Vec<Rating>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner.values().filter(|x| x.user_id == user_id);
+
+        let r = match sort_by {
+            RatingSortBy::CreatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.created_at.cmp(&a.created_at))
+            }
+            RatingSortBy::CreatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.created_at.cmp(&b.created_at))
+            }
+            RatingSortBy::UpdatedAt(Sort::Desc) => {
+                r.sorted_by(|a, b| b.updated_at.cmp(&a.updated_at))
+            }
+            RatingSortBy::UpdatedAt(Sort::Asc) => {
+                r.sorted_by(|a, b| a.updated_at.cmp(&b.updated_at))
+            }
+            RatingSortBy::Score(Sort::Desc) => {
+                r.sorted_by(|a, b| b.score.cmp(&a.score).then(b.updated_at.cmp(&a.updated_at)))
+            }
+            RatingSortBy::Score(Sort::Asc) => {
+                r.sorted_by(|a, b| a.score.cmp(&b.score).then(b.updated_at.cmp(&a.updated_at)))
+            }
+        }
+        .skip(per_page * (page - 1))
+        .take(per_page)
+        .cloned()
+        .collect();
+
+        Ok(r)
+    }
+
+    async fn add_or_update(&self, rating: Rating) -> crate::Result<()> {
+        let mut inner = self.inner.write().unwrap();
+
+        match inner.get_mut(&(rating.user_id, rating.book_id)) {
+            Some(exists) => {
+                exists.score = rating.score;
+                exists.note = rating.note;
+                exists.updated_at = rating.updated_at;
+            }
+            None => {
+                inner.insert((rating.user_id, rating.book_id), rating);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, user_id: Uuid, book_id: u32) -> crate::Result<bool> {
+        let mut inner = self.inner.write().unwrap();
+
+        Ok(inner.remove(&(user_id, book_id)).is_some())
+    }
+
+    async fn get_aggregates(&self, book_ids: Vec<u32>) -> crate::Result<Vec<RatingAggregate>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = book_ids
+            .into_iter()
+            .unique()
+            .sorted()
+            .filter_map(|book_id| {
+                let scores = inner
+                    .values()
+                    .filter(|x| x.book_id == book_id)
+                    .map(|x| x.score as f64)
+                    .collect::<Vec<_>>();
+
+                if scores.is_empty() {
+                    return None;
+                }
+
+                Some(RatingAggregate {
+                    book_id,
+                    average: scores.iter().sum::<f64>() / scores.len() as f64,
+                    count: scores.len(),
+                })
+            })
+            .collect();
+
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Rating, RatingAggregate},
+        repository::r#trait::RatingRepository,
+    };
+
+    use super::InMemoryRatingRepository;
+
+    #[tokio::test]
+    async fn aggregates() {
+        let repository = InMemoryRatingRepository::default();
+
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        for rating in [
+            Rating::new(a, 1, 5, None),
+            Rating::new(b, 1, 2, Some("meh".to_string())),
+            Rating::new(a, 2, 4, None),
+            // updated
+            Rating::new(a, 2, 3, None),
+        ] {
+            repository.add_or_update(rating).await.unwrap();
+        }
+
+        let r = repository.get_aggregates(vec![1, 2, 3]).await.unwrap();
+
+        assert_eq!(
+            r,
+            vec![
+                RatingAggregate {
+                    book_id: 1,
+                    average: 3.5,
+                    count: 2
+                },
+                RatingAggregate {
+                    book_id: 2,
+                    average: 3.0,
+                    count: 1
+                }
+            ]
+        );
+    }
+}
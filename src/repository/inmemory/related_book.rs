@@ -0,0 +1,135 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::RwLock,
+};
+
+use itertools::Itertools;
+use sai::{Component, Injected};
+
+use crate::{
+    entity::{Like, RelatedBook},
+    repository::r#trait::RelatedBookRepository,
+};
+
+use super::{paginate, InMemoryLikeRepository};
+
+#[derive(Component)]
+pub struct InMemoryRelatedBookRepository {
+    #[injected]
+    like_repository: Injected<InMemoryLikeRepository>,
+
+    inner: RwLock<Vec<RelatedBook>>,
+}
+
+/// 같은 사용자가 좋아요한 작품 쌍의 수
+fn related_books(likes: &[Like], min_support: usize) -> Vec<RelatedBook> {
+    let book_ids_by_user = likes
+        .iter()
+        .filter_map(|x| match x {
+            Like::Book {
+                book_id, user_id, ..
+            } => Some((*user_id, *book_id)),
+            Like::BookTag { .. } => None,
+        })
+        .into_group_map();
+
+    let mut support = HashMap::<(u32, u32), usize>::new();
+
+    for book_ids in book_ids_by_user.values() {
+        let book_ids = book_ids.iter().collect::<HashSet<_>>();
+
+        for (a, b) in book_ids.iter().cartesian_product(book_ids.iter()) {
+            if a != b {
+                *support.entry((**a, **b)).or_default() += 1;
+            }
+        }
+    }
+
+    support
+        .into_iter()
+        .filter(|(_, support)| *support >= min_support)
+        .map(|((book_id, related_book_id), support)| RelatedBook {
+            book_id,
+            related_book_id,
+            support,
+        })
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl RelatedBookRepository for InMemoryRelatedBookRepository {
+    async fn get_many(
+        &self,
+        book_id: u32,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<RelatedBook>> {
+        let inner = self.inner.read().unwrap();
+
+        let r = inner
+            .iter()
+            .filter(|x| x.book_id == book_id)
+            .sorted_by(|a, b| {
+                b.support
+                    .cmp(&a.support)
+                    .then(b.related_book_id.cmp(&a.related_book_id))
+            })
+            .cloned();
+
+        Ok(paginate(r, per_page, page))
+    }
+
+    async fn refresh(&self, min_support: usize) -> crate::Result<bool> {
+        let related = related_books(&self.like_repository.all(), min_support);
+
+        *self.inner.write().unwrap() = related;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use uuid::Uuid;
+
+    use crate::entity::{Like, RelatedBook};
+
+    use super::related_books;
+
+    #[test]
+    fn support() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let likes = vec![
+            Like::book(a, 1),
+            Like::book(a, 2),
+            Like::book(a, 3),
+            Like::book(b, 1),
+            Like::book(b, 2),
+            Like::book(c, 3),
+            Like::book_tag(c, "artist".to_string(), "x".to_string()),
+        ];
+
+        let r = related_books(&likes, 2)
+            .into_iter()
+            .sorted_by_key(|x| x.book_id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            r,
+            vec![
+                RelatedBook {
+                    book_id: 1,
+                    related_book_id: 2,
+                    support: 2
+                },
+                RelatedBook {
+                    book_id: 2,
+                    related_book_id: 1,
+                    support: 2
+                }
+            ]
+        );
+    }
+}
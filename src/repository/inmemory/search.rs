@@ -0,0 +1,227 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use sai::{Component, Injected};
+use uuid::Uuid;
+
+use crate::{
+    entity::{BookTitle, History, Like, Notification, SearchResult},
+    repository::r#trait::SearchRepository,
+};
+
+use super::{
+    paginate, InMemoryHistoryRepository, InMemoryLikeRepository, InMemoryNotificationRepository,
+};
+
+#[derive(Component)]
+pub struct InMemorySearchRepository {
+    #[injected]
+    like_repository: Injected<InMemoryLikeRepository>,
+
+    #[injected]
+    history_repository: Injected<InMemoryHistoryRepository>,
+
+    #[injected]
+    notification_repository: Injected<InMemoryNotificationRepository>,
+
+    titles: RwLock<HashMap<u32, BookTitle>>,
+}
+
+/// 대소문자 구분 없이 부분 문자열로 찾음
+fn matches(text: &str, query: &str) -> bool {
+    text.to_lowercase().contains(&query.to_lowercase())
+}
+
+#[async_trait::async_trait]
+impl SearchRepository for InMemorySearchRepository {
+    async fn add_or_update_title(&self, book_title: BookTitle) -> crate::Result<()> {
+        let mut titles = self.titles.write().unwrap();
+
+        match titles.get_mut(&book_title.book_id) {
+            // 올라온 날짜는 처음 저장된 것을 유지함
+            Some(exists) => {
+                exists.title = book_title.title;
+                exists.published_at = book_title.published_at.or(exists.published_at);
+                exists.updated_at = book_title.updated_at;
+            }
+            None => {
+                let published_at = book_title.published_at.or(Some(book_title.updated_at));
+
+                titles.insert(
+                    book_title.book_id,
+                    BookTitle {
+                        published_at,
+                        ..book_title
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn add_or_update_titles(&self, book_titles: Vec<BookTitle>) -> crate::Result<()> {
+        let mut titles = self.titles.write().unwrap();
+
+        for book_title in book_titles {
+            match titles.get_mut(&book_title.book_id) {
+                // 여러 개를 한 번에 저장할 때는 이미 저장된 날짜를 유지함
+                Some(exists) => {
+                    exists.title = book_title.title;
+                    exists.published_at = exists.published_at.or(book_title.published_at);
+                    exists.updated_at = book_title.updated_at;
+                }
+                None => {
+                    let published_at = book_title.published_at.or(Some(book_title.updated_at));
+
+                    titles.insert(
+                        book_title.book_id,
+                        BookTitle {
+                            published_at,
+                            ..book_title
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_untitled_book_ids(&self, after: u32, limit: usize) -> crate::Result<Vec<u32>> {
+        let likes = self.like_repository.all();
+        let histories = self.history_repository.all();
+
+        let titles = self.titles.read().unwrap();
+
+        let liked = likes.into_iter().filter_map(|x| match x {
+            Like::Book { book_id, .. } => Some(book_id),
+            Like::BookTag { .. } => None,
+        });
+        let read = histories.into_iter().map(|x| match x {
+            History::Book { book_id, .. } => book_id,
+        });
+
+        let book_ids = liked
+            .chain(read)
+            .filter(|book_id| *book_id > after && !titles.contains_key(book_id))
+            .unique()
+            .sorted()
+            .take(limit)
+            .collect();
+
+        Ok(book_ids)
+    }
+
+    async fn search(
+        &self,
+        user_id: Uuid,
+        query: String,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<SearchResult>> {
+        let likes = self.like_repository.all();
+        let histories = self.history_repository.all();
+        let notifications = self.notification_repository.all();
+
+        let titles = self.titles.read().unwrap();
+
+        let title = |book_id: u32| {
+            titles
+                .get(&book_id)
+                .filter(|x| matches(&x.title, &query))
+                .map(|x| x.title.clone())
+        };
+
+        let likes = likes
+            .into_iter()
+            .filter(|x| x.user_id() == user_id)
+            .filter_map(|x| match x {
+                Like::Book {
+                    book_id,
+                    user_id,
+                    created_at,
+                } => Some(SearchResult::LikeBook {
+                    book_id,
+                    title: title(book_id)?,
+                    user_id,
+                    created_at,
+                }),
+                Like::BookTag {
+                    tag_kind,
+                    tag_name,
+                    user_id,
+                    created_at,
+                } => matches(&tag_name, &query).then(|| SearchResult::LikeBookTag {
+                    tag_kind,
+                    tag_name,
+                    user_id,
+                    created_at,
+                }),
+            });
+
+        let histories = histories
+            .into_iter()
+            .filter(|x| x.user_id() == user_id)
+            .filter_map(|x| match x {
+                History::Book {
+                    book_id,
+                    user_id,
+                    updated_at,
+                    ..
+                } => Some(SearchResult::History {
+                    book_id,
+                    title: title(book_id)?,
+                    user_id,
+                    updated_at,
+                }),
+            });
+
+        let notifications = notifications
+            .into_iter()
+            .filter(|x| x.user_id() == user_id)
+            .filter_map(|x| match x {
+                Notification::Book {
+                    book_id,
+                    user_id,
+                    created_at,
+                    ..
+                } => Some(SearchResult::Notification {
+                    book_id,
+                    title: title(book_id)?,
+                    user_id,
+                    created_at,
+                }),
+                Notification::BookSeries { .. } | Notification::User { .. } => None,
+            });
+
+        let at = |x: &SearchResult| -> DateTime<Utc> {
+            match x {
+                SearchResult::LikeBook { created_at, .. }
+                | SearchResult::LikeBookTag { created_at, .. }
+                | SearchResult::Notification { created_at, .. } => *created_at,
+                SearchResult::History { updated_at, .. } => *updated_at,
+            }
+        };
+
+        let r = likes
+            .chain(histories)
+            .chain(notifications)
+            .sorted_by(|a, b| at(b).cmp(&at(a)));
+
+        Ok(paginate(r, per_page, page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    #[test]
+    fn case_insensitive() {
+        assert!(matches("Hello World", "wor"));
+        assert!(matches("Hello World", "HELLO"));
+        assert!(!matches("Hello World", "bye"));
+    }
+}
@@ -9,36 +9,73 @@ use std::sync::Arc;
 
 use sai::{Component, Injected};
 
+use crate::database::Migrator;
+
+/// 저장소 구현의 묶음
+///
+/// 레지스트리에서 `PostgresqlBackend`나 `InMemoryBackend`를 골라서 넣음
+pub trait Backend: Send + Sync + 'static {
+    /// 마이그레이션과 `/readyz`에서 씀
+    type Database: Migrator;
+
+    type User: r#trait::UserRepository + Component;
+    type Like: r#trait::LikeRepository + Component;
+    type Dislike: r#trait::DislikeRepository + Component;
+    type Notification: r#trait::NotificationRepository + Component;
+    type FcmToken: r#trait::FcmTokenRepository + Component;
+    type History: r#trait::HistoryRepository + Component;
+    type Collection: r#trait::CollectionRepository + Component;
+    type Profile: r#trait::ProfileRepository + Component;
+    type Rating: r#trait::RatingRepository + Component;
+    type Follow: r#trait::FollowRepository + Component;
+    type Block: r#trait::BlockRepository + Component;
+    type RelatedBook: r#trait::RelatedBookRepository + Component;
+    type Search: r#trait::SearchRepository + Component;
+}
+
 #[derive(Component)]
-pub struct RepositorySet {
-    #[cfg(not(test))]
+pub struct RepositorySet<B: Backend> {
+    #[injected]
+    user_repository: Injected<B::User>,
+
+    #[injected]
+    like_repository: Injected<B::Like>,
+
+    #[injected]
+    dislike_repository: Injected<B::Dislike>,
+
+    #[injected]
+    notification_repository: Injected<B::Notification>,
+
     #[injected]
-    user_repository: Injected<PostgresqlUserRepository>,
+    fcm_token_repository: Injected<B::FcmToken>,
 
-    #[cfg(test)]
     #[injected]
-    user_repository: Injected<InMemoryUserRepository>,
+    history_repository: Injected<B::History>,
 
     #[injected]
-    like_repository: Injected<PostgresqlLikeRepository>,
+    collection_repository: Injected<B::Collection>,
 
     #[injected]
-    dislike_repository: Injected<PostgresqlDislikeRepository>,
+    profile_repository: Injected<B::Profile>,
 
-    /* #[cfg(test)]
     #[injected]
-    like_repository: Injected<InMemoryLikeRepository>, */
+    rating_repository: Injected<B::Rating>,
+
     #[injected]
-    notification_repository: Injected<PostgresqlNotificationRepository>,
+    follow_repository: Injected<B::Follow>,
 
     #[injected]
-    fcm_token_repository: Injected<PostgresqlFcmTokenRepository>,
+    block_repository: Injected<B::Block>,
 
     #[injected]
-    history_repository: Injected<PostgresqlHistoryRepository>,
+    related_book_repository: Injected<B::RelatedBook>,
+
+    #[injected]
+    search_repository: Injected<B::Search>,
 }
 
-impl RepositorySet {
+impl<B: Backend> RepositorySet<B> {
     pub fn user(&self) -> Arc<impl r#trait::UserRepository> {
         Arc::clone(&self.user_repository)
     }
@@ -62,4 +99,32 @@ impl RepositorySet {
     pub fn history(&self) -> Arc<impl r#trait::HistoryRepository> {
         Arc::clone(&self.history_repository)
     }
+
+    pub fn collection(&self) -> Arc<impl r#trait::CollectionRepository> {
+        Arc::clone(&self.collection_repository)
+    }
+
+    pub fn profile(&self) -> Arc<impl r#trait::ProfileRepository> {
+        Arc::clone(&self.profile_repository)
+    }
+
+    pub fn rating(&self) -> Arc<impl r#trait::RatingRepository> {
+        Arc::clone(&self.rating_repository)
+    }
+
+    pub fn follow(&self) -> Arc<impl r#trait::FollowRepository> {
+        Arc::clone(&self.follow_repository)
+    }
+
+    pub fn block(&self) -> Arc<impl r#trait::BlockRepository> {
+        Arc::clone(&self.block_repository)
+    }
+
+    pub fn related_book(&self) -> Arc<impl r#trait::RelatedBookRepository> {
+        Arc::clone(&self.related_book_repository)
+    }
+
+    pub fn search(&self) -> Arc<impl r#trait::SearchRepository> {
+        Arc::clone(&self.search_repository)
+    }
 }
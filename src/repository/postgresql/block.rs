@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    ColumnTrait, Condition, DbErr, EntityTrait, IdenStatic, QueryFilter,
+};
+use uuid::Uuid;
+
+use crate::{
+    constant::postgresql,
+    database::{postgresql::entity::block, DatabaseSet},
+    entity::{Block, BlockKind},
+    metrics::Metrics,
+    repository::r#trait::BlockRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlBlockRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlBlockRepository {
+    async fn start(&mut self) {
+        block::create_table(self.database.postgresql()).await;
+    }
+}
+
+/// `column`의 사용자가 `user_id`와 차단 관계가 아님
+///
+/// `retain_unblocked`와 같은 조건을 다른 쿼리 안에서 쓸 때 사용함, `column`은 `follows.follower_id`처럼 씀
+pub(super) fn unblocked(user_id: Uuid, column: &str, exclude_muted: bool) -> SimpleExpr {
+    let kinds = if exclude_muted {
+        format!("{}, {}", BlockKind::Block as i16, BlockKind::Mute as i16)
+    } else {
+        format!("{}", BlockKind::Block as i16)
+    };
+
+    Expr::cust(&format!(
+        r#"NOT EXISTS (
+            SELECT 1 FROM {table_name} b
+            WHERE (b.user_id = '{user_id}' AND b.target_id = {column} AND b.kind = {block})
+               OR (b.target_id = '{user_id}' AND b.user_id = {column} AND b.kind IN ({kinds}))
+        )"#,
+        table_name = block::Entity.as_str(),
+        block = BlockKind::Block as i16,
+    ))
+}
+
+#[async_trait::async_trait]
+impl BlockRepository for PostgresqlBlockRepository {
+    #[tracing::instrument(name = "BlockRepository::add", skip_all)]
+    async fn add(&self, block: Block) -> crate::Result<bool> {
+        let _timer = self.metrics.query("BlockRepository::add");
+
+        let r = block::Entity::insert::<block::ActiveModel>(block.into())
+            .exec(self.database.postgresql())
+            .await;
+
+        match r {
+            Ok(_) => Ok(true),
+            Err(err) => match err {
+                DbErr::Query(err) if err.contains(postgresql::DUPLICATE_KEY_VALUE) => Ok(false),
+                err => Err(err.into()),
+            },
+        }
+    }
+
+    #[tracing::instrument(name = "BlockRepository::remove", skip_all)]
+    async fn remove(&self, user_id: Uuid, target_id: Uuid, kind: BlockKind) -> crate::Result<bool> {
+        let _timer = self.metrics.query("BlockRepository::remove");
+
+        let r = block::Entity::delete_by_id(block::ActiveModel::id(user_id, target_id, kind))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+
+    #[tracing::instrument(name = "BlockRepository::retain_unblocked", skip_all)]
+    async fn retain_unblocked(
+        &self,
+        user_id: Uuid,
+        user_ids: Vec<Uuid>,
+        exclude_muted: bool,
+    ) -> crate::Result<Vec<Uuid>> {
+        let _timer = self.metrics.query("BlockRepository::retain_unblocked");
+
+        if user_ids.is_empty() {
+            return Ok(user_ids);
+        }
+
+        // user_id가 차단한 사용자
+        let blocked_by_user = Condition::all()
+            .add(block::Column::UserId.eq(user_id))
+            .add(block::Column::TargetId.is_in(user_ids.clone()))
+            .add(block::Column::Kind.eq(BlockKind::Block as i16));
+
+        let kinds = if exclude_muted {
+            vec![BlockKind::Block as i16, BlockKind::Mute as i16]
+        } else {
+            vec![BlockKind::Block as i16]
+        };
+
+        // user_id를 차단(뮤트)한 사용자
+        let blocking_user = Condition::all()
+            .add(block::Column::TargetId.eq(user_id))
+            .add(block::Column::UserId.is_in(user_ids.clone()))
+            .add(block::Column::Kind.is_in(kinds));
+
+        let r = block::Entity::find()
+            .filter(Condition::any().add(blocked_by_user).add(blocking_user))
+            .all(self.database.postgresql())
+            .await?;
+
+        let excluded = r
+            .into_iter()
+            .map(|x| {
+                if x.user_id == user_id {
+                    x.target_id
+                } else {
+                    x.user_id
+                }
+            })
+            .collect::<HashSet<_>>();
+
+        Ok(user_ids
+            .into_iter()
+            .filter(|x| !excluded.contains(x))
+            .collect())
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use crate::{
+        entity::{Block, BlockKind},
+        repository::{postgresql::tests::context, r#trait::BlockRepository},
+    };
+
+    #[tokio::test]
+    async fn retain_unblocked() {
+        let context = context().await;
+        let repository = &context.block;
+
+        let me = context.add_user().await;
+        let blocked = context.add_user().await;
+        let blocking = context.add_user().await;
+        let muting = context.add_user().await;
+        let other = context.add_user().await;
+
+        let blocks = [
+            Block::new(me, blocked, BlockKind::Block),
+            Block::new(blocking, me, BlockKind::Block),
+            Block::new(muting, me, BlockKind::Mute),
+        ];
+
+        for block in blocks {
+            assert!(repository.add(block).await.unwrap());
+        }
+
+        let user_ids = vec![blocked, blocking, muting, other];
+
+        let r = repository
+            .retain_unblocked(me, user_ids.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(r, vec![muting, other]);
+
+        let r = repository
+            .retain_unblocked(me, user_ids, true)
+            .await
+            .unwrap();
+        assert_eq!(r, vec![other]);
+
+        assert!(repository
+            .remove(muting, me, BlockKind::Mute)
+            .await
+            .unwrap());
+        assert!(!repository
+            .remove(muting, me, BlockKind::Block)
+            .await
+            .unwrap());
+
+        context.teardown().await;
+    }
+}
@@ -0,0 +1,391 @@
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbErr, EntityTrait,
+    IdenStatic, PaginatorTrait, QueryFilter, QueryOrder, Statement, TransactionError,
+    TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::{
+    constant::postgresql,
+    database::{postgresql::entity::collection, DatabaseSet},
+    entity::{Collection, CollectionItem, CollectionSortBy, Sort, Visibility},
+    metrics::Metrics,
+    repository::r#trait::CollectionRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlCollectionRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlCollectionRepository {
+    async fn start(&mut self) {
+        collection::create_table(self.database.postgresql()).await;
+        collection::item::create_table(self.database.postgresql()).await;
+    }
+}
+
+/// 트랜잭션이 끝날 때까지 같은 컬렉션의 작품 순서를 바꾸지 못하게 함
+async fn lock_collection(txn: &DatabaseTransaction, collection_id: Uuid) -> Result<(), DbErr> {
+    let query = format!(
+        "SELECT id FROM {table_name} WHERE id = $1 FOR UPDATE",
+        table_name = collection::Entity.as_str()
+    );
+
+    txn.execute(Statement::from_sql_and_values(
+        txn.get_database_backend(),
+        &query,
+        [collection_id.into()],
+    ))
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl CollectionRepository for PostgresqlCollectionRepository {
+    #[tracing::instrument(name = "CollectionRepository::get", skip_all)]
+    async fn get(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<Option<Collection>> {
+        let _timer = self.metrics.query("CollectionRepository::get");
+
+        let r = collection::Entity::find_by_id(collection_id)
+            .filter(collection::Column::UserId.eq(user_id))
+            .one(self.database.postgresql())
+            .await?;
+
+        Ok(r.map(Into::into))
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::get_by_share_token", skip_all)]
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Collection>> {
+        let _timer = self
+            .metrics
+            .query("CollectionRepository::get_by_share_token");
+
+        let r = collection::Entity::find()
+            .filter(collection::Column::ShareToken.eq(share_token))
+            .filter(collection::Column::Visibility.ne(Visibility::Private as i16))
+            .one(self.database.postgresql())
+            .await?;
+
+        Ok(r.map(Into::into))
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::get_many_public", skip_all)]
+    async fn get_many_public(&self, user_id: Uuid) -> crate::Result<Vec<Collection>> {
+        let _timer = self.metrics.query("CollectionRepository::get_many_public");
+
+        let r = collection::Entity::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Visibility.eq(Visibility::Public as i16))
+            .order_by_desc(collection::Column::UpdatedAt)
+            .all(self.database.postgresql())
+            .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::get_many", skip_all)]
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: CollectionSortBy,
+    ) -> crate::Result<Vec<Collection>> {
+        let _timer = self.metrics.query("CollectionRepository::get_many");
+
+        let select = collection::Entity::find();
+        let r = match sort_by {
+            CollectionSortBy::CreatedAt(Sort::Desc) => {
+                select.order_by_desc(collection::Column::CreatedAt)
+            }
+            CollectionSortBy::CreatedAt(Sort::Asc) => {
+                select.order_by_asc(collection::Column::CreatedAt)
+            }
+            CollectionSortBy::UpdatedAt(Sort::Desc) => {
+                select.order_by_desc(collection::Column::UpdatedAt)
+            }
+            CollectionSortBy::UpdatedAt(Sort::Asc) => {
+                select.order_by_asc(collection::Column::UpdatedAt)
+            }
+        }
+        .filter(collection::Column::UserId.eq(user_id))
+        .paginate(self.database.postgresql(), per_page)
+        .fetch_page(page - 1)
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::get_by_name", skip_all)]
+    async fn get_by_name(&self, user_id: Uuid, name: String) -> crate::Result<Option<Collection>> {
+        let _timer = self.metrics.query("CollectionRepository::get_by_name");
+
+        let r = collection::Entity::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Name.eq(name))
+            .one(self.database.postgresql())
+            .await?;
+
+        Ok(r.map(Into::into))
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::add", skip_all)]
+    async fn add(&self, collection: Collection) -> crate::Result<bool> {
+        let _timer = self.metrics.query("CollectionRepository::add");
+
+        let r = collection::Entity::insert::<collection::ActiveModel>(collection.into())
+            .exec(self.database.postgresql())
+            .await;
+
+        match r {
+            Ok(_) => Ok(true),
+            Err(err) => match err {
+                DbErr::Query(err) if err.contains(postgresql::DUPLICATE_KEY_VALUE) => Ok(false),
+                err => Err(err.into()),
+            },
+        }
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::update", skip_all)]
+    async fn update(
+        &self,
+        Collection {
+            id,
+            user_id,
+            name,
+            visibility,
+            share_token,
+            updated_at,
+            ..
+        }: Collection,
+    ) -> crate::Result<bool> {
+        let _timer = self.metrics.query("CollectionRepository::update");
+
+        let r = collection::Entity::update_many()
+            .col_expr(collection::Column::Name, Expr::value(name))
+            .col_expr(
+                collection::Column::Visibility,
+                Expr::value(visibility as i16),
+            )
+            .col_expr(collection::Column::ShareToken, Expr::value(share_token))
+            .col_expr(collection::Column::UpdatedAt, Expr::value(updated_at))
+            .filter(collection::Column::Id.eq(id))
+            .filter(collection::Column::UserId.eq(user_id))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::remove", skip_all)]
+    async fn remove(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<bool> {
+        let _timer = self.metrics.query("CollectionRepository::remove");
+
+        // collections_item은 on delete cascade로 같이 지워짐
+        let r = collection::Entity::delete_many()
+            .filter(collection::Column::Id.eq(collection_id))
+            .filter(collection::Column::UserId.eq(user_id))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::get_items", skip_all)]
+    async fn get_items(&self, collection_id: Uuid) -> crate::Result<Vec<CollectionItem>> {
+        let _timer = self.metrics.query("CollectionRepository::get_items");
+
+        let r = collection::item::Entity::find()
+            .filter(collection::item::Column::CollectionId.eq(collection_id))
+            .order_by_asc(collection::item::Column::Position)
+            .all(self.database.postgresql())
+            .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::add_item", skip_all)]
+    async fn add_item(
+        &self,
+        CollectionItem {
+            collection_id,
+            book_id,
+            created_at,
+            ..
+        }: CollectionItem,
+    ) -> crate::Result<bool> {
+        let _timer = self.metrics.query("CollectionRepository::add_item");
+
+        let query = format!(
+            r#"
+            INSERT INTO
+                {table_name}(id, collection_id, book_id, position, created_at)
+            SELECT
+                $1, $2, $3, COALESCE(MAX(position), 0) + 1, $4
+            FROM {table_name}
+            WHERE collection_id = $2
+            "#,
+            table_name = collection::item::Entity.as_str()
+        );
+
+        let r = self
+            .database
+            .postgresql()
+            .transaction::<_, (), DbErr>(|txn| {
+                Box::pin(async move {
+                    let psql = txn.get_database_backend();
+
+                    // 동시에 추가하면 같은 position을 가져갈 수 있으므로 컬렉션을 잠금
+                    lock_collection(txn, collection_id).await?;
+
+                    txn.execute(Statement::from_sql_and_values(
+                        psql,
+                        &query,
+                        [
+                            collection::item::ActiveModel::id(collection_id, book_id).into(),
+                            collection_id.into(),
+                            (book_id as i32).into(),
+                            created_at.into(),
+                        ],
+                    ))
+                    .await?;
+
+                    Ok(())
+                })
+            })
+            .await;
+
+        match r {
+            Ok(_) => Ok(true),
+            Err(TransactionError::Transaction(DbErr::Query(err)))
+                if err.contains(postgresql::DUPLICATE_KEY_VALUE) =>
+            {
+                Ok(false)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::remove_item", skip_all)]
+    async fn remove_item(&self, collection_id: Uuid, book_id: u32) -> crate::Result<bool> {
+        let _timer = self.metrics.query("CollectionRepository::remove_item");
+
+        let r = collection::item::Entity::delete_many()
+            .filter(collection::item::Column::CollectionId.eq(collection_id))
+            .filter(collection::item::Column::BookId.eq(book_id as i32))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+
+    #[tracing::instrument(name = "CollectionRepository::reorder_items", skip_all)]
+    async fn reorder_items(&self, collection_id: Uuid, book_ids: Vec<u32>) -> crate::Result<()> {
+        let _timer = self.metrics.query("CollectionRepository::reorder_items");
+
+        self.database
+            .postgresql()
+            .transaction::<_, (), DbErr>(|txn| {
+                Box::pin(async move {
+                    lock_collection(txn, collection_id).await?;
+
+                    for (i, book_id) in book_ids.into_iter().enumerate() {
+                        collection::item::Entity::update_many()
+                            .col_expr(
+                                collection::item::Column::Position,
+                                Expr::value((i + 1) as i32),
+                            )
+                            .filter(collection::item::Column::CollectionId.eq(collection_id))
+                            .filter(collection::item::Column::BookId.eq(book_id as i32))
+                            .exec(txn)
+                            .await?;
+                    }
+
+                    Ok(())
+                })
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Collection, CollectionItem},
+        repository::{postgresql::tests::context, r#trait::CollectionRepository},
+    };
+
+    #[tokio::test]
+    async fn add_and_reorder_items() {
+        let context = context().await;
+        let repository = &context.collection;
+
+        let user_id = context.add_user().await;
+        let collection = Collection::new(user_id, "to read".to_string());
+        let collection_id = collection.id;
+
+        assert!(repository.add(collection).await.unwrap());
+
+        // duplicated name
+        let same_name = Collection::new(user_id, "to read".to_string());
+        assert!(!repository.add(same_name).await.unwrap());
+
+        let r = repository
+            .get_by_name(user_id, "to read".to_string())
+            .await
+            .unwrap();
+        assert_eq!(r.map(|x| x.id), Some(collection_id));
+
+        for book_id in [3, 1, 2] {
+            let item = CollectionItem::new(collection_id, book_id);
+
+            assert!(repository.add_item(item).await.unwrap());
+        }
+
+        // duplicated
+        let item = CollectionItem::new(collection_id, 1);
+        assert!(!repository.add_item(item).await.unwrap());
+
+        let book_ids = |items: Vec<CollectionItem>| -> Vec<u32> {
+            items.into_iter().map(|x| x.book_id).collect()
+        };
+
+        let r = book_ids(repository.get_items(collection_id).await.unwrap());
+        assert_eq!(r, vec![3, 1, 2]);
+
+        repository
+            .reorder_items(collection_id, vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        let r = book_ids(repository.get_items(collection_id).await.unwrap());
+        assert_eq!(r, vec![1, 2, 3]);
+
+        // another user can't remove
+        assert!(!repository
+            .remove(Uuid::new_v4(), collection_id)
+            .await
+            .unwrap());
+        assert!(repository.remove(user_id, collection_id).await.unwrap());
+        assert!(repository
+            .get_items(collection_id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        context.teardown().await;
+    }
+}
@@ -10,7 +10,8 @@ use crate::{
     constant::postgresql,
     database::{postgresql::entity::like, DatabaseSet},
     entity::{Dislike, DislikeKind, DislikeSortBy, Sort},
-    repository::r#trait::DislikeRepository,
+    metrics::Metrics,
+    repository::r#trait::{DislikeBy, DislikeRepository},
 };
 
 #[derive(Component)]
@@ -18,6 +19,9 @@ use crate::{
 pub struct PostgresqlDislikeRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -31,6 +35,7 @@ impl ComponentLifecycle for PostgresqlDislikeRepository {
 
 #[async_trait::async_trait]
 impl DislikeRepository for PostgresqlDislikeRepository {
+    #[tracing::instrument(name = "DislikeRepository::get_many", skip_all)]
     async fn get_many(
         &self,
         user_id: Uuid,
@@ -39,6 +44,8 @@ impl DislikeRepository for PostgresqlDislikeRepository {
         page: usize,
         sort_by: DislikeSortBy,
     ) -> crate::Result<Vec<Dislike>> {
+        let _timer = self.metrics.query("DislikeRepository::get_many");
+
         let dislikes = match kind {
             Some(DislikeKind::Book) => {
                 let select = like::book::Entity::find();
@@ -103,11 +110,11 @@ impl DislikeRepository for PostgresqlDislikeRepository {
                     r#"
                     SELECT * FROM
                     (
-                        SELECT id, user_id, book_id, NULL AS tag_kind, NULL AS tag_name, created_at
+                        SELECT id, user_id, book_id, NULL AS tag_kind, NULL AS tag_name, is_dislike, created_at
                             FROM {like_book_table}
                             WHERE user_id = $1 AND is_dislike = true
                         UNION ALL
-                        SELECT id, user_id, NULL, tag_kind, tag_name, created_at
+                        SELECT id, user_id, NULL, tag_kind, tag_name, is_dislike, created_at
                             FROM {like_book_tag_table}
                             WHERE user_id = $1 AND is_dislike = true
                     ) AS a
@@ -173,6 +180,31 @@ impl DislikeRepository for PostgresqlDislikeRepository {
         Ok(dislikes)
     }
 
+    #[tracing::instrument(name = "DislikeRepository::get_many_by", skip_all)]
+    async fn get_many_by(&self, user_id: Uuid, by: DislikeBy) -> crate::Result<Vec<Dislike>> {
+        let _timer = self.metrics.query("DislikeRepository::get_many_by");
+
+        match by {
+            DislikeBy::Book { ids } => {
+                if ids.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                let dislikes = like::book::Entity::find()
+                    .filter(like::book::Column::UserId.eq(user_id))
+                    .filter(like::book::Column::IsDislike.eq(true))
+                    .filter(like::book::Column::BookId.is_in(ids.into_iter().map(|x| x as i32)))
+                    .all(self.database.postgresql())
+                    .await?;
+
+                Ok(dislikes
+                    .into_iter()
+                    .map(like::book::Model::into_dislike)
+                    .collect())
+            }
+        }
+    }
+
     /* async fn filter(&self, user_id: Uuid, dislikes: Vec<Dislike>) -> crate::Result<bool> {
         let dislikes_book = dislikes.iter().filter(|x| x.kind() == DislikeKind::Book);
         let dislikes_book_tag = dislikes.iter().filter(|x| x.kind() == DislikeKind::BookTag);
@@ -180,7 +212,10 @@ impl DislikeRepository for PostgresqlDislikeRepository {
         todo!()
     } */
 
+    #[tracing::instrument(name = "DislikeRepository::add", skip_all)]
     async fn add(&self, dislike: Dislike) -> crate::Result<bool> {
+        let _timer = self.metrics.query("DislikeRepository::add");
+
         let r = match dislike.kind() {
             DislikeKind::Book => {
                 let r = like::book::Entity::insert::<like::book::ActiveModel>(dislike.into())
@@ -209,7 +244,10 @@ impl DislikeRepository for PostgresqlDislikeRepository {
         }
     }
 
+    #[tracing::instrument(name = "DislikeRepository::remove", skip_all)]
     async fn remove(&self, dislike: Dislike) -> crate::Result<bool> {
+        let _timer = self.metrics.query("DislikeRepository::remove");
+
         let r = match dislike.kind() {
             DislikeKind::Book => {
                 like::book::Entity::delete::<like::book::ActiveModel>(dislike.into())
@@ -230,3 +268,139 @@ impl DislikeRepository for PostgresqlDislikeRepository {
         }
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        entity::{Dislike, DislikeKind, DislikeSortBy, Like, Sort},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{DislikeBy, DislikeRepository, LikeRepository},
+        },
+    };
+
+    fn keys(dislikes: &[Dislike]) -> Vec<String> {
+        dislikes
+            .iter()
+            .map(|x| match x {
+                Dislike::Book { book_id, .. } => book_id.to_string(),
+                Dislike::BookTag { tag_name, .. } => tag_name.clone(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let context = context().await;
+        let repository = &context.dislike;
+        let user_id = context.add_user().await;
+        let now = Utc::now();
+
+        // 0, 2, 4, ... 작품 / 1, 3, 5, ... 태그
+        for x in 0..10_u32 {
+            let created_at = now - Duration::minutes(x as i64);
+
+            let dislike = match x % 2 {
+                0 => Dislike::Book {
+                    book_id: x,
+                    user_id,
+                    created_at,
+                },
+                _ => Dislike::BookTag {
+                    tag_kind: "artist".to_string(),
+                    tag_name: x.to_string(),
+                    user_id,
+                    created_at,
+                },
+            };
+
+            assert!(repository.add(dislike).await.unwrap());
+        }
+
+        // 좋아요는 싫어요 목록에 섞이지 않음
+        context.like.add(Like::book(user_id, 100)).await.unwrap();
+
+        let r = repository
+            .get_many(user_id, None, 4, 2, DislikeSortBy::CreatedAt(Sort::Desc))
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["4", "5", "6", "7"]);
+
+        let r = repository
+            .get_many(user_id, None, 4, 3, DislikeSortBy::CreatedAt(Sort::Desc))
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["8", "9"]);
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(DislikeKind::Book),
+                3,
+                1,
+                DislikeSortBy::CreatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["8", "6", "4"]);
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(DislikeKind::BookTag),
+                10,
+                1,
+                DislikeSortBy::CreatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["9", "7", "5", "3", "1"]);
+
+        let r = repository
+            .get_many(user_id, None, 25, 1, DislikeSortBy::Random)
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 10);
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn add_and_remove() {
+        let context = context().await;
+        let repository = &context.dislike;
+        let user_id = context.add_user().await;
+
+        assert!(repository.add(Dislike::book(user_id, 1)).await.unwrap());
+        assert!(!repository.add(Dislike::book(user_id, 1)).await.unwrap());
+
+        assert!(repository.remove(Dislike::book(user_id, 1)).await.unwrap());
+        assert!(!repository.remove(Dislike::book(user_id, 1)).await.unwrap());
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn get_many_by() {
+        let context = context().await;
+        let repository = &context.dislike;
+        let (a, b) = (context.add_user().await, context.add_user().await);
+
+        repository.add(Dislike::book(a, 1)).await.unwrap();
+        repository.add(Dislike::book(a, 2)).await.unwrap();
+        // 좋아요는 제외
+        context.like.add(Like::book(a, 3)).await.unwrap();
+        // 다른 사용자
+        repository.add(Dislike::book(b, 4)).await.unwrap();
+
+        let r = repository
+            .get_many_by(a, DislikeBy::Book { ids: vec![1, 3, 4] })
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["1"]);
+
+        context.teardown().await;
+    }
+}
@@ -5,6 +5,7 @@ use uuid::Uuid;
 use crate::{
     database::{postgresql::entity::fcm_token, DatabaseSet},
     entity::fcm_token::FcmToken,
+    metrics::Metrics,
     repository::r#trait::FcmTokenRepository,
 };
 
@@ -13,6 +14,9 @@ use crate::{
 pub struct PostgresqlFcmTokenRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -24,6 +28,7 @@ impl ComponentLifecycle for PostgresqlFcmTokenRepository {
 
 #[async_trait::async_trait]
 impl FcmTokenRepository for PostgresqlFcmTokenRepository {
+    #[tracing::instrument(name = "FcmTokenRepository::add_or_update", skip_all)]
     async fn add_or_update(
         &self,
         FcmToken {
@@ -32,6 +37,8 @@ impl FcmTokenRepository for PostgresqlFcmTokenRepository {
             fcm_token,
         }: FcmToken,
     ) -> crate::Result<()> {
+        let _timer = self.metrics.query("FcmTokenRepository::add_or_update");
+
         let query = format!(
             r#"
             INSERT INTO
@@ -60,7 +67,10 @@ impl FcmTokenRepository for PostgresqlFcmTokenRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "FcmTokenRepository::get_many", skip_all)]
     async fn get_many(&self, user_ids: Vec<Uuid>) -> crate::Result<Vec<String>> {
+        let _timer = self.metrics.query("FcmTokenRepository::get_many");
+
         let r = fcm_token::Entity::find()
             .filter(Expr::col(fcm_token::Column::UserId).is_in(user_ids))
             .filter(
@@ -73,3 +83,36 @@ impl FcmTokenRepository for PostgresqlFcmTokenRepository {
         Ok(r.into_iter().map(|x| x.fcm_token).collect())
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::fcm_token::FcmToken,
+        repository::{postgresql::tests::context, r#trait::FcmTokenRepository},
+    };
+
+    #[tokio::test]
+    async fn add_or_update() {
+        let context = context().await;
+        let repository = &context.fcm_token;
+        let (udid, user_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        for fcm_token in ["a", "b"] {
+            repository
+                .add_or_update(FcmToken::new(udid, user_id, fcm_token.to_string()))
+                .await
+                .unwrap();
+        }
+
+        let r = repository
+            .get_many(vec![user_id, Uuid::new_v4()])
+            .await
+            .unwrap();
+
+        assert_eq!(r, vec!["b".to_string()]);
+
+        context.teardown().await;
+    }
+}
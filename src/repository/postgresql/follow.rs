@@ -0,0 +1,231 @@
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{
+    ColumnTrait, DbErr, EntityTrait, IdenStatic, PaginatorTrait, QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::{
+    constant::postgresql,
+    database::{postgresql::entity::follow, DatabaseSet},
+    entity::{Follow, FollowSortBy, Sort},
+    metrics::Metrics,
+    repository::r#trait::FollowRepository,
+};
+
+use super::block::unblocked;
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlFollowRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlFollowRepository {
+    async fn start(&mut self) {
+        follow::create_table(self.database.postgresql()).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl FollowRepository for PostgresqlFollowRepository {
+    #[tracing::instrument(name = "FollowRepository::get_followers", skip_all)]
+    async fn get_followers(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>> {
+        let _timer = self.metrics.query("FollowRepository::get_followers");
+
+        let select = follow::Entity::find();
+        let r = match sort_by {
+            FollowSortBy::CreatedAt(Sort::Desc) => select.order_by_desc(follow::Column::CreatedAt),
+            FollowSortBy::CreatedAt(Sort::Asc) => select.order_by_asc(follow::Column::CreatedAt),
+        }
+        .filter(follow::Column::FolloweeId.eq(user_id))
+        // 차단 관계인 사용자는 보이지 않음
+        .filter(unblocked(
+            user_id,
+            &format!("{}.follower_id", follow::Entity.as_str()),
+            false,
+        ))
+        .paginate(self.database.postgresql(), per_page)
+        .fetch_page(page - 1)
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "FollowRepository::get_following", skip_all)]
+    async fn get_following(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>> {
+        let _timer = self.metrics.query("FollowRepository::get_following");
+
+        let select = follow::Entity::find();
+        let r = match sort_by {
+            FollowSortBy::CreatedAt(Sort::Desc) => select.order_by_desc(follow::Column::CreatedAt),
+            FollowSortBy::CreatedAt(Sort::Asc) => select.order_by_asc(follow::Column::CreatedAt),
+        }
+        .filter(follow::Column::FollowerId.eq(user_id))
+        .filter(unblocked(
+            user_id,
+            &format!("{}.followee_id", follow::Entity.as_str()),
+            false,
+        ))
+        .paginate(self.database.postgresql(), per_page)
+        .fetch_page(page - 1)
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "FollowRepository::get_follower_ids", skip_all)]
+    async fn get_follower_ids(&self, user_id: Uuid) -> crate::Result<Vec<Uuid>> {
+        let _timer = self.metrics.query("FollowRepository::get_follower_ids");
+
+        let r = follow::Entity::find()
+            .filter(follow::Column::FolloweeId.eq(user_id))
+            .all(self.database.postgresql())
+            .await?;
+
+        Ok(r.into_iter().map(|x| x.follower_id).collect())
+    }
+
+    #[tracing::instrument(name = "FollowRepository::add", skip_all)]
+    async fn add(&self, follow: Follow) -> crate::Result<bool> {
+        let _timer = self.metrics.query("FollowRepository::add");
+
+        let r = follow::Entity::insert::<follow::ActiveModel>(follow.into())
+            .exec(self.database.postgresql())
+            .await;
+
+        match r {
+            Ok(_) => Ok(true),
+            Err(err) => match err {
+                DbErr::Query(err) if err.contains(postgresql::DUPLICATE_KEY_VALUE) => Ok(false),
+                err => Err(err.into()),
+            },
+        }
+    }
+
+    #[tracing::instrument(name = "FollowRepository::remove", skip_all)]
+    async fn remove(&self, follower_id: Uuid, followee_id: Uuid) -> crate::Result<bool> {
+        let _timer = self.metrics.query("FollowRepository::remove");
+
+        let r = follow::Entity::delete_by_id(follow::ActiveModel::id(follower_id, followee_id))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use crate::{
+        entity::{Block, BlockKind, Follow, FollowSortBy},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{BlockRepository, FollowRepository},
+        },
+    };
+
+    #[tokio::test]
+    async fn followers_and_following() {
+        let context = context().await;
+        let repository = &context.follow;
+
+        let a = context.add_user().await;
+        let b = context.add_user().await;
+        let c = context.add_user().await;
+
+        assert!(repository.add(Follow::new(b, a)).await.unwrap());
+        assert!(repository.add(Follow::new(c, a)).await.unwrap());
+        assert!(repository.add(Follow::new(a, b)).await.unwrap());
+        // duplicated
+        assert!(!repository.add(Follow::new(b, a)).await.unwrap());
+
+        let mut follower_ids = repository.get_follower_ids(a).await.unwrap();
+        follower_ids.sort();
+        let mut expected = vec![b, c];
+        expected.sort();
+        assert_eq!(follower_ids, expected);
+
+        let followers = repository
+            .get_followers(a, 1, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(followers.len(), 1);
+
+        let following = repository
+            .get_following(a, 25, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(following.len(), 1);
+        assert_eq!(following[0].followee_id, b);
+
+        assert!(repository.remove(b, a).await.unwrap());
+        assert!(!repository.remove(b, a).await.unwrap());
+        assert_eq!(repository.get_follower_ids(a).await.unwrap(), vec![c]);
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn exclude_blocked() {
+        let context = context().await;
+        let repository = &context.follow;
+
+        let a = context.add_user().await;
+        let blocked = context.add_user().await;
+        let blocking = context.add_user().await;
+        let muting = context.add_user().await;
+
+        for follower_id in [blocked, blocking, muting] {
+            assert!(repository.add(Follow::new(follower_id, a)).await.unwrap());
+        }
+        assert!(repository.add(Follow::new(a, blocking)).await.unwrap());
+
+        let blocks = [
+            Block::new(a, blocked, BlockKind::Block),
+            Block::new(blocking, a, BlockKind::Block),
+            Block::new(muting, a, BlockKind::Mute),
+        ];
+
+        for block in blocks {
+            assert!(context.block.add(block).await.unwrap());
+        }
+
+        // 차단 관계인 사용자가 앞 페이지를 차지하지 않음
+        let followers = repository
+            .get_followers(a, 1, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            followers
+                .into_iter()
+                .map(|x| x.follower_id)
+                .collect::<Vec<_>>(),
+            vec![muting]
+        );
+
+        let following = repository
+            .get_following(a, 25, 1, FollowSortBy::default())
+            .await
+            .unwrap();
+        assert!(following.is_empty());
+
+        context.teardown().await;
+    }
+}
@@ -1,15 +1,19 @@
 use itertools::Itertools;
 use sai::{Component, ComponentLifecycle, Injected};
 use sea_orm::{
-    ColumnTrait, Condition, ConnectionTrait, EntityTrait, IdenStatic, PaginatorTrait, QueryFilter,
-    QueryOrder, Statement,
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, FromQueryResult, IdenStatic,
+    PaginatorTrait, QueryFilter, QueryOrder, Statement,
 };
 use util::sea_orm::OrderByRandom;
 use uuid::Uuid;
 
 use crate::{
-    database::{postgresql::entity::history, DatabaseSet},
-    entity::{History, HistoryKind, HistorySortBy, Sort},
+    database::{
+        postgresql::entity::{book_title, history, like},
+        DatabaseSet,
+    },
+    entity::{History, HistoryFilter, HistoryKind, HistorySortBy, Sort},
+    metrics::Metrics,
     repository::r#trait::{HistoryBy, HistoryRepository},
 };
 
@@ -18,6 +22,9 @@ use crate::{
 pub struct PostgresqlHistoryRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -27,20 +34,139 @@ impl ComponentLifecycle for PostgresqlHistoryRepository {
     }
 }
 
+impl PostgresqlHistoryRepository {
+    async fn get_many_by_book_metadata(
+        &self,
+        user_id: Uuid,
+        filter: HistoryFilter,
+        per_page: usize,
+        page: usize,
+        sort_by: HistorySortBy,
+    ) -> crate::Result<Vec<History>> {
+        let by_popularity = matches!(sort_by, HistorySortBy::Popularity(_));
+
+        let sort_by = match sort_by {
+            HistorySortBy::Title(sort) => format!("b.title {} NULLS LAST", sort.as_sql()),
+            HistorySortBy::PublishedAt(sort) => {
+                format!("b.published_at {} NULLS LAST", sort.as_sql())
+            }
+            HistorySortBy::Popularity(sort) => format!("popularity {} NULLS LAST", sort.as_sql()),
+            _ => unreachable!("not sort by book"),
+        };
+
+        let mut values: Vec<sea_orm::Value> = vec![user_id.into()];
+        let mut preds = vec!["h.user_id = $1".to_string()];
+
+        if let Some(created_after) = filter.created_after {
+            values.push(created_after.into());
+            preds.push(format!("h.created_at > ${}", values.len()));
+        }
+        if let Some(created_before) = filter.created_before {
+            values.push(created_before.into());
+            preds.push(format!("h.created_at < ${}", values.len()));
+        }
+        if let Some(updated_after) = filter.updated_after {
+            values.push(updated_after.into());
+            preds.push(format!("h.updated_at > ${}", values.len()));
+        }
+
+        // 인기순일 때만 사용자가 읽은 작품들의 좋아요 수를 한 번에 셈
+        let (popularity, popularity_join) = match by_popularity {
+            true => (
+                "COALESCE(p.count, 0)".to_string(),
+                format!(
+                    r#"
+                    LEFT JOIN (
+                        SELECT book_id, COUNT(*) AS count FROM {like_book_table}
+                            WHERE is_dislike = false
+                                AND book_id IN (SELECT book_id FROM {history_book_table} WHERE user_id = $1)
+                            GROUP BY book_id
+                    ) AS p ON p.book_id = h.book_id
+                    "#,
+                    like_book_table = like::book::Entity.as_str(),
+                    history_book_table = history::book::Entity.as_str(),
+                ),
+            ),
+            false => ("NULL::bigint".to_string(), String::new()),
+        };
+
+        let query = format!(
+            r#"
+            SELECT
+                h.*,
+                {popularity} AS popularity
+                FROM {history_book_table} AS h
+                    LEFT JOIN {book_title_table} AS b ON b.book_id = h.book_id
+                    {popularity_join}
+                WHERE {preds}
+                ORDER BY {sort_by}, h.updated_at DESC
+                LIMIT ${limit}
+                OFFSET ${offset}
+            "#,
+            history_book_table = history::book::Entity.as_str(),
+            book_title_table = book_title::Entity.as_str(),
+            preds = preds.join(" AND "),
+            limit = values.len() + 1,
+            offset = values.len() + 2,
+        );
+
+        values.push((per_page as i64).into());
+        values.push(((per_page * (page - 1)) as i64).into());
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        let histories = history::book::Model::find_by_statement(Statement::from_sql_and_values(
+            psql, &query, values,
+        ))
+        .all(db)
+        .await?;
+
+        Ok(histories.into_iter().map_into().collect())
+    }
+}
+
 #[async_trait::async_trait]
 impl HistoryRepository for PostgresqlHistoryRepository {
+    #[tracing::instrument(name = "HistoryRepository::get_many", skip_all)]
     async fn get_many(
         &self,
         user_id: Uuid,
         kind: Option<HistoryKind>,
+        filter: HistoryFilter,
         per_page: usize,
         page: usize,
         sort_by: HistorySortBy,
     ) -> crate::Result<Vec<History>> {
+        let _timer = self.metrics.query("HistoryRepository::get_many");
+
+        // 지금은 작품 기록뿐이므로 종류를 지정해도 결과가 같음
+        // TODO: if added other kind, fixme
         let histories = match kind {
-            Some(_) => todo!(),
-            // TODO: if added other kind, fixme
-            None => {
+            // 작품 정보로 정렬할 때는 저장해둔 작품 정보와 조인해야 함
+            Some(HistoryKind::Book) | None if sort_by.by_book() => {
+                self.get_many_by_book_metadata(user_id, filter, per_page, page, sort_by)
+                    .await?
+            }
+            Some(HistoryKind::Book) | None => {
+                let cond = Condition::all()
+                    .add(history::book::Column::UserId.eq(user_id))
+                    .add_option(
+                        filter
+                            .created_after
+                            .map(|x| history::book::Column::CreatedAt.gt(x)),
+                    )
+                    .add_option(
+                        filter
+                            .created_before
+                            .map(|x| history::book::Column::CreatedAt.lt(x)),
+                    )
+                    .add_option(
+                        filter
+                            .updated_after
+                            .map(|x| history::book::Column::UpdatedAt.gt(x)),
+                    );
+
                 let select = history::book::Entity::find();
                 let r = match sort_by {
                     HistorySortBy::CreatedAt(Sort::Desc) => {
@@ -56,8 +182,11 @@ impl HistoryRepository for PostgresqlHistoryRepository {
                         select.order_by_asc(history::book::Column::UpdatedAt)
                     }
                     HistorySortBy::Random => select.order_by_random(),
+                    HistorySortBy::Title(_)
+                    | HistorySortBy::PublishedAt(_)
+                    | HistorySortBy::Popularity(_) => unreachable!("sort by book"),
                 }
-                .filter(history::book::Column::UserId.eq(user_id))
+                .filter(cond)
                 .paginate(self.database.postgresql(), per_page)
                 .fetch_page(page - 1)
                 .await?;
@@ -69,18 +198,21 @@ impl HistoryRepository for PostgresqlHistoryRepository {
         Ok(histories)
     }
 
+    #[tracing::instrument(name = "HistoryRepository::add_or_update", skip_all)]
     async fn add_or_update(&self, history: History) -> crate::Result<()> {
+        let _timer = self.metrics.query("HistoryRepository::add_or_update");
+
         match history.kind() {
             HistoryKind::Book => {
                 let query = format!(
                     r#"
                     INSERT INTO
-                        {table_name}(id, book_id, user_id, created_at, updated_at)
+                        {table_name}(id, book_id, user_id, page, created_at, updated_at)
                     VALUES
-                        ($1, $2, $3, $4, $4)
+                        ($1, $2, $3, $4, $5, $5)
                     ON CONFLICT (id)
                         DO UPDATE
-                            SET updated_at = $4
+                            SET page = $4, updated_at = $5
                 "#,
                     table_name = history::book::Entity.as_str()
                 );
@@ -93,6 +225,7 @@ impl HistoryRepository for PostgresqlHistoryRepository {
                     id: history_id,
                     book_id,
                     user_id,
+                    page,
                     created_at: now,
                     ..
                 } = history.into();
@@ -105,6 +238,7 @@ impl HistoryRepository for PostgresqlHistoryRepository {
                             history_id.into_value().unwrap(),
                             book_id.into_value().unwrap(),
                             user_id.into_value().unwrap(),
+                            page.into_value().unwrap(),
                             now.into_value().unwrap(),
                         ],
                     ))
@@ -115,7 +249,10 @@ impl HistoryRepository for PostgresqlHistoryRepository {
         }
     }
 
+    #[tracing::instrument(name = "HistoryRepository::get_many_by", skip_all)]
     async fn get_many_by(&self, user_id: Uuid, by: HistoryBy) -> crate::Result<Vec<History>> {
+        let _timer = self.metrics.query("HistoryRepository::get_many_by");
+
         // TODO: 나중에 kind가 생기면 그때 추가하면 됨
         match by {
             HistoryBy::Book { ids } => {
@@ -134,6 +271,24 @@ impl HistoryRepository for PostgresqlHistoryRepository {
         }
     }
 
+    #[tracing::instrument(name = "HistoryRepository::get_reader_ids", skip_all)]
+    async fn get_reader_ids(&self, book_ids: Vec<u32>) -> crate::Result<Vec<Uuid>> {
+        let _timer = self.metrics.query("HistoryRepository::get_reader_ids");
+
+        if book_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let book_ids = book_ids.into_iter().map(|x| x as i32).collect::<Vec<_>>();
+
+        let histories = history::book::Entity::find()
+            .filter(history::book::Column::BookId.is_in(book_ids))
+            .all(self.database.postgresql())
+            .await?;
+
+        Ok(histories.into_iter().map(|x| x.user_id).unique().collect())
+    }
+
     /* async fn update(&self, history: History) -> crate::Result<Option<History>> {
         let r = history::book::Entity::update::<history::book::ActiveModel>(history.into())
             .exec(self.database.postgresql())
@@ -150,7 +305,10 @@ impl HistoryRepository for PostgresqlHistoryRepository {
         }
     } */
 
+    #[tracing::instrument(name = "HistoryRepository::remove", skip_all)]
     async fn remove(&self, history: History) -> crate::Result<bool> {
+        let _timer = self.metrics.query("HistoryRepository::remove");
+
         let r = history::book::Entity::delete::<history::book::ActiveModel>(history.into())
             .exec(self.database.postgresql())
             .await?;
@@ -158,3 +316,151 @@ impl HistoryRepository for PostgresqlHistoryRepository {
         Ok(r.rows_affected > 0)
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        entity::{History, HistoryFilter, HistoryKind, HistorySortBy, Sort},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{HistoryBy, HistoryRepository},
+        },
+    };
+
+    fn book_ids(histories: &[History]) -> Vec<u32> {
+        histories
+            .iter()
+            .map(|History::Book { book_id, .. }| *book_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn add_or_update() {
+        let context = context().await;
+        let repository = &context.history;
+        let user_id = context.add_user().await;
+
+        repository
+            .add_or_update(History::book(1, 3, user_id))
+            .await
+            .unwrap();
+        repository
+            .add_or_update(History::book(1, 7, user_id))
+            .await
+            .unwrap();
+
+        let r = repository
+            .get_many_by(user_id, HistoryBy::Book { ids: vec![1, 2] })
+            .await
+            .unwrap();
+
+        assert_eq!(r.len(), 1);
+        assert!(matches!(r[0], History::Book { page: 7, .. }));
+
+        assert!(repository
+            .remove(History::book(1, 7, user_id))
+            .await
+            .unwrap());
+        assert!(!repository
+            .remove(History::book(1, 7, user_id))
+            .await
+            .unwrap());
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let context = context().await;
+        let repository = &context.history;
+        let (user_id, another_user_id) = (context.add_user().await, context.add_user().await);
+        let now = Utc::now();
+
+        for book_id in 0..10 {
+            let at = now - Duration::minutes(book_id as i64);
+
+            repository
+                .add_or_update(History::Book {
+                    book_id,
+                    page: 1,
+                    user_id,
+                    created_at: at,
+                    updated_at: at,
+                })
+                .await
+                .unwrap();
+        }
+        repository
+            .add_or_update(History::book(0, 1, another_user_id))
+            .await
+            .unwrap();
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                HistoryFilter::default(),
+                3,
+                2,
+                HistorySortBy::UpdatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![3, 4, 5]);
+
+        let filter = HistoryFilter {
+            created_after: Some(now - Duration::minutes(5) - Duration::seconds(1)),
+            ..Default::default()
+        };
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                filter,
+                3,
+                2,
+                HistorySortBy::UpdatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![2, 1, 0]);
+
+        // 작품 정보가 없으면 NULLS LAST라서 updated_at 내림차순이 됨
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                HistoryFilter::default(),
+                3,
+                1,
+                HistorySortBy::Title(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![0, 1, 2]);
+
+        // 작품 기록만 있으므로 종류를 지정하지 않았을 때와 같음
+        let r = repository
+            .get_many(
+                user_id,
+                Some(HistoryKind::Book),
+                HistoryFilter::default(),
+                3,
+                2,
+                HistorySortBy::UpdatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![3, 4, 5]);
+
+        let mut r = repository.get_reader_ids(vec![0]).await.unwrap();
+        r.sort();
+        let mut expected = vec![user_id, another_user_id];
+        expected.sort();
+        assert_eq!(r, expected);
+
+        context.teardown().await;
+    }
+}
@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use sai::{Component, ComponentLifecycle, Injected};
 use sea_orm::{
     ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, IdenStatic,
@@ -8,8 +9,12 @@ use uuid::Uuid;
 
 use crate::{
     constant::postgresql,
-    database::{postgresql::entity::like, DatabaseSet},
-    entity::{like::LikeSortBy, Like, LikeKind, Sort},
+    database::{
+        postgresql::entity::{book_title, like},
+        DatabaseSet,
+    },
+    entity::{like::LikeSortBy, Like, LikeFilter, LikeKind, Sort},
+    metrics::Metrics,
     repository::r#trait::{LikeBy, LikeRepository},
 };
 
@@ -18,6 +23,9 @@ use crate::{
 pub struct PostgresqlLikeRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -28,18 +36,215 @@ impl ComponentLifecycle for PostgresqlLikeRepository {
     }
 }
 
+impl PostgresqlLikeRepository {
+    /// 작품 좋아요와 작품 태그 좋아요를 합쳐서 가져옴
+    ///
+    /// 작품 정보로 정렬할 때도 씀
+    async fn get_many_union(
+        &self,
+        user_id: Uuid,
+        kind: Option<LikeKind>,
+        filter: LikeFilter,
+        per_page: usize,
+        page: usize,
+        sort_by: LikeSortBy,
+    ) -> crate::Result<Vec<Like>> {
+        let by_popularity = matches!(sort_by, LikeSortBy::Popularity(_));
+
+        let sort_by = match sort_by {
+            LikeSortBy::CreatedAt(sort) => format!("created_at {}", sort.as_sql()),
+            LikeSortBy::Title(sort) => {
+                format!("sort_title {} NULLS LAST, created_at DESC", sort.as_sql())
+            }
+            LikeSortBy::PublishedAt(sort) => format!(
+                "sort_published_at {} NULLS LAST, created_at DESC",
+                sort.as_sql()
+            ),
+            LikeSortBy::Popularity(sort) => format!(
+                "sort_popularity {} NULLS LAST, created_at DESC",
+                sort.as_sql()
+            ),
+            LikeSortBy::Random => "RANDOM() DESC".to_string(),
+        };
+
+        // 태그 조건이 있으면 작품 좋아요는 제외함
+        let include_book = kind != Some(LikeKind::BookTag) && !filter.has_tag();
+        let include_book_tag = kind != Some(LikeKind::Book);
+
+        // 두 테이블에 같은 조건을 쓰기 때문에 placeholder도 같이 씀
+        let mut values: Vec<sea_orm::Value> = vec![user_id.into()];
+        let mut preds = vec![
+            "l.user_id = $1".to_string(),
+            "l.is_dislike = false".to_string(),
+        ];
+        let mut tag_preds = Vec::new();
+
+        if let Some(created_after) = filter.created_after {
+            values.push(created_after.into());
+            preds.push(format!("l.created_at > ${}", values.len()));
+        }
+        if let Some(created_before) = filter.created_before {
+            values.push(created_before.into());
+            preds.push(format!("l.created_at < ${}", values.len()));
+        }
+        if let Some(tag_kind) = filter.tag_kind {
+            values.push(tag_kind.into());
+            tag_preds.push(format!("l.tag_kind = ${}", values.len()));
+        }
+        if let Some(tag_name) = filter.tag_name {
+            values.push(tag_name.into());
+            tag_preds.push(format!("l.tag_name = ${}", values.len()));
+        }
+
+        let mut selects = Vec::new();
+
+        if include_book {
+            // 인기순일 때만 사용자가 좋아한 작품들의 좋아요 수를 한 번에 셈
+            let (popularity, popularity_join) = match by_popularity {
+                true => (
+                    "COALESCE(p.count, 0)".to_string(),
+                    format!(
+                        r#"
+                        LEFT JOIN (
+                            SELECT book_id, COUNT(*) AS count FROM {like_book_table}
+                                WHERE is_dislike = false
+                                    AND book_id IN (SELECT book_id FROM {like_book_table} WHERE user_id = $1)
+                                GROUP BY book_id
+                        ) AS p ON p.book_id = l.book_id
+                        "#,
+                        like_book_table = like::book::Entity.as_str(),
+                    ),
+                ),
+                false => ("NULL::bigint".to_string(), String::new()),
+            };
+
+            selects.push(format!(
+                r#"
+                SELECT
+                    l.id, l.user_id, l.book_id, NULL AS tag_kind, NULL AS tag_name, l.is_dislike, l.created_at,
+                    b.title AS sort_title,
+                    b.published_at AS sort_published_at,
+                    {popularity} AS sort_popularity
+                    FROM {like_book_table} AS l
+                        LEFT JOIN {book_title_table} AS b ON b.book_id = l.book_id
+                        {popularity_join}
+                    WHERE {preds}
+                "#,
+                like_book_table = like::book::Entity.as_str(),
+                book_title_table = book_title::Entity.as_str(),
+                preds = preds.join(" AND "),
+            ));
+        }
+
+        if include_book_tag {
+            selects.push(format!(
+                r#"
+                SELECT
+                    l.id, l.user_id, NULL::integer AS book_id, l.tag_kind, l.tag_name, l.is_dislike, l.created_at,
+                    l.tag_name AS sort_title,
+                    NULL::timestamptz AS sort_published_at,
+                    NULL::bigint AS sort_popularity
+                    FROM {like_book_tag_table} AS l
+                    WHERE {preds}
+                "#,
+                like_book_tag_table = like::book_tag::Entity.as_str(),
+                preds = preds.iter().chain(tag_preds.iter()).join(" AND "),
+            ));
+        }
+
+        let query = format!(
+            r#"
+            SELECT * FROM
+            ({selects}) AS a
+            ORDER BY {sort_by}
+            LIMIT ${limit}
+            OFFSET ${offset}
+            "#,
+            selects = selects.join("UNION ALL"),
+            limit = values.len() + 1,
+            offset = values.len() + 2,
+        );
+
+        values.push((per_page as i64).into());
+        values.push(((per_page * (page - 1)) as i64).into());
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        let query_results = db
+            .query_all(Statement::from_sql_and_values(psql, &query, values))
+            .await?;
+
+        let likes = query_results
+            .iter()
+            .map(|x| {
+                // partitioning by LikeKind
+                let like_kind = {
+                    // TODO: if another kind added, change kind partitioning logic
+                    let r = Option::<i32>::try_get(x, "", like::book::Column::BookId.as_str())
+                        .map_err(DbErr::from)?;
+
+                    log::debug!("{r:?}");
+
+                    match r {
+                        Some(_) => LikeKind::Book,
+                        None => LikeKind::BookTag,
+                    }
+                };
+
+                // into Like
+                match like_kind {
+                    LikeKind::Book => {
+                        let model = like::book::Model::from_query_result(x, "")?;
+
+                        Ok(model.into_like())
+                    }
+                    LikeKind::BookTag => {
+                        let model = like::book_tag::Model::from_query_result(x, "")?;
+
+                        Ok(model.into_like())
+                    }
+                }
+            })
+            .collect::<Result<_, DbErr>>()?;
+
+        Ok(likes)
+    }
+}
+
 #[async_trait::async_trait]
 impl LikeRepository for PostgresqlLikeRepository {
+    #[tracing::instrument(name = "LikeRepository::get_many", skip_all)]
     async fn get_many(
         &self,
         user_id: Uuid,
         kind: Option<LikeKind>,
+        filter: LikeFilter,
         per_page: usize,
         page: usize,
         sort_by: LikeSortBy,
     ) -> crate::Result<Vec<Like>> {
+        let _timer = self.metrics.query("LikeRepository::get_many");
+
         let likes = match kind {
-            Some(LikeKind::Book) => {
+            // 작품 좋아요에는 태그가 없음
+            Some(LikeKind::Book) if filter.has_tag() => Vec::new(),
+
+            Some(LikeKind::Book) if !sort_by.by_book() => {
+                let cond = Condition::all()
+                    .add(like::book::Column::UserId.eq(user_id))
+                    .add(like::book::Column::IsDislike.eq(false))
+                    .add_option(
+                        filter
+                            .created_after
+                            .map(|x| like::book::Column::CreatedAt.gt(x)),
+                    )
+                    .add_option(
+                        filter
+                            .created_before
+                            .map(|x| like::book::Column::CreatedAt.lt(x)),
+                    );
+
                 let select = like::book::Entity::find();
                 let r = match sort_by {
                     LikeSortBy::Random => select.order_by_random(),
@@ -49,12 +254,11 @@ impl LikeRepository for PostgresqlLikeRepository {
                     LikeSortBy::CreatedAt(Sort::Asc) => {
                         select.order_by_asc(like::book::Column::CreatedAt)
                     }
+                    LikeSortBy::Title(_)
+                    | LikeSortBy::PublishedAt(_)
+                    | LikeSortBy::Popularity(_) => unreachable!("sort by book"),
                 }
-                .filter(
-                    like::book::Column::UserId
-                        .eq(user_id)
-                        .and(like::book::Column::IsDislike.eq(false)),
-                )
+                .filter(cond)
                 /* .query()
                 .per_page(per_page * (page - 1))
                 .limit(per_page) */
@@ -65,7 +269,31 @@ impl LikeRepository for PostgresqlLikeRepository {
                 r.into_iter().map(like::book::Model::into_like).collect()
             }
 
-            Some(LikeKind::BookTag) => {
+            Some(LikeKind::BookTag) if !sort_by.by_book() => {
+                let cond = Condition::all()
+                    .add(like::book_tag::Column::UserId.eq(user_id))
+                    .add(like::book_tag::Column::IsDislike.eq(false))
+                    .add_option(
+                        filter
+                            .created_after
+                            .map(|x| like::book_tag::Column::CreatedAt.gt(x)),
+                    )
+                    .add_option(
+                        filter
+                            .created_before
+                            .map(|x| like::book_tag::Column::CreatedAt.lt(x)),
+                    )
+                    .add_option(
+                        filter
+                            .tag_kind
+                            .map(|x| like::book_tag::Column::TagKind.eq(x)),
+                    )
+                    .add_option(
+                        filter
+                            .tag_name
+                            .map(|x| like::book_tag::Column::TagName.eq(x)),
+                    );
+
                 let select = like::book_tag::Entity::find();
                 let r = match sort_by {
                     LikeSortBy::Random => select.order_by_random(),
@@ -75,13 +303,12 @@ impl LikeRepository for PostgresqlLikeRepository {
                     LikeSortBy::CreatedAt(Sort::Asc) => {
                         select.order_by_asc(like::book_tag::Column::CreatedAt)
                     }
+                    LikeSortBy::Title(_)
+                    | LikeSortBy::PublishedAt(_)
+                    | LikeSortBy::Popularity(_) => unreachable!("sort by book"),
                 }
                 .order_by_desc(like::book_tag::Column::CreatedAt)
-                .filter(
-                    like::book_tag::Column::UserId
-                        .eq(user_id)
-                        .and(like::book_tag::Column::IsDislike.eq(false)),
-                )
+                .filter(cond)
                 .paginate(self.database.postgresql(), per_page)
                 .fetch_page(page - 1)
                 .await?;
@@ -91,88 +318,20 @@ impl LikeRepository for PostgresqlLikeRepository {
                     .collect()
             }
 
-            None => {
-                let sort_by = match sort_by {
-                    LikeSortBy::CreatedAt(Sort::Desc) => "created_at DESC",
-                    LikeSortBy::CreatedAt(Sort::Asc) => "created_at ASC",
-                    LikeSortBy::Random => "RANDOM() DESC",
-                };
-
-                let query = format!(
-                    r#"
-                    SELECT * FROM
-                    (
-                        SELECT id, user_id, book_id, NULL AS tag_kind, NULL AS tag_name, is_dislike, created_at
-                            FROM {like_book_table}
-                            WHERE user_id = $1 AND is_dislike = false
-                        UNION ALL
-                        SELECT id, user_id, NULL, tag_kind, tag_name, is_dislike, created_at
-                            FROM {like_book_tag_table}
-                            WHERE user_id = $1 AND is_dislike = false
-                    ) AS a
-                    ORDER BY {sort_by}
-                    LIMIT $2
-                    OFFSET $3
-                    "#,
-                    like_book_table = like::book::Entity.as_str(),
-                    like_book_tag_table = like::book_tag::Entity.as_str(),
-                );
-
-                let db = self.database.postgresql();
-                let psql = db.get_database_backend();
-
-                let query_results = db
-                    .query_all(Statement::from_sql_and_values(
-                        psql,
-                        &query,
-                        [
-                            user_id.into(),
-                            (per_page as i64).into(),
-                            ((per_page * (page - 1)) as i64).into(),
-                        ],
-                    ))
-                    .await?;
-
-                query_results
-                    .iter()
-                    .map(|x| {
-                        // partitioning by LikeKind
-                        let like_kind = {
-                            // TODO: if another kind added, change kind partitioning logic
-                            let r =
-                                Option::<i32>::try_get(x, "", like::book::Column::BookId.as_str())
-                                    .map_err(DbErr::from)?;
-
-                            log::debug!("{r:?}");
-
-                            match r {
-                                Some(_) => LikeKind::Book,
-                                None => LikeKind::BookTag,
-                            }
-                        };
-
-                        // into Like
-                        match like_kind {
-                            LikeKind::Book => {
-                                let model = like::book::Model::from_query_result(x, "")?;
-
-                                Ok(model.into_like())
-                            }
-                            LikeKind::BookTag => {
-                                let model = like::book_tag::Model::from_query_result(x, "")?;
-
-                                Ok(model.into_like())
-                            }
-                        }
-                    })
-                    .collect::<Result<_, DbErr>>()?
+            // 작품 정보로 정렬할 때는 저장해둔 작품 정보와 조인해야 함
+            kind => {
+                self.get_many_union(user_id, kind, filter, per_page, page, sort_by)
+                    .await?
             }
         };
 
         Ok(likes)
     }
 
+    #[tracing::instrument(name = "LikeRepository::get_many_by", skip_all)]
     async fn get_many_by(&self, user_id: Option<Uuid>, by: LikeBy) -> crate::Result<Vec<Like>> {
+        let _timer = self.metrics.query("LikeRepository::get_many_by");
+
         match by {
             LikeBy::Book { ids } => {
                 if ids.is_empty() {
@@ -266,7 +425,10 @@ impl LikeRepository for PostgresqlLikeRepository {
             .collect())
     } */
 
+    #[tracing::instrument(name = "LikeRepository::add", skip_all)]
     async fn add(&self, like: Like) -> crate::Result<bool> {
+        let _timer = self.metrics.query("LikeRepository::add");
+
         let r = match like.kind() {
             LikeKind::Book => {
                 let r = like::book::Entity::insert::<like::book::ActiveModel>(like.into())
@@ -294,7 +456,10 @@ impl LikeRepository for PostgresqlLikeRepository {
         }
     }
 
+    #[tracing::instrument(name = "LikeRepository::remove", skip_all)]
     async fn remove(&self, like: Like) -> crate::Result<bool> {
+        let _timer = self.metrics.query("LikeRepository::remove");
+
         let r = match like.kind() {
             LikeKind::Book => {
                 like::book::Entity::delete::<like::book::ActiveModel>(like.into())
@@ -315,3 +480,180 @@ impl LikeRepository for PostgresqlLikeRepository {
         }
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{like::LikeSortBy, Like, LikeFilter, LikeKind, Sort},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{LikeBy, LikeRepository},
+        },
+    };
+
+    /// 타임스탬프는 마이크로초까지만 저장되므로 식별할 수 있는 값으로 비교함
+    fn keys(likes: &[Like]) -> Vec<String> {
+        likes
+            .iter()
+            .map(|x| match x {
+                Like::Book { book_id, .. } => book_id.to_string(),
+                Like::BookTag { tag_name, .. } => tag_name.clone(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let context = context().await;
+        let repository = &context.like;
+        let (user_id, another_user_id) = (context.add_user().await, context.add_user().await);
+        let now = Utc::now();
+
+        let likes = (0..25)
+            .map(|x| Like::Book {
+                user_id,
+                book_id: x,
+                created_at: now - Duration::minutes(x as i64),
+            })
+            .chain((25..50).map(|x| Like::BookTag {
+                user_id,
+                tag_kind: "artist".to_string(),
+                tag_name: x.to_string(),
+                created_at: now - Duration::minutes(x as i64),
+            }))
+            .chain((0..10).map(|x| Like::book(another_user_id, x)));
+
+        for like in likes {
+            assert!(repository.add(like).await.unwrap());
+        }
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(LikeKind::Book),
+                LikeFilter::default(),
+                10,
+                2,
+                LikeSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        let expected = (10..20).map(|x| x.to_string()).collect::<Vec<_>>();
+        assert_eq!(keys(&r), expected);
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(LikeKind::BookTag),
+                LikeFilter::default(),
+                5,
+                1,
+                LikeSortBy::CreatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        let expected = (45..50).rev().map(|x| x.to_string()).collect::<Vec<_>>();
+        assert_eq!(keys(&r), expected);
+
+        // 종류가 섞여도 날짜순으로 이어짐
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                LikeFilter::default(),
+                10,
+                3,
+                LikeSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        let expected = (20..30).map(|x| x.to_string()).collect::<Vec<_>>();
+        assert_eq!(keys(&r), expected);
+
+        let filter = LikeFilter {
+            tag_name: Some("30".to_string()),
+            ..Default::default()
+        };
+        let r = repository
+            .get_many(user_id, None, filter, 25, 1, LikeSortBy::default())
+            .await
+            .unwrap();
+        assert_eq!(keys(&r), vec!["30".to_string()]);
+
+        let r = repository
+            .get_many_by(None, LikeBy::Book { ids: vec![1, 20] })
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 3);
+
+        let r = repository
+            .get_many_by(Some(another_user_id), LikeBy::Book { ids: vec![1, 20] })
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 1);
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn add_and_remove() {
+        let context = context().await;
+        let repository = &context.like;
+        let user_id = context.add_user().await;
+
+        assert!(repository.add(Like::book(user_id, 1)).await.unwrap());
+        assert!(!repository.add(Like::book(user_id, 1)).await.unwrap());
+
+        let tag = || Like::book_tag(user_id, "artist".to_string(), "madome".to_string());
+        assert!(repository.add(tag()).await.unwrap());
+        assert!(!repository.add(tag()).await.unwrap());
+
+        assert!(repository.remove(Like::book(user_id, 1)).await.unwrap());
+        assert!(!repository.remove(Like::book(user_id, 1)).await.unwrap());
+        assert!(repository.remove(tag()).await.unwrap());
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn random_and_empty_user() {
+        let context = context().await;
+        let repository = &context.like;
+        let user_id = context.add_user().await;
+
+        for book_id in 1..=5 {
+            repository.add(Like::book(user_id, book_id)).await.unwrap();
+        }
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                LikeFilter::default(),
+                25,
+                1,
+                LikeSortBy::Random,
+            )
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 5);
+
+        let r = repository
+            .get_many(
+                Uuid::new_v4(),
+                None,
+                LikeFilter::default(),
+                25,
+                1,
+                LikeSortBy::default(),
+            )
+            .await
+            .unwrap();
+        assert!(r.is_empty());
+
+        context.teardown().await;
+    }
+}
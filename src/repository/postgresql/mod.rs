@@ -1,13 +1,265 @@
+mod block;
+mod collection;
 mod dislike;
 mod fcm_token;
+mod follow;
 mod history;
 mod like;
 mod notification;
+mod profile;
+mod rating;
+mod related_book;
+mod search;
 mod user;
 
+pub use block::PostgresqlBlockRepository;
+pub use collection::PostgresqlCollectionRepository;
 pub use dislike::PostgresqlDislikeRepository;
 pub use fcm_token::PostgresqlFcmTokenRepository;
+pub use follow::PostgresqlFollowRepository;
 pub use history::PostgresqlHistoryRepository;
 pub use like::PostgresqlLikeRepository;
 pub use notification::PostgresqlNotificationRepository;
+pub use profile::PostgresqlProfileRepository;
+pub use rating::PostgresqlRatingRepository;
+pub use related_book::PostgresqlRelatedBookRepository;
+pub use search::PostgresqlSearchRepository;
 pub use user::PostgresqlUserRepository;
+
+/// 운영에서 쓰는 저장소
+pub struct PostgresqlBackend;
+
+impl super::Backend for PostgresqlBackend {
+    type Database = crate::database::DatabaseSet;
+    type User = PostgresqlUserRepository;
+    type Like = PostgresqlLikeRepository;
+    type Dislike = PostgresqlDislikeRepository;
+    type Notification = PostgresqlNotificationRepository;
+    type FcmToken = PostgresqlFcmTokenRepository;
+    type History = PostgresqlHistoryRepository;
+    type Collection = PostgresqlCollectionRepository;
+    type Profile = PostgresqlProfileRepository;
+    type Rating = PostgresqlRatingRepository;
+    type Follow = PostgresqlFollowRepository;
+    type Block = PostgresqlBlockRepository;
+    type RelatedBook = PostgresqlRelatedBookRepository;
+    type Search = PostgresqlSearchRepository;
+}
+
+/// `cargo test --features postgres-test`
+///
+/// 테스트마다 스키마를 따로 만들어서 병렬로 실행해도 서로 영향을 주지 않음
+#[cfg(all(test, feature = "postgres-test"))]
+pub(super) mod tests {
+    use std::sync::{Arc, OnceLock};
+
+    use parking_lot::Mutex;
+    use sai::{
+        combine_component_registry, component_registry, Component, ComponentLifecycle, Injected,
+        System,
+    };
+    use sea_orm::{ConnectionTrait, Statement};
+    use uuid::Uuid;
+
+    use crate::{
+        config::Config,
+        database::DatabaseSet,
+        entity::{User, UserRole},
+        metrics::Metrics,
+        registry::tests::set_env,
+        repository::r#trait::UserRepository,
+    };
+
+    use super::{
+        PostgresqlBlockRepository, PostgresqlCollectionRepository, PostgresqlDislikeRepository,
+        PostgresqlFcmTokenRepository, PostgresqlFollowRepository, PostgresqlHistoryRepository,
+        PostgresqlLikeRepository, PostgresqlNotificationRepository, PostgresqlProfileRepository,
+        PostgresqlRatingRepository, PostgresqlRelatedBookRepository, PostgresqlSearchRepository,
+        PostgresqlUserRepository,
+    };
+
+    combine_component_registry!(PostgresqlRegistry, [RepositoryRegistry, ConfigRegistry]);
+
+    component_registry!(
+        RepositoryRegistry,
+        [
+            Repositories,
+            DatabaseSet,
+            Metrics,
+            PostgresqlUserRepository,
+            PostgresqlLikeRepository,
+            PostgresqlDislikeRepository,
+            PostgresqlNotificationRepository,
+            PostgresqlFcmTokenRepository,
+            PostgresqlHistoryRepository,
+            PostgresqlCollectionRepository,
+            PostgresqlProfileRepository,
+            PostgresqlRatingRepository,
+            PostgresqlRelatedBookRepository,
+            PostgresqlFollowRepository,
+            PostgresqlBlockRepository,
+            PostgresqlSearchRepository
+        ]
+    );
+
+    component_registry!(ConfigRegistry, [Config]);
+
+    /// System 밖으로 저장소를 꺼내기 위한 컴포넌트
+    #[derive(Component)]
+    #[lifecycle]
+    struct Repositories {
+        #[injected]
+        database: Injected<DatabaseSet>,
+
+        #[injected]
+        user: Injected<PostgresqlUserRepository>,
+
+        #[injected]
+        like: Injected<PostgresqlLikeRepository>,
+
+        #[injected]
+        dislike: Injected<PostgresqlDislikeRepository>,
+
+        #[injected]
+        notification: Injected<PostgresqlNotificationRepository>,
+
+        #[injected]
+        fcm_token: Injected<PostgresqlFcmTokenRepository>,
+
+        #[injected]
+        history: Injected<PostgresqlHistoryRepository>,
+
+        #[injected]
+        collection: Injected<PostgresqlCollectionRepository>,
+
+        #[injected]
+        profile: Injected<PostgresqlProfileRepository>,
+
+        #[injected]
+        rating: Injected<PostgresqlRatingRepository>,
+
+        #[injected]
+        related_book: Injected<PostgresqlRelatedBookRepository>,
+
+        #[injected]
+        follow: Injected<PostgresqlFollowRepository>,
+
+        #[injected]
+        block: Injected<PostgresqlBlockRepository>,
+
+        #[injected]
+        search: Injected<PostgresqlSearchRepository>,
+    }
+
+    /// 지금 띄우고 있는 System의 저장소
+    static STARTED: Mutex<Option<Context>> = parking_lot::const_mutex(None);
+
+    /// Config가 환경 변수를 읽는 동안 다른 테스트가 스키마를 바꾸지 못하게 함
+    fn starting() -> &'static tokio::sync::Mutex<()> {
+        static STARTING: OnceLock<tokio::sync::Mutex<()>> = OnceLock::new();
+
+        STARTING.get_or_init(Default::default)
+    }
+
+    #[async_trait::async_trait]
+    impl ComponentLifecycle for Repositories {
+        async fn start(&mut self) {
+            // HttpServer와 마찬가지로 저장소들이 테이블을 만든 뒤에 마이그레이션함
+            migration::up(self.database.postgresql())
+                .await
+                .expect("failed to migration");
+
+            STARTED.lock().replace(Context {
+                schema: String::new(),
+                system: None,
+                database: Arc::clone(&self.database),
+                user: Arc::clone(&self.user),
+                like: Arc::clone(&self.like),
+                dislike: Arc::clone(&self.dislike),
+                notification: Arc::clone(&self.notification),
+                fcm_token: Arc::clone(&self.fcm_token),
+                history: Arc::clone(&self.history),
+                collection: Arc::clone(&self.collection),
+                profile: Arc::clone(&self.profile),
+                rating: Arc::clone(&self.rating),
+                related_book: Arc::clone(&self.related_book),
+                follow: Arc::clone(&self.follow),
+                block: Arc::clone(&self.block),
+                search: Arc::clone(&self.search),
+            });
+        }
+    }
+
+    pub struct Context {
+        schema: String,
+        system: Option<System<PostgresqlRegistry>>,
+        database: Arc<DatabaseSet>,
+        pub user: Arc<PostgresqlUserRepository>,
+        pub like: Arc<PostgresqlLikeRepository>,
+        pub dislike: Arc<PostgresqlDislikeRepository>,
+        pub notification: Arc<PostgresqlNotificationRepository>,
+        pub fcm_token: Arc<PostgresqlFcmTokenRepository>,
+        pub history: Arc<PostgresqlHistoryRepository>,
+        pub collection: Arc<PostgresqlCollectionRepository>,
+        pub profile: Arc<PostgresqlProfileRepository>,
+        pub rating: Arc<PostgresqlRatingRepository>,
+        pub related_book: Arc<PostgresqlRelatedBookRepository>,
+        pub follow: Arc<PostgresqlFollowRepository>,
+        pub block: Arc<PostgresqlBlockRepository>,
+        pub search: Arc<PostgresqlSearchRepository>,
+    }
+
+    /// 새 스키마에 마이그레이션까지 끝난 저장소들
+    pub async fn context() -> Context {
+        let _guard = starting().lock().await;
+
+        let schema = format!("test_{}", Uuid::new_v4().to_simple());
+
+        set_env();
+        std::env::set_var("POSTGRES_SCHEMA", &schema);
+
+        let mut system = System::<PostgresqlRegistry>::new();
+
+        system.start().await;
+
+        let context = STARTED.lock().take().expect("started repositories");
+
+        Context {
+            schema,
+            system: Some(system),
+            ..context
+        }
+    }
+
+    impl Context {
+        /// 대부분의 테이블이 users를 참조하므로 사용자부터 만들어야 함
+        pub async fn add_user(&self) -> Uuid {
+            let user = User::new(
+                "madome".to_string(),
+                format!("{}@madome.app", Uuid::new_v4()),
+                UserRole::Normal,
+            );
+            let user_id = user.id;
+
+            self.user.add(user).await.unwrap().expect("add user");
+
+            user_id
+        }
+
+        /// 테스트가 실패하면 스키마가 남으므로 `DROP SCHEMA test_* CASCADE`로 직접 지워야 함
+        pub async fn teardown(mut self) {
+            let db = self.database.postgresql();
+
+            let stmt = Statement::from_string(
+                db.get_database_backend(),
+                format!("DROP SCHEMA \"{}\" CASCADE", self.schema),
+            );
+
+            db.execute(stmt).await.expect("drop postgresql schema");
+
+            if let Some(mut system) = self.system.take() {
+                system.stop().await;
+            }
+        }
+    }
+}
@@ -1,13 +1,15 @@
+use itertools::Itertools;
 use sai::{Component, ComponentLifecycle, Injected};
 use sea_orm::{
-    prelude::DateTimeUtc, ConnectionTrait, DbErr, EntityName, IdenStatic, Statement,
-    TransactionTrait,
+    prelude::DateTimeUtc, ColumnTrait, ConnectionTrait, DbErr, EntityName, EntityTrait, IdenStatic,
+    QueryFilter, QueryOrder, QuerySelect, Statement, TransactionTrait,
 };
 use uuid::Uuid;
 
 use crate::{
     database::{postgresql::entity::notification, DatabaseSet},
     entity::{Notification, NotificationKind, NotificationSortBy, Sort},
+    metrics::Metrics,
     repository::r#trait::NotificationRepository,
 };
 
@@ -16,6 +18,9 @@ use crate::{
 pub struct PostgresqlNotificationRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -23,25 +28,62 @@ impl ComponentLifecycle for PostgresqlNotificationRepository {
     async fn start(&mut self) {
         notification::book::create_table(self.database.postgresql()).await;
         notification::book::tag::create_table(self.database.postgresql()).await;
+        notification::book_series::create_table(self.database.postgresql()).await;
+        notification::user::create_table(self.database.postgresql()).await;
     }
 }
 
-#[async_trait::async_trait]
-impl NotificationRepository for PostgresqlNotificationRepository {
-    async fn get_many(
+impl PostgresqlNotificationRepository {
+    /// 이미 있는 알림은 무시함
+    ///
+    /// TODO: sea_orm에서 `ON CONFLICT DO NOTHING`을 지원하면 insert_many로 바꾸기
+    async fn insert_many(
+        &self,
+        table_name: &str,
+        columns: &[&str],
+        rows: Vec<Vec<sea_orm::Value>>,
+    ) -> crate::Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let values_query = (0..rows.len())
+            .map(|i| {
+                let placeholders = (1..=columns.len())
+                    .map(|j| format!("${}", i * columns.len() + j))
+                    .join(", ");
+
+                format!("({placeholders})")
+            })
+            .join(",\n");
+
+        let query = format!(
+            "INSERT INTO {} ({}) VALUES {} ON CONFLICT (id) DO NOTHING",
+            table_name,
+            columns.join(", "),
+            values_query
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        db.execute(Statement::from_sql_and_values(
+            psql,
+            &query,
+            rows.into_iter().flatten(),
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_many_book(
         &self,
         user_id: Uuid,
-        _kind: Option<NotificationKind>,
-        per_page: usize,
-        page: usize,
+        limit: usize,
+        offset: usize,
         sort_by: NotificationSortBy,
     ) -> crate::Result<Vec<Notification>> {
-        // TODO: kind 추가되면 패턴 매칭
-        /* let notificiations = match kind {
-            Some(NotificationKind::Book) => {}
-            _ => {}
-        }; */
-
         // TODO: https://github.com/SeaQL/sea-orm/issues/529
         /* let select =
             notification::book::Entity::find().find_with_related(notification::book::tag::Entity);
@@ -98,6 +140,8 @@ impl NotificationRepository for PostgresqlNotificationRepository {
                 ) AS "notifications_book"
                 LEFT JOIN "notifications_book_tag"
                     ON "notifications_book"."id" = "notifications_book_tag"."notification_book_id"
+            ORDER BY
+                {sort_by}, "notifications_book"."id"
             "#
         );
 
@@ -110,13 +154,13 @@ impl NotificationRepository for PostgresqlNotificationRepository {
                 &query,
                 [
                     user_id.into(),
-                    (per_page as u64).into(),
-                    ((per_page * (page - 1)) as u64).into(),
+                    (limit as u64).into(),
+                    (offset as u64).into(),
                 ],
             ))
             .await?;
 
-        let mut xs = Vec::with_capacity(per_page);
+        let mut xs = Vec::with_capacity(limit);
 
         for query_result in r {
             let a = notification::book::Model {
@@ -151,11 +195,120 @@ impl NotificationRepository for PostgresqlNotificationRepository {
         Ok(xs.into_iter().map(Into::into).collect())
     }
 
+    async fn get_many_book_series(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+        offset: usize,
+        sort_by: NotificationSortBy,
+    ) -> crate::Result<Vec<Notification>> {
+        let select = notification::book_series::Entity::find();
+        let r = match sort_by {
+            NotificationSortBy::CreatedAt(Sort::Desc) => {
+                select.order_by_desc(notification::book_series::Column::CreatedAt)
+            }
+            NotificationSortBy::CreatedAt(Sort::Asc) => {
+                select.order_by_asc(notification::book_series::Column::CreatedAt)
+            }
+        }
+        .filter(notification::book_series::Column::UserId.eq(user_id))
+        .limit(limit as u64)
+        .offset(offset as u64)
+        .all(self.database.postgresql())
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_many_user(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+        offset: usize,
+        sort_by: NotificationSortBy,
+    ) -> crate::Result<Vec<Notification>> {
+        let select = notification::user::Entity::find();
+        let r = match sort_by {
+            NotificationSortBy::CreatedAt(Sort::Desc) => {
+                select.order_by_desc(notification::user::Column::CreatedAt)
+            }
+            NotificationSortBy::CreatedAt(Sort::Asc) => {
+                select.order_by_asc(notification::user::Column::CreatedAt)
+            }
+        }
+        .filter(notification::user::Column::UserId.eq(user_id))
+        .limit(limit as u64)
+        .offset(offset as u64)
+        .all(self.database.postgresql())
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationRepository for PostgresqlNotificationRepository {
+    #[tracing::instrument(name = "NotificationRepository::get_many", skip_all)]
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        kind: Option<NotificationKind>,
+        per_page: usize,
+        page: usize,
+        sort_by: NotificationSortBy,
+    ) -> crate::Result<Vec<Notification>> {
+        let _timer = self.metrics.query("NotificationRepository::get_many");
+
+        let offset = per_page * (page - 1);
+
+        match kind {
+            Some(NotificationKind::Book) => {
+                self.get_many_book(user_id, per_page, offset, sort_by).await
+            }
+            Some(NotificationKind::BookSeries) => {
+                self.get_many_book_series(user_id, per_page, offset, sort_by)
+                    .await
+            }
+            Some(NotificationKind::User) => {
+                self.get_many_user(user_id, per_page, offset, sort_by).await
+            }
+            None => {
+                // 종류마다 테이블이 달라서 각각 앞에서부터 필요한 만큼 가져와서 합침
+                let limit = per_page * page;
+
+                let (books, book_series, users) = futures::try_join!(
+                    self.get_many_book(user_id, limit, 0, sort_by),
+                    self.get_many_book_series(user_id, limit, 0, sort_by),
+                    self.get_many_user(user_id, limit, 0, sort_by)
+                )?;
+
+                let r = books.into_iter().chain(book_series).chain(users);
+
+                let r = match sort_by {
+                    NotificationSortBy::CreatedAt(Sort::Desc) => {
+                        r.sorted_by(|a, b| b.created_at().cmp(&a.created_at()))
+                    }
+                    NotificationSortBy::CreatedAt(Sort::Asc) => {
+                        r.sorted_by(|a, b| a.created_at().cmp(&b.created_at()))
+                    }
+                }
+                .skip(offset)
+                .take(per_page)
+                .collect();
+
+                Ok(r)
+            }
+        }
+    }
+
+    #[tracing::instrument(name = "NotificationRepository::add_many", skip_all)]
     async fn add_many(
         &self,
         kind: NotificationKind,
         notifications: Vec<Notification>,
     ) -> crate::Result<()> {
+        let _timer = self.metrics.query("NotificationRepository::add_many");
+
         match kind {
             NotificationKind::Book => {
                 let r = notifications
@@ -283,6 +436,64 @@ impl NotificationRepository for PostgresqlNotificationRepository {
 
                 Ok(())
             }
+
+            NotificationKind::BookSeries => {
+                let rows = notifications
+                    .into_iter()
+                    .map(notification::book_series::ActiveModel::insert)
+                    .map(|x| {
+                        vec![
+                            x.id.unwrap().into(),
+                            x.user_id.unwrap().into(),
+                            x.book_id.unwrap().into(),
+                            x.series_name.unwrap().into(),
+                            x.created_at.unwrap().into(),
+                        ]
+                    })
+                    .collect();
+
+                self.insert_many(
+                    notification::book_series::Entity.table_name(),
+                    &[
+                        notification::book_series::Column::Id.as_str(),
+                        notification::book_series::Column::UserId.as_str(),
+                        notification::book_series::Column::BookId.as_str(),
+                        notification::book_series::Column::SeriesName.as_str(),
+                        notification::book_series::Column::CreatedAt.as_str(),
+                    ],
+                    rows,
+                )
+                .await
+            }
+
+            NotificationKind::User => {
+                let rows = notifications
+                    .into_iter()
+                    .map(notification::user::ActiveModel::insert)
+                    .map(|x| {
+                        vec![
+                            x.id.unwrap().into(),
+                            x.user_id.unwrap().into(),
+                            x.followee_id.unwrap().into(),
+                            x.book_id.unwrap().into(),
+                            x.created_at.unwrap().into(),
+                        ]
+                    })
+                    .collect();
+
+                self.insert_many(
+                    notification::user::Entity.table_name(),
+                    &[
+                        notification::user::Column::Id.as_str(),
+                        notification::user::Column::UserId.as_str(),
+                        notification::user::Column::FolloweeId.as_str(),
+                        notification::user::Column::BookId.as_str(),
+                        notification::user::Column::CreatedAt.as_str(),
+                    ],
+                    rows,
+                )
+                .await
+            }
         }
 
         /*  match noti.kind() {
@@ -325,3 +536,199 @@ impl NotificationRepository for PostgresqlNotificationRepository {
         } */
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Notification, NotificationKind, NotificationSortBy, Sort},
+        repository::{postgresql::tests::context, r#trait::NotificationRepository},
+    };
+
+    fn book_ids(notifications: &[Notification]) -> Vec<u32> {
+        notifications
+            .iter()
+            .map(|x| match x {
+                Notification::Book { book_id, .. }
+                | Notification::BookSeries { book_id, .. }
+                | Notification::User { book_id, .. } => *book_id,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn add_many() {
+        let context = context().await;
+        let repository = &context.notification;
+        let (user_id, followee_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let tags = || vec![("artist".to_string(), "a".to_string())];
+
+        repository
+            .add_many(
+                NotificationKind::Book,
+                vec![
+                    Notification::book(user_id, 1, tags()),
+                    Notification::book(user_id, 2, tags()),
+                ],
+            )
+            .await
+            .unwrap();
+        // 이미 있는 알림은 무시함
+        repository
+            .add_many(
+                NotificationKind::Book,
+                vec![
+                    Notification::book(user_id, 1, tags()),
+                    Notification::book(user_id, 3, tags()),
+                ],
+            )
+            .await
+            .unwrap();
+        repository
+            .add_many(
+                NotificationKind::User,
+                vec![
+                    Notification::user(user_id, followee_id, 1),
+                    Notification::user(user_id, followee_id, 1),
+                ],
+            )
+            .await
+            .unwrap();
+        repository
+            .add_many(
+                NotificationKind::BookSeries,
+                vec![Notification::book_series(user_id, 4, "madome".to_string())],
+            )
+            .await
+            .unwrap();
+        repository
+            .add_many(NotificationKind::Book, vec![])
+            .await
+            .unwrap();
+
+        let sort_by = NotificationSortBy::CreatedAt(Sort::Desc);
+
+        let r = repository
+            .get_many(user_id, None, 10, 1, sort_by)
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 5);
+
+        let r = repository
+            .get_many(user_id, Some(NotificationKind::Book), 10, 1, sort_by)
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 3);
+        assert!(r
+            .iter()
+            .all(|x| matches!(x, Notification::Book { book_tags, .. } if *book_tags == tags())));
+
+        let r = repository
+            .get_many(user_id, Some(NotificationKind::User), 10, 1, sort_by)
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 1);
+
+        let r = repository
+            .get_many(user_id, Some(NotificationKind::BookSeries), 10, 1, sort_by)
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![4]);
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn get_many() {
+        let context = context().await;
+        let repository = &context.notification;
+        let user_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        // 작품 알림은 태그가 여러 개라도 하나로 묶임
+        let books = (0..6_u32)
+            .filter(|x| x % 3 == 0)
+            .map(|x| Notification::Book {
+                book_id: x,
+                book_tags: vec![
+                    ("artist".to_string(), "a".to_string()),
+                    ("group".to_string(), "b".to_string()),
+                ],
+                user_id,
+                created_at: now - Duration::minutes(x as i64),
+            })
+            .collect();
+        let book_series = (0..6_u32)
+            .filter(|x| x % 3 == 1)
+            .map(|x| Notification::BookSeries {
+                book_id: x,
+                series_name: "madome".to_string(),
+                user_id,
+                created_at: now - Duration::minutes(x as i64),
+            })
+            .collect();
+        let users = (0..6_u32)
+            .filter(|x| x % 3 == 2)
+            .map(|x| Notification::User {
+                followee_id: Uuid::new_v4(),
+                book_id: x,
+                user_id,
+                created_at: now - Duration::minutes(x as i64),
+            })
+            .collect();
+
+        repository
+            .add_many(NotificationKind::Book, books)
+            .await
+            .unwrap();
+        repository
+            .add_many(NotificationKind::BookSeries, book_series)
+            .await
+            .unwrap();
+        repository
+            .add_many(NotificationKind::User, users)
+            .await
+            .unwrap();
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                2,
+                2,
+                NotificationSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![2, 3]);
+
+        let r = repository
+            .get_many(
+                user_id,
+                None,
+                4,
+                1,
+                NotificationSortBy::CreatedAt(Sort::Asc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![5, 4, 3, 2]);
+
+        let r = repository
+            .get_many(
+                user_id,
+                Some(NotificationKind::Book),
+                1,
+                2,
+                NotificationSortBy::CreatedAt(Sort::Desc),
+            )
+            .await
+            .unwrap();
+        assert_eq!(book_ids(&r), vec![3]);
+        assert!(matches!(&r[0], Notification::Book { book_tags, .. } if book_tags.len() == 2));
+
+        context.teardown().await;
+    }
+}
@@ -0,0 +1,147 @@
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, IdenStatic, QueryFilter, Statement};
+use uuid::Uuid;
+
+use crate::{
+    database::{postgresql::entity::profile, DatabaseSet},
+    entity::{Profile, Visibility},
+    metrics::Metrics,
+    repository::r#trait::ProfileRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlProfileRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlProfileRepository {
+    async fn start(&mut self) {
+        profile::create_table(self.database.postgresql()).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ProfileRepository for PostgresqlProfileRepository {
+    #[tracing::instrument(name = "ProfileRepository::get", skip_all)]
+    async fn get(&self, user_id: Uuid) -> crate::Result<Option<Profile>> {
+        let _timer = self.metrics.query("ProfileRepository::get");
+
+        let r = profile::Entity::find_by_id(user_id)
+            .one(self.database.postgresql())
+            .await?;
+
+        Ok(r.map(Into::into))
+    }
+
+    #[tracing::instrument(name = "ProfileRepository::get_by_share_token", skip_all)]
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Profile>> {
+        let _timer = self.metrics.query("ProfileRepository::get_by_share_token");
+
+        let r = profile::Entity::find()
+            .filter(profile::Column::ShareToken.eq(share_token))
+            .filter(profile::Column::Visibility.ne(Visibility::Private as i16))
+            .one(self.database.postgresql())
+            .await?;
+
+        Ok(r.map(Into::into))
+    }
+
+    #[tracing::instrument(name = "ProfileRepository::add_or_update", skip_all)]
+    async fn add_or_update(
+        &self,
+        Profile {
+            user_id,
+            visibility,
+            share_token,
+            updated_at,
+        }: Profile,
+    ) -> crate::Result<()> {
+        let _timer = self.metrics.query("ProfileRepository::add_or_update");
+
+        let query = format!(
+            r#"
+            INSERT INTO
+                {table_name}(user_id, visibility, share_token, updated_at)
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT (user_id)
+                DO UPDATE
+                    SET visibility = $2, share_token = $3, updated_at = $4
+            "#,
+            table_name = profile::Entity.as_str()
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        db.execute(Statement::from_sql_and_values(
+            psql,
+            &query,
+            [
+                user_id.into(),
+                (visibility as i16).into(),
+                share_token.into(),
+                updated_at.into(),
+            ],
+        ))
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{Profile, Visibility},
+        repository::{postgresql::tests::context, r#trait::ProfileRepository},
+    };
+
+    #[tokio::test]
+    async fn add_or_update() {
+        let context = context().await;
+        let repository = &context.profile;
+        let user_id = context.add_user().await;
+
+        assert!(repository.get(user_id).await.unwrap().is_none());
+
+        repository
+            .add_or_update(Profile::new(user_id))
+            .await
+            .unwrap();
+
+        let share_token = Uuid::new_v4().to_simple().to_string();
+        let profile = Profile {
+            visibility: Visibility::Unlisted,
+            share_token: Some(share_token.clone()),
+            ..Profile::new(user_id)
+        };
+        repository.add_or_update(profile).await.unwrap();
+
+        let r = repository.get(user_id).await.unwrap().unwrap();
+        assert_eq!(r.visibility, Visibility::Unlisted);
+
+        let r = repository
+            .get_by_share_token(share_token)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(r.user_id, user_id);
+
+        assert!(repository
+            .get_by_share_token("nothing".to_string())
+            .await
+            .unwrap()
+            .is_none());
+
+        context.teardown().await;
+    }
+}
@@ -0,0 +1,195 @@
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, ConnectionTrait, EntityTrait, IdenStatic, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Statement,
+};
+use uuid::Uuid;
+
+use crate::{
+    database::{postgresql::entity::rating, DatabaseSet},
+    entity::{Rating, RatingAggregate, RatingSortBy, Sort},
+    metrics::Metrics,
+    repository::r#trait::RatingRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlRatingRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlRatingRepository {
+    async fn start(&mut self) {
+        rating::create_table(self.database.postgresql()).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl RatingRepository for PostgresqlRatingRepository {
+    #[tracing::instrument(name = "RatingRepository::get_many", skip_all)]
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: RatingSortBy,
+    ) -> crate::Result<Vec<Rating>> {
+        let _timer = self.metrics.query("RatingRepository::get_many");
+
+        let select = rating::Entity::find();
+        let r = match sort_by {
+            RatingSortBy::CreatedAt(Sort::Desc) => select.order_by_desc(rating::Column::CreatedAt),
+            RatingSortBy::CreatedAt(Sort::Asc) => select.order_by_asc(rating::Column::CreatedAt),
+            RatingSortBy::UpdatedAt(Sort::Desc) => select.order_by_desc(rating::Column::UpdatedAt),
+            RatingSortBy::UpdatedAt(Sort::Asc) => select.order_by_asc(rating::Column::UpdatedAt),
+            RatingSortBy::Score(Sort::Desc) => select
+                .order_by_desc(rating::Column::Score)
+                .order_by_desc(rating::Column::UpdatedAt),
+            RatingSortBy::Score(Sort::Asc) => select
+                .order_by_asc(rating::Column::Score)
+                .order_by_desc(rating::Column::UpdatedAt),
+        }
+        .filter(rating::Column::UserId.eq(user_id))
+        .paginate(self.database.postgresql(), per_page)
+        .fetch_page(page - 1)
+        .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "RatingRepository::add_or_update", skip_all)]
+    async fn add_or_update(
+        &self,
+        Rating {
+            user_id,
+            book_id,
+            score,
+            note,
+            created_at,
+            updated_at,
+        }: Rating,
+    ) -> crate::Result<()> {
+        let _timer = self.metrics.query("RatingRepository::add_or_update");
+
+        let query = format!(
+            r#"
+            INSERT INTO
+                {table_name}(id, user_id, book_id, score, note, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id)
+                DO UPDATE
+                    SET score = $4, note = $5, updated_at = $7
+            "#,
+            table_name = rating::Entity.as_str()
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        db.execute(Statement::from_sql_and_values(
+            psql,
+            &query,
+            [
+                rating::ActiveModel::id(user_id, book_id).into(),
+                user_id.into(),
+                (book_id as i32).into(),
+                (score as i16).into(),
+                note.into(),
+                created_at.into(),
+                updated_at.into(),
+            ],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "RatingRepository::remove", skip_all)]
+    async fn remove(&self, user_id: Uuid, book_id: u32) -> crate::Result<bool> {
+        let _timer = self.metrics.query("RatingRepository::remove");
+
+        let r = rating::Entity::delete_by_id(rating::ActiveModel::id(user_id, book_id))
+            .exec(self.database.postgresql())
+            .await?;
+
+        Ok(r.rows_affected > 0)
+    }
+
+    #[tracing::instrument(name = "RatingRepository::get_aggregates", skip_all)]
+    async fn get_aggregates(&self, book_ids: Vec<u32>) -> crate::Result<Vec<RatingAggregate>> {
+        let _timer = self.metrics.query("RatingRepository::get_aggregates");
+
+        if book_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let r = rating::Entity::find()
+            .select_only()
+            .column(rating::Column::BookId)
+            .column_as(Expr::cust("AVG(score)::float8"), "average")
+            .column_as(Expr::cust("COUNT(*)"), "count")
+            .filter(rating::Column::BookId.is_in(book_ids.into_iter().map(|x| x as i32)))
+            .group_by(rating::Column::BookId)
+            .order_by_asc(rating::Column::BookId)
+            .into_model::<rating::Aggregate>()
+            .all(self.database.postgresql())
+            .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use crate::{
+        entity::{Rating, RatingAggregate},
+        repository::{postgresql::tests::context, r#trait::RatingRepository},
+    };
+
+    #[tokio::test]
+    async fn aggregates() {
+        let context = context().await;
+        let repository = &context.rating;
+
+        let (a, b) = (context.add_user().await, context.add_user().await);
+
+        for rating in [
+            Rating::new(a, 1, 5, None),
+            Rating::new(b, 1, 2, Some("meh".to_string())),
+            Rating::new(a, 2, 4, None),
+            // updated
+            Rating::new(a, 2, 3, None),
+        ] {
+            repository.add_or_update(rating).await.unwrap();
+        }
+
+        let r = repository.get_aggregates(vec![1, 2, 3]).await.unwrap();
+
+        assert_eq!(
+            r,
+            vec![
+                RatingAggregate {
+                    book_id: 1,
+                    average: 3.5,
+                    count: 2
+                },
+                RatingAggregate {
+                    book_id: 2,
+                    average: 3.0,
+                    count: 1
+                }
+            ]
+        );
+
+        assert!(repository.remove(b, 1).await.unwrap());
+        assert!(!repository.remove(b, 1).await.unwrap());
+
+        context.teardown().await;
+    }
+}
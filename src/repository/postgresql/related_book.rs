@@ -0,0 +1,177 @@
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, IdenStatic, PaginatorTrait, QueryFilter,
+    QueryOrder, Statement, TransactionTrait,
+};
+
+use crate::{
+    constant::postgresql,
+    database::{postgresql::entity::like, DatabaseSet},
+    entity::RelatedBook,
+    metrics::Metrics,
+    repository::r#trait::RelatedBookRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlRelatedBookRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlRelatedBookRepository {
+    async fn start(&mut self) {
+        like::related_book::create_table(self.database.postgresql()).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl RelatedBookRepository for PostgresqlRelatedBookRepository {
+    #[tracing::instrument(name = "RelatedBookRepository::get_many", skip_all)]
+    async fn get_many(
+        &self,
+        book_id: u32,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<RelatedBook>> {
+        let _timer = self.metrics.query("RelatedBookRepository::get_many");
+
+        let r = like::related_book::Entity::find()
+            .filter(like::related_book::Column::BookId.eq(book_id as i32))
+            .order_by_desc(like::related_book::Column::Support)
+            .order_by_desc(like::related_book::Column::RelatedBookId)
+            .paginate(self.database.postgresql(), per_page)
+            .fetch_page(page - 1)
+            .await?;
+
+        Ok(r.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(name = "RelatedBookRepository::refresh", skip_all)]
+    async fn refresh(&self, min_support: usize) -> crate::Result<bool> {
+        let _timer = self.metrics.query("RelatedBookRepository::refresh");
+
+        let delete_query = format!(
+            "DELETE FROM {related_table}",
+            related_table = like::related_book::Entity.as_str()
+        );
+
+        // 같은 사용자가 좋아요한 작품 쌍의 수
+        let insert_query = format!(
+            r#"
+            INSERT INTO
+                {related_table}(book_id, related_book_id, support, updated_at)
+            SELECT
+                a.book_id, b.book_id, COUNT(*), now()
+            FROM {like_book_table} AS a
+            JOIN {like_book_table} AS b
+                ON a.user_id = b.user_id AND a.book_id <> b.book_id
+            WHERE a.is_dislike = false AND b.is_dislike = false
+            GROUP BY a.book_id, b.book_id
+            HAVING COUNT(*) >= $1
+            "#,
+            related_table = like::related_book::Entity.as_str(),
+            like_book_table = like::book::Entity.as_str(),
+        );
+
+        let refreshed = self
+            .database
+            .postgresql()
+            .transaction::<_, bool, DbErr>(|txn| {
+                Box::pin(async move {
+                    let psql = txn.get_database_backend();
+
+                    // 트랜잭션이 끝나면 같이 풀림
+                    let locked = txn
+                        .query_one(Statement::from_sql_and_values(
+                            psql,
+                            "SELECT pg_try_advisory_xact_lock($1) AS locked",
+                            [postgresql::REFRESH_RELATED_BOOKS_LOCK_KEY.into()],
+                        ))
+                        .await?
+                        .map(|x| x.try_get::<bool>("", "locked"))
+                        .transpose()?
+                        .unwrap_or(false);
+
+                    if !locked {
+                        return Ok(false);
+                    }
+
+                    txn.execute(Statement::from_string(psql, delete_query))
+                        .await?;
+
+                    txn.execute(Statement::from_sql_and_values(
+                        psql,
+                        &insert_query,
+                        [(min_support as i64).into()],
+                    ))
+                    .await?;
+
+                    Ok(true)
+                })
+            })
+            .await?;
+
+        Ok(refreshed)
+    }
+}
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use crate::{
+        entity::{Like, RelatedBook},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{LikeRepository, RelatedBookRepository},
+        },
+    };
+
+    #[tokio::test]
+    async fn refresh() {
+        let context = context().await;
+        let (a, b, c) = (
+            context.add_user().await,
+            context.add_user().await,
+            context.add_user().await,
+        );
+
+        for like in [
+            Like::book(a, 1),
+            Like::book(a, 2),
+            Like::book(a, 3),
+            Like::book(b, 1),
+            Like::book(b, 2),
+            Like::book(c, 3),
+            Like::book_tag(c, "artist".to_string(), "x".to_string()),
+        ] {
+            context.like.add(like).await.unwrap();
+        }
+
+        assert!(context.related_book.refresh(2).await.unwrap());
+
+        let r = context.related_book.get_many(1, 10, 1).await.unwrap();
+        assert_eq!(
+            r,
+            vec![RelatedBook {
+                book_id: 1,
+                related_book_id: 2,
+                support: 2
+            }]
+        );
+
+        // 다시 계산하면 이전 결과는 지워짐
+        assert!(context.related_book.refresh(1).await.unwrap());
+
+        let r = context.related_book.get_many(1, 10, 1).await.unwrap();
+        assert_eq!(
+            r.into_iter().map(|x| x.related_book_id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+
+        context.teardown().await;
+    }
+}
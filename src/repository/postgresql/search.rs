@@ -0,0 +1,474 @@
+use itertools::Itertools;
+use sai::{Component, ComponentLifecycle, Injected};
+use sea_orm::{prelude::DateTimeUtc, ConnectionTrait, IdenStatic, Statement};
+use uuid::Uuid;
+
+use crate::{
+    database::{
+        postgresql::entity::{book_title, history, like, notification},
+        DatabaseSet,
+    },
+    entity::{BookTitle, SearchResult},
+    metrics::Metrics,
+    repository::r#trait::SearchRepository,
+};
+
+#[derive(Component)]
+#[lifecycle]
+pub struct PostgresqlSearchRepository {
+    #[injected]
+    database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
+}
+
+#[async_trait::async_trait]
+impl ComponentLifecycle for PostgresqlSearchRepository {
+    async fn start(&mut self) {
+        book_title::create_table(self.database.postgresql()).await;
+    }
+}
+
+/// ILIKE 패턴으로 쓸 수 있게 와일드카드를 이스케이프함
+fn like_pattern(query: &str) -> String {
+    let escaped = query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{escaped}%")
+}
+
+#[async_trait::async_trait]
+impl SearchRepository for PostgresqlSearchRepository {
+    #[tracing::instrument(name = "SearchRepository::add_or_update_title", skip_all)]
+    async fn add_or_update_title(
+        &self,
+        BookTitle {
+            book_id,
+            title,
+            published_at,
+            updated_at,
+        }: BookTitle,
+    ) -> crate::Result<()> {
+        let _timer = self.metrics.query("SearchRepository::add_or_update_title");
+
+        // 올라온 날짜는 처음 저장된 것을 유지함
+        let query = format!(
+            r#"
+            INSERT INTO
+                {table_name}(book_id, title, published_at, updated_at)
+            VALUES
+                ($1, $2, COALESCE($4, $3), $3)
+            ON CONFLICT (book_id)
+                DO UPDATE
+                    SET title = $2, published_at = COALESCE($4, {table_name}.published_at), updated_at = $3
+            "#,
+            table_name = book_title::Entity.as_str()
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        db.execute(Statement::from_sql_and_values(
+            psql,
+            &query,
+            [
+                (book_id as i32).into(),
+                title.into(),
+                updated_at.into(),
+                published_at.into(),
+            ],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SearchRepository::add_or_update_titles", skip_all)]
+    async fn add_or_update_titles(&self, book_titles: Vec<BookTitle>) -> crate::Result<()> {
+        let _timer = self.metrics.query("SearchRepository::add_or_update_titles");
+
+        // 한 쿼리에서 같은 행을 두 번 수정할 수 없음
+        let book_titles = book_titles
+            .into_iter()
+            .unique_by(|x| x.book_id)
+            .collect::<Vec<_>>();
+
+        if book_titles.is_empty() {
+            return Ok(());
+        }
+
+        let mut values: Vec<sea_orm::Value> = Vec::with_capacity(book_titles.len() * 3);
+        let mut rows = Vec::with_capacity(book_titles.len());
+
+        for BookTitle {
+            book_id,
+            title,
+            published_at,
+            updated_at,
+        } in book_titles
+        {
+            let n = values.len();
+
+            values.push((book_id as i32).into());
+            values.push(title.into());
+            values.push(updated_at.into());
+            values.push(published_at.into());
+
+            rows.push(format!(
+                "(${}, ${}, COALESCE(${}::timestamptz, ${}), ${})",
+                n + 1,
+                n + 2,
+                n + 4,
+                n + 3,
+                n + 3
+            ));
+        }
+
+        let query = format!(
+            r#"
+            INSERT INTO
+                {table_name}(book_id, title, published_at, updated_at)
+            VALUES
+                {rows}
+            ON CONFLICT (book_id)
+                DO UPDATE
+                    SET title = EXCLUDED.title, published_at = COALESCE({table_name}.published_at, EXCLUDED.published_at), updated_at = EXCLUDED.updated_at
+            "#,
+            table_name = book_title::Entity.as_str(),
+            rows = rows.join(", "),
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        db.execute(Statement::from_sql_and_values(psql, &query, values))
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SearchRepository::get_untitled_book_ids", skip_all)]
+    async fn get_untitled_book_ids(&self, after: u32, limit: usize) -> crate::Result<Vec<u32>> {
+        let _timer = self
+            .metrics
+            .query("SearchRepository::get_untitled_book_ids");
+
+        let query = format!(
+            r#"
+            SELECT DISTINCT x.book_id FROM (
+                SELECT book_id FROM {likes_book}
+                UNION ALL
+                SELECT book_id FROM {histories_book}
+            ) AS x
+            WHERE x.book_id > $1
+                AND NOT EXISTS (SELECT 1 FROM {books_title} AS t WHERE t.book_id = x.book_id)
+            ORDER BY x.book_id
+            LIMIT $2
+            "#,
+            likes_book = like::book::Entity.as_str(),
+            histories_book = history::book::Entity.as_str(),
+            books_title = book_title::Entity.as_str(),
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        let rows = db
+            .query_all(Statement::from_sql_and_values(
+                psql,
+                &query,
+                [(after as i32).into(), (limit as i64).into()],
+            ))
+            .await?;
+
+        let book_ids = rows
+            .into_iter()
+            .map(|row| row.try_get::<i32>("", "book_id").map(|x| x as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(book_ids)
+    }
+
+    #[tracing::instrument(name = "SearchRepository::search", skip_all)]
+    async fn search(
+        &self,
+        user_id: Uuid,
+        query: String,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<SearchResult>> {
+        let _timer = self.metrics.query("SearchRepository::search");
+
+        // 제목은 단어 단위(tsvector)나 부분 문자열(trigram)로 찾음
+        let title_matches =
+            "(to_tsvector('simple', t.title) @@ plainto_tsquery('simple', $2) OR t.title ILIKE $3)";
+
+        let sql = format!(
+            r#"
+            SELECT * FROM (
+                SELECT
+                    'like_book' AS kind, l.book_id, t.title,
+                    NULL::varchar AS tag_kind, NULL::varchar AS tag_name, l.created_at AS at
+                FROM {likes_book} AS l
+                    JOIN {books_title} AS t ON t.book_id = l.book_id
+                WHERE l.user_id = $1 AND l.is_dislike = false AND {title_matches}
+
+                UNION ALL
+
+                SELECT
+                    'like_book_tag' AS kind, NULL::integer, NULL::varchar,
+                    l.tag_kind, l.tag_name, l.created_at AS at
+                FROM {likes_book_tag} AS l
+                WHERE l.user_id = $1 AND l.is_dislike = false AND l.tag_name ILIKE $3
+
+                UNION ALL
+
+                SELECT
+                    'history' AS kind, h.book_id, t.title,
+                    NULL::varchar, NULL::varchar, h.updated_at AS at
+                FROM {histories_book} AS h
+                    JOIN {books_title} AS t ON t.book_id = h.book_id
+                WHERE h.user_id = $1 AND {title_matches}
+
+                UNION ALL
+
+                SELECT
+                    'notification' AS kind, n.book_id, t.title,
+                    NULL::varchar, NULL::varchar, n.created_at AS at
+                FROM {notifications_book} AS n
+                    JOIN {books_title} AS t ON t.book_id = n.book_id
+                WHERE n.user_id = $1 AND {title_matches}
+            ) AS r
+            ORDER BY at DESC
+            LIMIT $4
+            OFFSET $5
+            "#,
+            likes_book = like::book::Entity.as_str(),
+            likes_book_tag = like::book_tag::Entity.as_str(),
+            histories_book = history::book::Entity.as_str(),
+            notifications_book = notification::book::Entity.as_str(),
+            books_title = book_title::Entity.as_str(),
+        );
+
+        let db = self.database.postgresql();
+        let psql = db.get_database_backend();
+
+        let pattern = like_pattern(&query);
+
+        let r = db
+            .query_all(Statement::from_sql_and_values(
+                psql,
+                &sql,
+                [
+                    user_id.into(),
+                    query.into(),
+                    pattern.into(),
+                    (per_page as u64).into(),
+                    ((per_page * (page - 1)) as u64).into(),
+                ],
+            ))
+            .await?;
+
+        let mut xs = Vec::with_capacity(r.len());
+
+        for row in r {
+            let kind = row.try_get::<String>("", "kind")?;
+            let at = row.try_get::<DateTimeUtc>("", "at")?;
+
+            let book = || -> crate::Result<(u32, String)> {
+                let book_id = row.try_get::<i32>("", "book_id")?;
+                let title = row.try_get::<String>("", "title")?;
+
+                Ok((book_id as u32, title))
+            };
+
+            let x = match kind.as_str() {
+                "like_book" => {
+                    let (book_id, title) = book()?;
+
+                    SearchResult::LikeBook {
+                        book_id,
+                        title,
+                        user_id,
+                        created_at: at,
+                    }
+                }
+                "like_book_tag" => SearchResult::LikeBookTag {
+                    tag_kind: row.try_get::<String>("", "tag_kind")?,
+                    tag_name: row.try_get::<String>("", "tag_name")?,
+                    user_id,
+                    created_at: at,
+                },
+                "history" => {
+                    let (book_id, title) = book()?;
+
+                    SearchResult::History {
+                        book_id,
+                        title,
+                        user_id,
+                        updated_at: at,
+                    }
+                }
+                "notification" => {
+                    let (book_id, title) = book()?;
+
+                    SearchResult::Notification {
+                        book_id,
+                        title,
+                        user_id,
+                        created_at: at,
+                    }
+                }
+                _ => unreachable!(),
+            };
+
+            xs.push(x);
+        }
+
+        Ok(xs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::like_pattern;
+
+    #[test]
+    fn escape_like_pattern() {
+        assert_eq!(like_pattern("loli"), "%loli%");
+        assert_eq!(like_pattern("100%_\\"), "%100\\%\\_\\\\%");
+    }
+}
+
+/// `like_pattern`의 테스트와 달리 Postgres가 필요함
+#[cfg(all(test, feature = "postgres-test"))]
+mod postgres_tests {
+    use crate::{
+        entity::{BookTitle, History, Like, SearchResult},
+        repository::{
+            postgresql::tests::context,
+            r#trait::{HistoryRepository, LikeRepository, SearchRepository},
+        },
+    };
+
+    #[tokio::test]
+    async fn search() {
+        let context = context().await;
+        let user_id = context.add_user().await;
+
+        for (book_id, title) in [(1, "Hello World"), (2, "Good Bye"), (3, "World Peace")] {
+            context
+                .search
+                .add_or_update_title(BookTitle::new(book_id, title.to_string(), None))
+                .await
+                .unwrap();
+        }
+        // updated
+        context
+            .search
+            .add_or_update_title(BookTitle::new(2, "Goodbye World".to_string(), None))
+            .await
+            .unwrap();
+
+        context.like.add(Like::book(user_id, 1)).await.unwrap();
+        context
+            .like
+            .add(Like::book_tag(
+                user_id,
+                "artist".to_string(),
+                "worldwide".to_string(),
+            ))
+            .await
+            .unwrap();
+        context
+            .history
+            .add_or_update(History::book(2, 1, user_id))
+            .await
+            .unwrap();
+        context
+            .history
+            .add_or_update(History::book(3, 1, context.add_user().await))
+            .await
+            .unwrap();
+
+        let r = context
+            .search
+            .search(user_id, "WORLD".to_string(), 10, 1)
+            .await
+            .unwrap();
+
+        // 최근 것부터
+        assert_eq!(r.len(), 3);
+        assert!(matches!(r[0], SearchResult::History { book_id: 2, .. }));
+        assert!(
+            matches!(&r[1], SearchResult::LikeBookTag { tag_name, .. } if tag_name == "worldwide")
+        );
+        assert!(matches!(r[2], SearchResult::LikeBook { book_id: 1, .. }));
+
+        let r = context
+            .search
+            .search(user_id, "world".to_string(), 1, 3)
+            .await
+            .unwrap();
+        assert_eq!(r.len(), 1);
+
+        let r = context
+            .search
+            .search(user_id, "peace".to_string(), 10, 1)
+            .await
+            .unwrap();
+        assert!(r.is_empty());
+
+        context.teardown().await;
+    }
+
+    #[tokio::test]
+    async fn untitled_book_ids() {
+        let context = context().await;
+        let user_id = context.add_user().await;
+
+        for book_id in [3, 1, 2] {
+            context
+                .like
+                .add(Like::book(user_id, book_id))
+                .await
+                .unwrap();
+        }
+        context
+            .history
+            .add_or_update(History::book(4, 1, user_id))
+            .await
+            .unwrap();
+        context
+            .search
+            .add_or_update_title(BookTitle::new(2, "Hello".to_string(), None))
+            .await
+            .unwrap();
+
+        let r = context.search.get_untitled_book_ids(0, 10).await.unwrap();
+        assert_eq!(r, vec![1, 3, 4]);
+
+        let r = context.search.get_untitled_book_ids(1, 1).await.unwrap();
+        assert_eq!(r, vec![3]);
+
+        // 같은 작품이 여러 번 있어도 됨
+        context
+            .search
+            .add_or_update_titles(vec![
+                BookTitle::new(1, "A".to_string(), None),
+                BookTitle::new(3, "B".to_string(), None),
+                BookTitle::new(3, "B".to_string(), None),
+                BookTitle::new(4, "C".to_string(), None),
+            ])
+            .await
+            .unwrap();
+
+        let r = context.search.get_untitled_book_ids(0, 10).await.unwrap();
+        assert!(r.is_empty());
+
+        context.teardown().await;
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
     constant::postgresql,
     database::{postgresql::entity::user, DatabaseSet},
     entity::User,
+    metrics::Metrics,
     repository::r#trait::UserRepository,
 };
 
@@ -15,6 +16,9 @@ use crate::{
 pub struct PostgresqlUserRepository {
     #[injected]
     database: Injected<DatabaseSet>,
+
+    #[injected]
+    metrics: Injected<Metrics>,
 }
 
 #[async_trait::async_trait]
@@ -26,7 +30,10 @@ impl ComponentLifecycle for PostgresqlUserRepository {
 
 #[async_trait::async_trait]
 impl UserRepository for PostgresqlUserRepository {
+    #[tracing::instrument(name = "UserRepository::get", skip_all)]
     async fn get(&self, id_or_email: String) -> crate::Result<Option<User>> {
+        let _timer = self.metrics.query("UserRepository::get");
+
         let maybe_id = Uuid::from_str(&id_or_email).ok();
 
         let user = user::Entity::find()
@@ -44,7 +51,10 @@ impl UserRepository for PostgresqlUserRepository {
         Ok(user.map(Into::into))
     }
 
+    #[tracing::instrument(name = "UserRepository::add", skip_all)]
     async fn add(&self, user: User) -> crate::Result<Option<User>> {
+        let _timer = self.metrics.query("UserRepository::add");
+
         let r = user::Entity::insert::<user::ActiveModel>(user.clone().into())
             .exec(self.database.postgresql())
             .await;
@@ -58,3 +68,42 @@ impl UserRepository for PostgresqlUserRepository {
         }
     }
 }
+
+#[cfg(all(test, feature = "postgres-test"))]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        entity::{User, UserRole},
+        repository::{postgresql::tests::context, r#trait::UserRepository},
+    };
+
+    #[tokio::test]
+    async fn add_and_get() {
+        let context = context().await;
+        let repository = &context.user;
+
+        let email = format!("{}@madome.app", Uuid::new_v4());
+        let user = User::new("madome".to_string(), email.clone(), UserRole::Normal);
+        let user_id = user.id;
+
+        assert!(repository.add(user).await.unwrap().is_some());
+
+        // duplicated email
+        let user = User::new("madome".to_string(), email.clone(), UserRole::Normal);
+        assert!(repository.add(user).await.unwrap().is_none());
+
+        let by_id = repository.get(user_id.to_string()).await.unwrap().unwrap();
+        let by_email = repository.get(email).await.unwrap().unwrap();
+        assert_eq!(by_id.id, user_id);
+        assert_eq!(by_email.id, user_id);
+
+        assert!(repository
+            .get(Uuid::new_v4().to_string())
+            .await
+            .unwrap()
+            .is_none());
+
+        context.teardown().await;
+    }
+}
@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::entity::{Block, BlockKind};
+
+#[async_trait::async_trait]
+pub trait BlockRepository: Send + Sync {
+    async fn add(&self, block: Block) -> crate::Result<bool>;
+
+    async fn remove(&self, user_id: Uuid, target_id: Uuid, kind: BlockKind) -> crate::Result<bool>;
+
+    /// `user_ids` 중에서 `user_id`와 차단 관계인 사용자를 제외함
+    ///
+    /// 차단은 어느 쪽이 했든 제외하고, `exclude_muted`면 `user_id`를 뮤트한 사용자도 제외함
+    ///
+    /// 팔로워 목록, 알림 등 다른 사용자에게 무언가를 보여주기 전에 사용함
+    async fn retain_unblocked(
+        &self,
+        user_id: Uuid,
+        user_ids: Vec<Uuid>,
+        exclude_muted: bool,
+    ) -> crate::Result<Vec<Uuid>>;
+}
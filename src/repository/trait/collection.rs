@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::entity::{Collection, CollectionItem, CollectionSortBy};
+
+#[async_trait::async_trait]
+pub trait CollectionRepository: Send + Sync {
+    async fn get(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<Option<Collection>>;
+
+    /// `Private`인 컬렉션은 토큰이 없으므로 찾을 수 없음
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Collection>>;
+
+    /// `Public`인 컬렉션만, updated_at 내림차순
+    async fn get_many_public(&self, user_id: Uuid) -> crate::Result<Vec<Collection>>;
+
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: CollectionSortBy,
+    ) -> crate::Result<Vec<Collection>>;
+
+    /// 이름은 사용자마다 겹치지 않음
+    async fn get_by_name(&self, user_id: Uuid, name: String) -> crate::Result<Option<Collection>>;
+
+    /// 같은 이름의 컬렉션이 이미 있으면 false
+    async fn add(&self, collection: Collection) -> crate::Result<bool>;
+
+    /// name, visibility, share_token, updated_at만 갱신함
+    async fn update(&self, collection: Collection) -> crate::Result<bool>;
+
+    async fn remove(&self, user_id: Uuid, collection_id: Uuid) -> crate::Result<bool>;
+
+    /// position 오름차순
+    async fn get_items(&self, collection_id: Uuid) -> crate::Result<Vec<CollectionItem>>;
+
+    /// 마지막 순서에 추가함
+    async fn add_item(&self, item: CollectionItem) -> crate::Result<bool>;
+
+    async fn remove_item(&self, collection_id: Uuid, book_id: u32) -> crate::Result<bool>;
+
+    /// `book_ids`의 순서대로 position을 다시 매김
+    async fn reorder_items(&self, collection_id: Uuid, book_ids: Vec<u32>) -> crate::Result<()>;
+}
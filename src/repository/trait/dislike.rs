@@ -2,6 +2,10 @@ use uuid::Uuid;
 
 use crate::entity::{Dislike, DislikeKind, DislikeSortBy};
 
+pub enum DislikeBy {
+    Book { ids: Vec<u32> },
+}
+
 #[async_trait::async_trait]
 pub trait DislikeRepository: Send + Sync {
     async fn get_many(
@@ -13,6 +17,8 @@ pub trait DislikeRepository: Send + Sync {
         sort_by: DislikeSortBy,
     ) -> crate::Result<Vec<Dislike>>;
 
+    async fn get_many_by(&self, user_id: Uuid, by: DislikeBy) -> crate::Result<Vec<Dislike>>;
+
     async fn add(&self, dislike: Dislike) -> crate::Result<bool>;
 
     async fn remove(&self, dislike: Dislike) -> crate::Result<bool>;
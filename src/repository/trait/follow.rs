@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::entity::{Follow, FollowSortBy};
+
+#[async_trait::async_trait]
+pub trait FollowRepository: Send + Sync {
+    /// `user_id`를 팔로우하는 사용자들
+    ///
+    /// `user_id`와 차단 관계인 사용자는 제외함
+    async fn get_followers(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>>;
+
+    /// `user_id`가 팔로우하는 사용자들
+    ///
+    /// `user_id`와 차단 관계인 사용자는 제외함
+    async fn get_following(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: FollowSortBy,
+    ) -> crate::Result<Vec<Follow>>;
+
+    /// 알림을 보낼 때 사용함
+    async fn get_follower_ids(&self, user_id: Uuid) -> crate::Result<Vec<Uuid>>;
+
+    async fn add(&self, follow: Follow) -> crate::Result<bool>;
+
+    async fn remove(&self, follower_id: Uuid, followee_id: Uuid) -> crate::Result<bool>;
+}
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::entity::{History, HistoryKind, HistorySortBy};
+use crate::entity::{History, HistoryFilter, HistoryKind, HistorySortBy};
 
 pub enum HistoryBy {
     Book { ids: Vec<u32> },
@@ -12,6 +12,7 @@ pub trait HistoryRepository: Send + Sync {
         &self,
         user_id: Uuid,
         kind: Option<HistoryKind>,
+        filter: HistoryFilter,
         per_page: usize,
         page: usize,
         sort_by: HistorySortBy,
@@ -19,6 +20,9 @@ pub trait HistoryRepository: Send + Sync {
 
     async fn get_many_by(&self, user_id: Uuid, by: HistoryBy) -> crate::Result<Vec<History>>;
 
+    /// 작품들 중 하나라도 읽은 사용자들
+    async fn get_reader_ids(&self, book_ids: Vec<u32>) -> crate::Result<Vec<Uuid>>;
+
     async fn add_or_update(&self, history: History) -> crate::Result<()>;
 
     // async fn update(&self, history: History) -> crate::Result<Option<History>>;
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::entity::{Like, LikeKind, LikeSortBy};
+use crate::entity::{Like, LikeFilter, LikeKind, LikeSortBy};
 
 pub enum LikeBy {
     Book { ids: Vec<u32> },
@@ -15,6 +15,7 @@ pub trait LikeRepository: Send + Sync {
         &self,
         user_id: Uuid,
         kind: Option<LikeKind>,
+        filter: LikeFilter,
         per_page: usize,
         page: usize,
         sort_by: LikeSortBy,
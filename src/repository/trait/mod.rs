@@ -1,13 +1,27 @@
+mod block;
+mod collection;
 mod dislike;
 mod fcm_token;
+mod follow;
 mod history;
 mod like;
 mod notification;
+mod profile;
+mod rating;
+mod related_book;
+mod search;
 mod user;
 
-pub use dislike::DislikeRepository;
+pub use block::BlockRepository;
+pub use collection::CollectionRepository;
+pub use dislike::*;
 pub use fcm_token::FcmTokenRepository;
+pub use follow::FollowRepository;
 pub use history::*;
 pub use like::*;
 pub use notification::NotificationRepository;
+pub use profile::ProfileRepository;
+pub use rating::RatingRepository;
+pub use related_book::RelatedBookRepository;
+pub use search::SearchRepository;
 pub use user::UserRepository;
@@ -3,7 +3,7 @@ use uuid::Uuid;
 use crate::entity::{notification::NotificationSortBy, Notification, NotificationKind};
 
 #[async_trait::async_trait]
-pub trait NotificationRepository {
+pub trait NotificationRepository: Send + Sync {
     async fn get_many(
         &self,
         user_id: Uuid,
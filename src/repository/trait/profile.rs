@@ -0,0 +1,12 @@
+use uuid::Uuid;
+
+use crate::entity::Profile;
+
+#[async_trait::async_trait]
+pub trait ProfileRepository: Send + Sync {
+    async fn get(&self, user_id: Uuid) -> crate::Result<Option<Profile>>;
+
+    async fn get_by_share_token(&self, share_token: String) -> crate::Result<Option<Profile>>;
+
+    async fn add_or_update(&self, profile: Profile) -> crate::Result<()>;
+}
@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+use crate::entity::{Rating, RatingAggregate, RatingSortBy};
+
+#[async_trait::async_trait]
+pub trait RatingRepository: Send + Sync {
+    async fn get_many(
+        &self,
+        user_id: Uuid,
+        per_page: usize,
+        page: usize,
+        sort_by: RatingSortBy,
+    ) -> crate::Result<Vec<Rating>>;
+
+    /// 이미 평가한 작품이면 score, note, updated_at을 갱신함
+    async fn add_or_update(&self, rating: Rating) -> crate::Result<()>;
+
+    async fn remove(&self, user_id: Uuid, book_id: u32) -> crate::Result<bool>;
+
+    /// 평가가 하나도 없는 작품은 포함하지 않음
+    ///
+    /// 작품 아이디 순서로 정렬됨
+    async fn get_aggregates(&self, book_ids: Vec<u32>) -> crate::Result<Vec<RatingAggregate>>;
+}
@@ -0,0 +1,17 @@
+use crate::entity::RelatedBook;
+
+#[async_trait::async_trait]
+pub trait RelatedBookRepository: Send + Sync {
+    /// support 내림차순, 같으면 related_book_id 내림차순
+    async fn get_many(
+        &self,
+        book_id: u32,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<RelatedBook>>;
+
+    /// likes_book에서 다시 계산함
+    ///
+    /// 다른 인스턴스에서 이미 계산 중이면 아무것도 하지 않고 false를 반환함
+    async fn refresh(&self, min_support: usize) -> crate::Result<bool>;
+}
@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::entity::{BookTitle, SearchResult};
+
+#[async_trait::async_trait]
+pub trait SearchRepository: Send + Sync {
+    async fn add_or_update_title(&self, book_title: BookTitle) -> crate::Result<()>;
+
+    /// Library에서 가져온 작품들을 한 번에 저장함
+    ///
+    /// 올라온 날짜는 이미 저장돼 있으면 유지함
+    async fn add_or_update_titles(&self, book_titles: Vec<BookTitle>) -> crate::Result<()>;
+
+    /// 좋아요나 기록에는 있지만 제목이 저장되지 않은 작품들
+    ///
+    /// `after`보다 큰 아이디만 오름차순으로 반환함
+    async fn get_untitled_book_ids(&self, after: u32, limit: usize) -> crate::Result<Vec<u32>>;
+
+    /// 좋아요한 태그 이름, 좋아요/기록/알림의 작품 제목에서 찾음
+    ///
+    /// 최근 것부터 정렬됨
+    async fn search(
+        &self,
+        user_id: Uuid,
+        query: String,
+        per_page: usize,
+        page: usize,
+    ) -> crate::Result<Vec<SearchResult>>;
+}
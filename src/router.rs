@@ -0,0 +1,440 @@
+//! 요청의 메서드와 경로로 어떤 Msg를 만들지 찾음
+//!
+//! 경로는 라우트 테이블에 적힌 순서대로 비교하므로 `/users/@me`처럼 고정된 경로를
+//! `/users/:user_id_or_email`처럼 변수가 있는 경로보다 먼저 적어야 함
+//!
+//! `/v1/users/@me`처럼 버전을 붙일 수 있고, 버전이 없는 경로는 v1으로 봄
+
+use std::str::FromStr;
+
+use chrono::{TimeZone, Utc};
+use hyper::{Body, Method, Response};
+
+use crate::msg::Error;
+
+/// 응답의 모양이 바뀌면 버전을 올림
+///
+/// 앱은 버전을 고정해서 쓰고, 핸들러와 Presenter는 요청의 extensions에서 버전을 꺼내 씀
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Version {
+    V1,
+}
+
+impl Version {
+    pub const ALL: &'static [Version] = &[Version::V1];
+
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Self::V1 => "/v1",
+        }
+    }
+
+    /// 버전이 없는 경로는 v1으로 봄
+    ///
+    /// 버전을 붙이기 전에 배포된 앱이 있어서 최신 버전이 아니라 v1이어야 함
+    pub fn split(path: &str) -> (Self, &str) {
+        for version in Self::ALL {
+            if let Some(rest) = path.strip_prefix(version.prefix()) {
+                if rest.starts_with('/') {
+                    return (*version, rest);
+                }
+            }
+        }
+
+        (Self::V1, path)
+    }
+}
+
+const DEPRECATION: &str = "deprecation";
+const SUNSET: &str = "sunset";
+
+/// 없어질 예정인 라우트
+///
+/// 응답에 `Deprecation`(RFC 9745), `Sunset`(RFC 8594) 헤더를 붙임
+// 아직 없어질 예정인 라우트가 없음
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub struct Deprecation {
+    /// unix timestamp
+    pub deprecated_at: i64,
+    /// 이 시각 이후로는 응답하지 않음, unix timestamp
+    pub sunset_at: Option<i64>,
+}
+
+impl Deprecation {
+    pub fn set_response(&self, resp: &mut Response<Body>) {
+        let deprecated_at = format!("@{}", self.deprecated_at);
+
+        resp.headers_mut()
+            .insert(DEPRECATION, deprecated_at.parse().unwrap());
+
+        if let Some(sunset_at) = self.sunset_at {
+            let sunset_at = Utc
+                .timestamp_opt(sunset_at, 0)
+                .unwrap()
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string();
+
+            resp.headers_mut()
+                .insert(SUNSET, sunset_at.parse().unwrap());
+        }
+    }
+}
+
+/// 라우트를 호출할 수 있는 사용자
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    /// 로그인한 사용자
+    Public,
+    /// 다른 서비스
+    Internal,
+    /// 누구나, 로그인했으면 사용자를 알아냄
+    Either,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endpoint {
+    CreateUser,
+    GetMe,
+    GetUser,
+
+    CreateLike,
+    GetLikes,
+    DeleteLike,
+    GetLikesBy,
+    GetRelatedBooks,
+
+    CreateNotifications,
+    GetNotifications,
+
+    CreateOrUpdateFcmToken,
+    GetFcmTokens,
+
+    CreateOrUpdateHistory,
+    GetHistories,
+    DeleteHistory,
+    GetHistoriesBy,
+
+    CreateCollection,
+    GetCollections,
+    GetCollection,
+    UpdateCollection,
+    DeleteCollection,
+    AddCollectionItem,
+    DeleteCollectionItem,
+    ReorderCollectionItems,
+    UpdateCollectionVisibility,
+
+    UpdateProfileVisibility,
+    GetShared,
+
+    CreateOrUpdateRating,
+    GetRatings,
+    DeleteRating,
+    GetRatingAggregates,
+
+    GetRecommendations,
+
+    FollowUser,
+    GetFollowing,
+    UnfollowUser,
+    GetFollowers,
+
+    CreateBlock,
+    DeleteBlock,
+    CreateMute,
+    DeleteMute,
+
+    GetSearchResults,
+
+    InvalidateLibraryCache,
+
+    GetOpenApi,
+    GetMetrics,
+    GetLiveness,
+    GetReadiness,
+}
+
+#[derive(Debug)]
+pub struct Route {
+    pub method: Method,
+    pub path: &'static str,
+    pub access: Access,
+    pub endpoint: Endpoint,
+    pub deprecation: Option<Deprecation>,
+}
+
+/// `(메서드, 경로, 접근, 엔드포인트)` 뒤에 `Deprecation`을 붙이면 없어질 예정인 라우트가 됨
+macro_rules! routes {
+    (@deprecation) => { None };
+    (@deprecation $deprecation:expr) => { Some($deprecation) };
+    [$(($method:ident, $path:literal, $access:ident, $endpoint:ident $(, $deprecation:expr)?)),* $(,)?] => {
+        &[$(Route {
+            method: Method::$method,
+            path: $path,
+            access: Access::$access,
+            endpoint: Endpoint::$endpoint,
+            deprecation: routes!(@deprecation $($deprecation)?),
+        }),*]
+    };
+}
+
+/// 버전마다 라우트가 달라지면 `V2_ROUTES`처럼 따로 만듦
+pub fn routes(version: Version) -> &'static [Route] {
+    match version {
+        Version::V1 => V1_ROUTES,
+    }
+}
+
+pub static V1_ROUTES: &[Route] = routes! {
+    (POST, "/users", Public, CreateUser),
+    (GET, "/users/@me", Public, GetMe),
+    //
+    (POST, "/users/@me/likes", Public, CreateLike),
+    (GET, "/users/@me/likes", Public, GetLikes),
+    (DELETE, "/users/@me/likes", Public, DeleteLike),
+    //
+    (GET, "/users/@me/notifications", Public, GetNotifications),
+    (POST, "/users/@me/fcm-token", Public, CreateOrUpdateFcmToken),
+    //
+    (POST, "/users/@me/histories", Public, CreateOrUpdateHistory),
+    (GET, "/users/@me/histories", Public, GetHistories),
+    (DELETE, "/users/@me/histories", Public, DeleteHistory),
+    //
+    (POST, "/users/@me/collections", Public, CreateCollection),
+    (GET, "/users/@me/collections", Public, GetCollections),
+    (GET, "/users/@me/collections/:collection_id", Public, GetCollection),
+    (PATCH, "/users/@me/collections/:collection_id", Public, UpdateCollection),
+    (DELETE, "/users/@me/collections/:collection_id", Public, DeleteCollection),
+    (POST, "/users/@me/collections/:collection_id/items", Public, AddCollectionItem),
+    (DELETE, "/users/@me/collections/:collection_id/items", Public, DeleteCollectionItem),
+    (PUT, "/users/@me/collections/:collection_id/items", Public, ReorderCollectionItems),
+    (PUT, "/users/@me/collections/:collection_id/visibility", Public, UpdateCollectionVisibility),
+    //
+    (PUT, "/users/@me/profile/visibility", Public, UpdateProfileVisibility),
+    (GET, "/users/shared/:share_token", Either, GetShared),
+    //
+    (POST, "/users/@me/ratings", Public, CreateOrUpdateRating),
+    (GET, "/users/@me/ratings", Public, GetRatings),
+    (DELETE, "/users/@me/ratings", Public, DeleteRating),
+    //
+    (GET, "/users/@me/recommendations", Public, GetRecommendations),
+    //
+    (POST, "/users/@me/following", Public, FollowUser),
+    (GET, "/users/@me/following", Public, GetFollowing),
+    (DELETE, "/users/@me/following", Public, UnfollowUser),
+    (GET, "/users/@me/followers", Public, GetFollowers),
+    //
+    (POST, "/users/@me/blocks", Public, CreateBlock),
+    (DELETE, "/users/@me/blocks", Public, DeleteBlock),
+    (POST, "/users/@me/mutes", Public, CreateMute),
+    (DELETE, "/users/@me/mutes", Public, DeleteMute),
+    //
+    (GET, "/users/@me/search", Public, GetSearchResults),
+    //
+    (POST, "/users/notifications", Internal, CreateNotifications),
+    (POST, "/users/library/invalidate", Internal, InvalidateLibraryCache),
+    (GET, "/users/ratings/aggregates", Internal, GetRatingAggregates),
+    (GET, "/users/fcm-token", Internal, GetFcmTokens),
+    (GET, "/users/likes/related", Internal, GetRelatedBooks),
+    (GET, "/users/:user_id/likes", Internal, GetLikesBy),
+    (GET, "/users/:user_id/histories", Internal, GetHistoriesBy),
+    (GET, "/users/openapi.json", Either, GetOpenApi),
+    (GET, "/users/:user_id_or_email", Internal, GetUser),
+    //
+    (GET, "/metrics", Internal, GetMetrics),
+    (GET, "/healthz", Internal, GetLiveness),
+    (GET, "/readyz", Internal, GetReadiness),
+};
+
+/// 경로에 있던 변수들
+#[derive(Debug, Default)]
+pub struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    /// 형식이 맞지 않으면 없는 경로로 봄
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, Error> {
+        self.0
+            .iter()
+            .find(|(key, _)| *key == name)
+            .and_then(|(_, value)| value.parse().ok())
+            .ok_or(Error::NotFound)
+    }
+}
+
+fn matches(pattern: &'static str, path: &str) -> Option<Params> {
+    let mut pats = pattern.split('/');
+    let mut origin = path.split('/');
+    let mut params = Vec::new();
+
+    loop {
+        match (pats.next(), origin.next()) {
+            (Some(pat), Some(origin)) => match pat.strip_prefix(':') {
+                Some(name) if !origin.is_empty() => params.push((name, origin.to_owned())),
+                Some(_) => return None,
+                None if pat == origin => {}
+                None => return None,
+            },
+            (None, None) => return Some(Params(params)),
+            _ => return None,
+        }
+    }
+}
+
+/// 경로는 있는데 메서드가 다르면 허용하는 메서드들을 알려줌
+pub fn route(method: &Method, path: &str) -> Result<(Version, &'static Route, Params), Error> {
+    let (version, path) = Version::split(path);
+    let mut allow = Vec::new();
+
+    for route in routes(version) {
+        if let Some(params) = matches(route.path, path) {
+            if route.method == *method {
+                return Ok((version, route, params));
+            }
+
+            if !allow.contains(&route.method) {
+                allow.push(route.method.clone());
+            }
+        }
+    }
+
+    match allow.is_empty() {
+        true => Err(Error::NotFound),
+        false => Err(Error::MethodNotAllowed(allow)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{Body, Method, Response};
+    use uuid::Uuid;
+
+    use crate::msg::Error;
+
+    use super::{route, Access, Deprecation, Endpoint, Version};
+
+    #[test]
+    fn static_path_first() {
+        let (_, r, _) = route(&Method::GET, "/users/@me").unwrap();
+        assert_eq!(r.endpoint, Endpoint::GetMe);
+        assert_eq!(r.access, Access::Public);
+
+        let (_, r, _) = route(&Method::GET, "/users/fcm-token").unwrap();
+        assert_eq!(r.endpoint, Endpoint::GetFcmTokens);
+
+        let (_, r, _) = route(&Method::GET, "/users/openapi.json").unwrap();
+        assert_eq!(r.endpoint, Endpoint::GetOpenApi);
+
+        let (_, r, params) = route(&Method::GET, "/users/madome@madome.app").unwrap();
+        assert_eq!(r.endpoint, Endpoint::GetUser);
+        assert_eq!(r.access, Access::Internal);
+        assert_eq!(
+            params.get::<String>("user_id_or_email").unwrap(),
+            "madome@madome.app"
+        );
+    }
+
+    #[test]
+    fn path_params() {
+        let collection_id = Uuid::new_v4();
+
+        let (_, r, params) = route(
+            &Method::PUT,
+            &format!("/users/@me/collections/{collection_id}/items"),
+        )
+        .unwrap();
+        assert_eq!(r.endpoint, Endpoint::ReorderCollectionItems);
+        assert_eq!(params.get::<Uuid>("collection_id").unwrap(), collection_id);
+
+        let (_, _, params) = route(&Method::GET, "/users/@me/collections/nothing").unwrap();
+        assert!(matches!(
+            params.get::<Uuid>("collection_id"),
+            Err(Error::NotFound)
+        ));
+    }
+
+    /// 프로브는 게이트웨이를 거치지 않고 로그인하지도 않음
+    #[test]
+    fn probes_skip_authentication() {
+        for (path, endpoint) in [
+            ("/healthz", Endpoint::GetLiveness),
+            ("/readyz", Endpoint::GetReadiness),
+            ("/metrics", Endpoint::GetMetrics),
+        ] {
+            let (_, r, _) = route(&Method::GET, path).unwrap();
+            assert_eq!(r.endpoint, endpoint);
+            assert_eq!(r.access, Access::Internal);
+        }
+    }
+
+    #[test]
+    fn not_found() {
+        assert!(matches!(
+            route(&Method::GET, "/users/@me/nothing"),
+            Err(Error::NotFound)
+        ));
+        assert!(matches!(
+            route(&Method::GET, "/users/@me/collections/"),
+            Err(Error::NotFound)
+        ));
+        assert!(matches!(route(&Method::GET, "/"), Err(Error::NotFound)));
+        // 구현되지 않은 라우트는 없음
+        assert!(matches!(
+            route(&Method::GET, "/users/@me/dislikes"),
+            Err(Error::NotFound)
+        ));
+    }
+
+    #[test]
+    fn method_not_allowed() {
+        match route(&Method::PUT, "/users/@me/likes") {
+            Err(Error::MethodNotAllowed(allow)) => {
+                assert_eq!(allow, vec![Method::POST, Method::GET, Method::DELETE])
+            }
+            r => panic!("{r:?}"),
+        }
+    }
+
+    #[test]
+    fn version() {
+        let (version, r, _) = route(&Method::GET, "/v1/users/@me/likes").unwrap();
+        assert_eq!(version, Version::V1);
+        assert_eq!(r.endpoint, Endpoint::GetLikes);
+
+        // 버전이 없으면 v1
+        let (version, r, _) = route(&Method::GET, "/users/@me/likes").unwrap();
+        assert_eq!(version, Version::V1);
+        assert_eq!(r.endpoint, Endpoint::GetLikes);
+
+        let (_, r, params) = route(&Method::GET, "/v1/users/madome@madome.app").unwrap();
+        assert_eq!(r.endpoint, Endpoint::GetUser);
+        assert_eq!(
+            params.get::<String>("user_id_or_email").unwrap(),
+            "madome@madome.app"
+        );
+
+        assert!(matches!(route(&Method::GET, "/v1"), Err(Error::NotFound)));
+        assert!(matches!(
+            route(&Method::GET, "/v2/users/@me"),
+            Err(Error::NotFound)
+        ));
+        assert!(matches!(
+            route(&Method::GET, "/v1users/@me"),
+            Err(Error::NotFound)
+        ));
+    }
+
+    #[test]
+    fn deprecation_headers() {
+        let mut resp = Response::new(Body::empty());
+
+        Deprecation {
+            deprecated_at: 1_688_169_599,
+            sunset_at: Some(1_704_067_199),
+        }
+        .set_response(&mut resp);
+
+        assert_eq!(resp.headers()["deprecation"], "@1688169599");
+        assert_eq!(resp.headers()["sunset"], "Sun, 31 Dec 2023 23:59:59 GMT");
+    }
+}
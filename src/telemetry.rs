@@ -0,0 +1,124 @@
+//! 요청 아이디, 구조화된 로그, 분산 추적
+//!
+//! 로그는 JSON으로 출력하고, `OTEL_EXPORTER_OTLP_ENDPOINT`가 있으면 span을 OTLP로 내보냄
+
+use std::future::Future;
+
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use tracing_subscriber::{
+    fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter,
+};
+
+pub const REQUEST_ID: &str = "x-request-id";
+
+const SERVICE_NAME: &str = "madome-user";
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// 요청에 붙어온 아이디가 없거나 이상하면 새로 만듦
+///
+/// 로그에 그대로 남으므로 길이와 문자를 제한함
+pub fn request_id_or_new(x: Option<&str>) -> String {
+    match x {
+        Some(x) if is_valid_request_id(x) => x.to_owned(),
+        _ => uuid::Uuid::new_v4().to_string(),
+    }
+}
+
+fn is_valid_request_id(x: &str) -> bool {
+    !x.is_empty()
+        && x.len() <= 128
+        && x.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// 요청을 처리하는 동안 `request_id()`로 아이디를 꺼낼 수 있음
+pub async fn with_request_id<F: Future>(request_id: String, f: F) -> F::Output {
+    CURRENT_REQUEST_ID.scope(request_id, f).await
+}
+
+/// 요청을 처리하는 중이 아니면 없음
+pub fn request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(|x| x.clone()).ok()
+}
+
+/// 기존의 `log` 매크로로 남긴 로그도 같이 출력함
+pub fn init(debug: bool) {
+    let default_level = if debug { "debug" } else { "info" };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_level));
+
+    let fmt = tracing_subscriber::fmt::layer()
+        .json()
+        .with_current_span(true)
+        .with_span_list(false)
+        // span이 닫힐 때 걸린 시간을 남김
+        .with_span_events(FmtSpan::CLOSE);
+
+    let otlp = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .ok()
+        .map(|endpoint| {
+            let tracer = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(endpoint),
+                )
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", SERVICE_NAME),
+                ])))
+                .install_batch(runtime::Tokio)
+                .expect("install otlp pipeline");
+
+            tracing_opentelemetry::layer().with_tracer(tracer)
+        });
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt)
+        .with(otlp)
+        .init();
+}
+
+/// 내보내지 못한 span을 마저 내보냄
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{request_id, request_id_or_new, with_request_id};
+
+    #[test]
+    fn accept_request_id() {
+        assert_eq!(request_id_or_new(Some("abc-123_x.y")), "abc-123_x.y");
+    }
+
+    #[test]
+    fn generate_request_id() {
+        for x in [
+            None,
+            Some(""),
+            Some("a b"),
+            Some("\"}"),
+            Some(&"a".repeat(129)),
+        ] {
+            let r = request_id_or_new(x);
+
+            assert!(uuid::Uuid::parse_str(&r).is_ok(), "{x:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn scoped_request_id() {
+        assert_eq!(request_id(), None);
+
+        let r = with_request_id("abc".to_string(), async { request_id() }).await;
+        assert_eq!(r.as_deref(), Some("abc"));
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    entity::{Block, BlockKind},
+    error::UseCaseError,
+    repository::{
+        r#trait::{BlockRepository, FollowRepository, UserRepository},
+        Backend, RepositorySet,
+    },
+};
+
+#[derive(Debug)]
+pub struct Payload {
+    pub target_id: Uuid,
+    pub user_id: Uuid,
+    pub kind: BlockKind,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct Target {
+    target_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    /// (user_id, 차단인지 뮤트인지는 경로로 정해짐)
+    type Parameter = (Uuid, BlockKind);
+
+    async fn from_request(
+        (user_id, kind): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let Target { target_id } = request.body_parse().await?;
+
+        Ok(Self {
+            target_id,
+            user_id,
+            kind,
+        })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Can't block yourself")]
+    CannotBlockYourself,
+
+    #[error("Not found user")]
+    NotFoundUser,
+
+    #[error("Already exists block")]
+    AlreadyExistsBlock,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        target_id,
+        user_id,
+        kind,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    if target_id == user_id {
+        return Err(Error::CannotBlockYourself.into());
+    }
+
+    let target = repository.user().get(target_id.to_string()).await?;
+
+    if target.is_none() {
+        return Err(Error::NotFoundUser.into());
+    }
+
+    let saved = repository
+        .block()
+        .add(Block::new(user_id, target_id, kind))
+        .await?;
+
+    if !saved {
+        return Err(Error::AlreadyExistsBlock.into());
+    }
+
+    // 차단하면 서로의 팔로우도 끊음
+    if kind == BlockKind::Block {
+        repository.follow().remove(user_id, target_id).await?;
+        repository.follow().remove(target_id, user_id).await?;
+    }
+
+    Ok(Model)
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    entity::BlockKind,
+    error::UseCaseError,
+    repository::{r#trait::BlockRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug)]
+pub struct Payload {
+    pub target_id: Uuid,
+    pub user_id: Uuid,
+    pub kind: BlockKind,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub(crate) struct Target {
+    target_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = (Uuid, BlockKind);
+
+    async fn from_request(
+        (user_id, kind): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let Target { target_id } = request.body_parse().await?;
+
+        Ok(Self {
+            target_id,
+            user_id,
+            kind,
+        })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found block")]
+    NotFoundBlock,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        target_id,
+        user_id,
+        kind,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let removed = repository.block().remove(user_id, target_id, kind).await?;
+
+    if !removed {
+        return Err(Error::NotFoundBlock.into());
+    }
+
+    Ok(Model)
+}
@@ -0,0 +1,2 @@
+pub mod create_block;
+pub mod delete_block;
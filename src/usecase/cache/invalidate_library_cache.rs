@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{
+    command::CommandSet,
+    entity::BookTitle,
+    error::UseCaseError,
+    repository::{r#trait::SearchRepository, Backend, RepositorySet},
+};
+
+/// Library에서 작품이 수정되거나 삭제될 때 호출함
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    #[serde(default)]
+    pub book_ids: Vec<u32>,
+    /// 삭제되면서 더 이상 작품이 없을 수도 있는 태그들
+    #[serde(default)]
+    pub book_tags: Vec<(String, String)>,
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        book_ids,
+        book_tags,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+    command: Arc<CommandSet>,
+) -> crate::Result<Model> {
+    command.invalidate_books(&book_ids, &book_tags);
+
+    // 수정된 제목을 다시 저장해둠, 삭제된 작품은 Library에서 오지 않음
+    if !book_ids.is_empty() {
+        tokio::spawn(async move {
+            let books = match command.get_books_by_ids(book_ids).await {
+                Ok(x) => x,
+                Err(err) => {
+                    log::warn!("failed to refresh book titles: {err}");
+                    return;
+                }
+            };
+
+            let book_titles = books
+                .iter()
+                .map(|book| BookTitle::new(book.id, book.title.clone(), Some(book.created_at)))
+                .collect::<Vec<_>>();
+
+            if let Err(err) = repository.search().add_or_update_titles(book_titles).await {
+                log::warn!("failed to refresh book titles: {err}");
+            }
+        });
+    }
+
+    Ok(Model)
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use super::Payload;
+
+    #[test]
+    fn inject() {
+        let input = r#"
+            {
+                "book_ids": [1, 2],
+                "book_tags": [["female", "loli"]]
+            }"#;
+
+        let payload: Payload = serde_json::from_str(input).unwrap();
+
+        let expected = Payload {
+            book_ids: vec![1, 2],
+            book_tags: vec![("female".to_string(), "loli".to_string())],
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[test]
+    fn default() {
+        let payload: Payload = serde_json::from_str("{}").unwrap();
+
+        let expected = Payload {
+            book_ids: vec![],
+            book_tags: vec![],
+        };
+
+        assert_eq!(payload, expected);
+    }
+}
@@ -0,0 +1 @@
+pub mod invalidate_library_cache;
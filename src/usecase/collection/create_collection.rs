@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{
+    validate::{string, ValidatorStringExt},
+    BodyParser, FromRequest,
+};
+use uuid::Uuid;
+
+use crate::{
+    entity::Collection,
+    error::UseCaseError,
+    model,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub name: String,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+}
+
+impl Payload {
+    pub fn check(self) -> Result<Self, Error> {
+        let name = self
+            .name
+            .validate()
+            .min(1)
+            .max(50)
+            .take()
+            .map_err(Error::InvalidName)?;
+
+        Ok(Self { name, ..self })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = Uuid;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self {
+            user_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub struct Model(pub model::Collection);
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("name: {0}")]
+    InvalidName(string::Error),
+
+    #[error("Already exists collection")]
+    AlreadyExistsCollection,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload { name, user_id }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let collection = Collection::new(user_id, name);
+
+    let saved = repository.collection().add(collection.clone()).await?;
+
+    if !saved {
+        return Err(Error::AlreadyExistsCollection.into());
+    }
+
+    Ok(Model(collection.into()))
+}
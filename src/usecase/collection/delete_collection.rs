@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug)]
+pub struct Payload {
+    pub user_id: Uuid,
+    pub collection_id: Uuid,
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found collection")]
+    NotFoundCollection,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let removed = repository
+        .collection()
+        .remove(user_id, collection_id)
+        .await?;
+
+    if !removed {
+        return Err(Error::NotFoundCollection.into());
+    }
+
+    Ok(Model)
+}
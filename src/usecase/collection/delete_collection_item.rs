@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub book_id: u32,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub collection_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    /// (user_id, collection_id)
+    type Parameter = (Uuid, Uuid);
+
+    async fn from_request(
+        (user_id, collection_id): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self {
+            user_id,
+            collection_id,
+            ..payload
+        })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found collection")]
+    NotFoundCollection,
+
+    #[error("Not found book in collection")]
+    NotFoundCollectionItem,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        book_id,
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let _collection = repository
+        .collection()
+        .get(user_id, collection_id)
+        .await?
+        .ok_or(Error::NotFoundCollection)?;
+
+    let removed = repository
+        .collection()
+        .remove_item(collection_id, book_id)
+        .await?;
+
+    if !removed {
+        return Err(Error::NotFoundCollectionItem.into());
+    }
+
+    Ok(Model)
+}
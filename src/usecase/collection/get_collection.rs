@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    model,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug)]
+pub struct Payload {
+    pub user_id: Uuid,
+    pub collection_id: Uuid,
+}
+
+pub type Model = model::CollectionWithItems;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found collection")]
+    NotFoundCollection,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let collection = repository
+        .collection()
+        .get(user_id, collection_id)
+        .await?
+        .ok_or(Error::NotFoundCollection)?;
+
+    let items = repository.collection().get_items(collection_id).await?;
+
+    Ok(model::CollectionWithItems {
+        collection: collection.into(),
+        items: items.into_iter().map(Into::into).collect(),
+    })
+}
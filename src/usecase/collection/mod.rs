@@ -0,0 +1,8 @@
+pub mod add_collection_item;
+pub mod create_collection;
+pub mod delete_collection;
+pub mod delete_collection_item;
+pub mod get_collection;
+pub mod get_collections;
+pub mod reorder_collection_items;
+pub mod update_collection;
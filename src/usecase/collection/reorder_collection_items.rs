@@ -0,0 +1,92 @@
+use std::{collections::HashSet, sync::Arc};
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    /// 바뀐 순서대로 컬렉션의 모든 작품 id
+    pub book_ids: Vec<u32>,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub collection_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    /// (user_id, collection_id)
+    type Parameter = (Uuid, Uuid);
+
+    async fn from_request(
+        (user_id, collection_id): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self {
+            user_id,
+            collection_id,
+            ..payload
+        })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found collection")]
+    NotFoundCollection,
+
+    #[error("book_ids must contain every book in collection exactly once")]
+    MismatchedItems,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        book_ids,
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let _collection = repository
+        .collection()
+        .get(user_id, collection_id)
+        .await?
+        .ok_or(Error::NotFoundCollection)?;
+
+    let items = repository.collection().get_items(collection_id).await?;
+
+    let exists = items.iter().map(|x| x.book_id).collect::<HashSet<_>>();
+    let requested = book_ids.iter().copied().collect::<HashSet<_>>();
+
+    if book_ids.len() != items.len() || exists != requested {
+        return Err(Error::MismatchedItems.into());
+    }
+
+    repository
+        .collection()
+        .reorder_items(collection_id, book_ids)
+        .await?;
+
+    Ok(Model)
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{
+    validate::{string, ValidatorStringExt},
+    BodyParser, FromRequest,
+};
+use uuid::Uuid;
+
+use crate::{
+    entity::Collection,
+    error::UseCaseError,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub name: String,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub collection_id: Uuid,
+}
+
+impl Payload {
+    pub fn check(self) -> Result<Self, Error> {
+        let name = self
+            .name
+            .validate()
+            .min(1)
+            .max(50)
+            .take()
+            .map_err(Error::InvalidName)?;
+
+        Ok(Self { name, ..self })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    /// (user_id, collection_id)
+    type Parameter = (Uuid, Uuid);
+
+    async fn from_request(
+        (user_id, collection_id): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self {
+            user_id,
+            collection_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("name: {0}")]
+    InvalidName(string::Error),
+
+    #[error("Not found collection")]
+    NotFoundCollection,
+
+    #[error("Already exists collection")]
+    AlreadyExistsCollection,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        name,
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let collection = repository
+        .collection()
+        .get(user_id, collection_id)
+        .await?
+        .ok_or(Error::NotFoundCollection)?;
+
+    let same_name = repository
+        .collection()
+        .get_by_name(user_id, name.clone())
+        .await?;
+
+    if same_name.filter(|x| x.id != collection_id).is_some() {
+        return Err(Error::AlreadyExistsCollection.into());
+    }
+
+    let updated = repository
+        .collection()
+        .update(Collection {
+            name,
+            updated_at: Utc::now(),
+            ..collection
+        })
+        .await?;
+
+    if !updated {
+        return Err(Error::NotFoundCollection.into());
+    }
+
+    Ok(Model)
+}
@@ -1,4 +1,5 @@
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::{BodyParser, FromRequest};
@@ -7,14 +8,15 @@ use uuid::Uuid;
 use crate::{
     entity::fcm_token::FcmToken,
     error::UseCaseError,
-    repository::{r#trait::FcmTokenRepository, RepositorySet},
+    repository::{r#trait::FcmTokenRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct Payload {
     pub udid: Uuid,
     pub fcm_token: String,
     #[serde(default)]
+    #[schemars(skip)]
     pub user_id: Uuid,
 }
 
@@ -50,7 +52,7 @@ pub async fn execute(
         fcm_token,
         user_id,
     }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
     let _r = repository
         .fcm_token()
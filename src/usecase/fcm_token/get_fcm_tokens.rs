@@ -1,4 +1,5 @@
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -6,10 +7,10 @@ use uuid::Uuid;
 use crate::{
     error::UseCaseError,
     payload,
-    repository::{r#trait::FcmTokenRepository, RepositorySet},
+    repository::{r#trait::FcmTokenRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub struct Payload {
     pub user_ids: Vec<Uuid>,
@@ -40,7 +41,7 @@ impl From<Error> for crate::Error {
 
 pub async fn execute(
     Payload { user_ids }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
     let fcm_tokens = repository.fcm_token().get_many(user_ids).await?;
 
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    entity::Follow,
+    error::UseCaseError,
+    repository::{
+        r#trait::{BlockRepository, FollowRepository, UserRepository},
+        Backend, RepositorySet,
+    },
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub followee_id: Uuid,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = Uuid;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self { user_id, ..payload })
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Can't follow yourself")]
+    CannotFollowYourself,
+
+    #[error("Not found user")]
+    NotFoundUser,
+
+    #[error("Already exists follow")]
+    AlreadyExistsFollow,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        followee_id,
+        user_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    if followee_id == user_id {
+        return Err(Error::CannotFollowYourself.into());
+    }
+
+    let followee = repository.user().get(followee_id.to_string()).await?;
+
+    // 차단 관계면 없는 사용자처럼 보임
+    let visible = repository
+        .block()
+        .retain_unblocked(user_id, vec![followee_id], false)
+        .await?;
+
+    if followee.is_none() || visible.is_empty() {
+        return Err(Error::NotFoundUser.into());
+    }
+
+    let saved = repository
+        .follow()
+        .add(Follow::new(user_id, followee_id))
+        .await?;
+
+    if !saved {
+        return Err(Error::AlreadyExistsFollow.into());
+    }
+
+    Ok(Model)
+}
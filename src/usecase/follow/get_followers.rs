@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use hyper::Request;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{validate::ValidatorNumberExt, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    model,
+    payload::{self, follow::FollowSortBy},
+    repository::{r#trait::FollowRepository, Backend, RepositorySet},
+};
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+    pub sort_by: Option<FollowSortBy>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        let per_page = self
+            .per_page
+            .unwrap_or(25)
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(payload::Error::InvalidPerPage)?;
+
+        let page = self
+            .page
+            .unwrap_or(1)
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidPage)?;
+
+        Ok(Self {
+            user_id: self.user_id,
+            per_page: Some(per_page),
+            page: Some(page),
+            sort_by: Some(self.sort_by.unwrap_or(FollowSortBy::CreatedAtDesc)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Parameter = Uuid;
+    type Error = crate::Error;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<hyper::Body>,
+    ) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        Ok(Self {
+            user_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub type Model = Vec<model::Follow>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        per_page,
+        page,
+        sort_by,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let r = repository
+        .follow()
+        .get_followers(
+            user_id,
+            per_page.unwrap(),
+            page.unwrap(),
+            sort_by.unwrap().into(),
+        )
+        .await?;
+
+    // 차단 관계인 사용자는 저장소에서 제외됨
+    Ok(r.into_iter().map(model::Follow::follower).collect())
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use hyper::{Body, Request};
+    use util::ToPayload;
+    use uuid::Uuid;
+
+    use crate::payload::follow::FollowSortBy;
+
+    use super::Payload;
+
+    pub const USER_ID: Uuid = Uuid::nil();
+
+    fn request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn default() {
+        let mut request = request("/");
+
+        let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+        let expected = Payload {
+            per_page: Some(25),
+            page: Some(1),
+            sort_by: Some(FollowSortBy::CreatedAtDesc),
+            user_id: USER_ID,
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[tokio::test]
+    async fn inject() {
+        let mut request = request("/?per-page=5&page=2&sort-by=created-at-asc");
+
+        let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+        let expected = Payload {
+            per_page: Some(5),
+            page: Some(2),
+            sort_by: Some(FollowSortBy::CreatedAtAsc),
+            user_id: USER_ID,
+        };
+
+        assert_eq!(payload, expected);
+    }
+}
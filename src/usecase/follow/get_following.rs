@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use hyper::Request;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{validate::ValidatorNumberExt, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    model,
+    payload::{self, follow::FollowSortBy},
+    repository::{r#trait::FollowRepository, Backend, RepositorySet},
+};
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+    pub sort_by: Option<FollowSortBy>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        let per_page = self
+            .per_page
+            .unwrap_or(25)
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(payload::Error::InvalidPerPage)?;
+
+        let page = self
+            .page
+            .unwrap_or(1)
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidPage)?;
+
+        Ok(Self {
+            user_id: self.user_id,
+            per_page: Some(per_page),
+            page: Some(page),
+            sort_by: Some(self.sort_by.unwrap_or(FollowSortBy::CreatedAtDesc)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Parameter = Uuid;
+    type Error = crate::Error;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<hyper::Body>,
+    ) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        Ok(Self {
+            user_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub type Model = Vec<model::Follow>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        per_page,
+        page,
+        sort_by,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let r = repository
+        .follow()
+        .get_following(
+            user_id,
+            per_page.unwrap(),
+            page.unwrap(),
+            sort_by.unwrap().into(),
+        )
+        .await?;
+
+    // 차단 관계인 사용자는 저장소에서 제외됨
+    Ok(r.into_iter().map(model::Follow::followee).collect())
+}
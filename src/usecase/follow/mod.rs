@@ -0,0 +1,4 @@
+pub mod follow_user;
+pub mod get_followers;
+pub mod get_following;
+pub mod unfollow_user;
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use util::{validate::ValidatorNumberExt, BodyParser, FromRequest};
 use uuid::Uuid;
@@ -10,16 +11,17 @@ use crate::{
     entity::History,
     error::UseCaseError,
     payload,
-    repository::{r#trait::HistoryRepository, RepositorySet},
+    repository::{r#trait::HistoryRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Payload {
     Book {
         book_id: u32,
         page: usize,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
 }
@@ -97,7 +99,7 @@ impl From<Error> for crate::Error {
 
 pub async fn execute(
     p: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
     command: Arc<CommandSet>,
 ) -> crate::Result<Model> {
     use Payload::*;
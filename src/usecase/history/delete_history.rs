@@ -1,4 +1,5 @@
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::{BodyParser, FromRequest};
@@ -7,15 +8,16 @@ use uuid::Uuid;
 use crate::{
     entity::History,
     error::UseCaseError,
-    repository::{r#trait::HistoryRepository, RepositorySet},
+    repository::{r#trait::HistoryRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Payload {
     Book {
         book_id: u32,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
 }
@@ -62,7 +64,10 @@ impl From<Error> for crate::Error {
     }
 }
 
-pub async fn execute(payload: Payload, repository: Arc<RepositorySet>) -> crate::Result<Model> {
+pub async fn execute(
+    payload: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
     let history = match payload {
         Payload::Book { book_id, user_id } => History::book(book_id, 1, user_id),
     };
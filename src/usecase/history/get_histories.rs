@@ -1,29 +1,36 @@
+use chrono::{DateTime, Utc};
 use hyper::Request;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::{validate::ValidatorNumberExt, FromRequest};
 use uuid::Uuid;
 
 use crate::{
+    entity::HistoryFilter,
     error::UseCaseError,
     model,
     payload::{
         self,
         history::{HistoryKind, HistorySortBy},
     },
-    repository::{r#trait::HistoryRepository, RepositorySet},
+    repository::{r#trait::HistoryRepository, Backend, RepositorySet},
 };
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub struct Payload {
     #[serde(default)]
+    #[schemars(skip)]
     pub user_id: Uuid,
     pub kind: Option<HistoryKind>,
     pub per_page: Option<usize>,
     pub page: Option<usize>,
     pub sort_by: Option<HistorySortBy>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
 }
 
 impl Payload {
@@ -45,6 +52,12 @@ impl Payload {
             .take()
             .map_err(payload::Error::InvalidPage)?;
 
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before) {
+            if after >= before {
+                return Err(payload::Error::InvalidDateRange.into());
+            }
+        }
+
         Ok(Self {
             kind: self.kind,
             per_page: Some(per_page),
@@ -93,14 +106,24 @@ pub async fn execute(
         per_page,
         page,
         sort_by,
+        created_after,
+        created_before,
+        updated_after,
     }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
+    let filter = HistoryFilter {
+        created_after,
+        created_before,
+        updated_after,
+    };
+
     let r = repository
         .history()
         .get_many(
             user_id,
             kind.map(Into::into),
+            filter,
             per_page.unwrap(),
             page.unwrap(),
             sort_by.unwrap().into(),
@@ -138,6 +161,9 @@ mod payload_tests {
             sort_by: Some(HistorySortBy::CreatedAtDesc),
             kind: None,
             user_id: USER_ID,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
         };
 
         assert_eq!(payload, expected);
@@ -155,6 +181,30 @@ mod payload_tests {
             sort_by: Some(HistorySortBy::CreatedAtAsc),
             kind: Some(HistoryKind::Book),
             user_id: USER_ID,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[tokio::test]
+    async fn filter() {
+        let mut request =
+            request("/?created-after=2022-10-01T00:00:00Z&updated-after=2022-10-15T00:00:00Z");
+
+        let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+        let expected = Payload {
+            per_page: Some(25),
+            page: Some(1),
+            sort_by: Some(HistorySortBy::UpdatedAtDesc),
+            kind: None,
+            user_id: USER_ID,
+            created_after: Some("2022-10-01T00:00:00Z".parse().unwrap()),
+            created_before: None,
+            updated_after: Some("2022-10-15T00:00:00Z".parse().unwrap()),
         };
 
         assert_eq!(payload, expected);
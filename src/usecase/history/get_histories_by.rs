@@ -1,4 +1,5 @@
 use hyper::Request;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::FromRequest;
@@ -9,16 +10,17 @@ use crate::{
     model, payload,
     repository::{
         r#trait::{HistoryBy, HistoryRepository},
-        RepositorySet,
+        Backend, RepositorySet,
     },
 };
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "kind")]
 pub enum Payload {
     Book {
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
         ids: Vec<u32>,
     },
@@ -64,7 +66,10 @@ impl From<Error> for crate::Error {
     }
 }
 
-pub async fn execute(payload: Payload, repository: Arc<RepositorySet>) -> crate::Result<Model> {
+pub async fn execute(
+    payload: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
     match payload {
         Payload::Book { user_id, ids } => {
             let by = HistoryBy::Book { ids };
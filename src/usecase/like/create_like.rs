@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use util::{BodyParser, FromRequest};
 use uuid::Uuid;
@@ -9,21 +10,25 @@ use crate::{
     command::CommandSet,
     entity::Like,
     error::UseCaseError,
-    repository::{r#trait::LikeRepository, RepositorySet},
+    metrics::Metrics,
+    repository::{r#trait::LikeRepository, Backend, RepositorySet},
+    usecase::create_notifications,
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Payload {
     Book {
         book_id: u32,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
     BookTag {
         tag_kind: String,
         tag_name: String,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
 }
@@ -81,8 +86,9 @@ impl From<Error> for crate::Error {
 
 pub async fn execute(
     p: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
     command: Arc<CommandSet>,
+    metrics: Arc<Metrics>,
 ) -> crate::Result<Model> {
     use Payload::*;
 
@@ -102,6 +108,17 @@ pub async fn execute(
                 return Err(Error::AlreadyExistsLike.into());
             }
 
+            // 팔로워 수만큼 알림을 만들므로 응답을 기다리게 하지 않음,
+            // 알림 실패로 좋아요가 실패하지는 않음
+            let p = create_notifications::Payload::User { user_id, book_id };
+            tokio::spawn(async move {
+                if let Err(err) =
+                    create_notifications::execute(p, repository, command, metrics).await
+                {
+                    log::error!("failed to notify followers: {err}");
+                }
+            });
+
             Ok(Model)
         }
 
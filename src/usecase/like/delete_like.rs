@@ -1,4 +1,5 @@
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::{BodyParser, FromRequest};
@@ -7,21 +8,23 @@ use uuid::Uuid;
 use crate::{
     entity::Like,
     error::UseCaseError,
-    repository::{r#trait::LikeRepository, RepositorySet},
+    repository::{r#trait::LikeRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Payload {
     Book {
         book_id: u32,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
     BookTag {
         tag_kind: String,
         tag_name: String,
         #[serde(default)]
+        #[schemars(skip)]
         user_id: Uuid,
     },
 }
@@ -73,7 +76,10 @@ impl From<Error> for crate::Error {
     }
 }
 
-pub async fn execute(payload: Payload, repository: Arc<RepositorySet>) -> crate::Result<Model> {
+pub async fn execute(
+    payload: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
     let like = match payload {
         Payload::Book { book_id, user_id } => Like::book(user_id, book_id),
 
@@ -1,29 +1,37 @@
+use chrono::{DateTime, Utc};
 use hyper::Request;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::{validate::ValidatorNumberExt, FromRequest};
 use uuid::Uuid;
 
 use crate::{
+    entity::LikeFilter,
     error::UseCaseError,
     model,
     payload::{
         self,
         like::{LikeKind, LikeSortBy},
     },
-    repository::{r#trait::LikeRepository, RepositorySet},
+    repository::{r#trait::LikeRepository, Backend, RepositorySet},
 };
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub struct Payload {
     #[serde(default)]
+    #[schemars(skip)]
     pub user_id: Uuid,
     pub kind: Option<LikeKind>,
     pub per_page: Option<usize>,
     pub page: Option<usize>,
     pub sort_by: Option<LikeSortBy>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub tag_kind: Option<String>,
+    pub tag_name: Option<String>,
 }
 
 impl Payload {
@@ -45,12 +53,17 @@ impl Payload {
             .take()
             .map_err(payload::Error::InvalidPage)?;
 
+        if let (Some(after), Some(before)) = (self.created_after, self.created_before) {
+            if after >= before {
+                return Err(payload::Error::InvalidDateRange.into());
+            }
+        }
+
         Ok(Self {
-            user_id: self.user_id,
-            kind: self.kind,
             per_page: Some(per_page),
             page: Some(page),
             sort_by: Some(self.sort_by.unwrap_or(LikeSortBy::CreatedAtDesc)),
+            ..self
         })
     }
 }
@@ -93,14 +106,26 @@ pub async fn execute(
         per_page,
         page,
         sort_by,
+        created_after,
+        created_before,
+        tag_kind,
+        tag_name,
     }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
+    let filter = LikeFilter {
+        created_after,
+        created_before,
+        tag_kind,
+        tag_name,
+    };
+
     let r = repository
         .like()
         .get_many(
             user_id,
             kind.map(Into::into),
+            filter,
             per_page.unwrap(),
             page.unwrap(),
             sort_by.unwrap().into(),
@@ -138,6 +163,10 @@ mod payload_tests {
             sort_by: Some(LikeSortBy::CreatedAtDesc),
             kind: None,
             user_id: USER_ID,
+            created_after: None,
+            created_before: None,
+            tag_kind: None,
+            tag_name: None,
         };
 
         assert_eq!(payload, expected);
@@ -155,8 +184,60 @@ mod payload_tests {
             sort_by: Some(LikeSortBy::CreatedAtAsc),
             kind: Some(LikeKind::Book),
             user_id: USER_ID,
+            created_after: None,
+            created_before: None,
+            tag_kind: None,
+            tag_name: None,
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[tokio::test]
+    async fn filter() {
+        let mut request = request(
+            "/?kind=book-tag&created-after=2022-10-01T00:00:00Z&created-before=2022-11-01T00:00:00Z&tag-kind=female&tag-name=glasses",
+        );
+
+        let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+        let expected = Payload {
+            per_page: Some(25),
+            page: Some(1),
+            sort_by: Some(LikeSortBy::CreatedAtDesc),
+            kind: Some(LikeKind::BookTag),
+            user_id: USER_ID,
+            created_after: Some("2022-10-01T00:00:00Z".parse().unwrap()),
+            created_before: Some("2022-11-01T00:00:00Z".parse().unwrap()),
+            tag_kind: Some("female".to_string()),
+            tag_name: Some("glasses".to_string()),
         };
 
         assert_eq!(payload, expected);
     }
+
+    #[tokio::test]
+    async fn sort_by_book() {
+        for (sort_by, expected) in [
+            ("title-asc", LikeSortBy::TitleAsc),
+            ("published-at-desc", LikeSortBy::PublishedAtDesc),
+            ("popularity-desc", LikeSortBy::PopularityDesc),
+        ] {
+            let mut request = request(&format!("/?kind=book&sort-by={sort_by}"));
+
+            let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+            assert_eq!(payload.sort_by, Some(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_date_range() {
+        let mut request =
+            request("/?created-after=2022-11-01T00:00:00Z&created-before=2022-10-01T00:00:00Z");
+
+        let r: Result<Payload, _> = request.to_payload(USER_ID).await;
+
+        assert!(r.is_err());
+    }
 }
@@ -1,5 +1,6 @@
 use hyper::Request;
 use itertools::Itertools;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 use util::FromRequest;
@@ -10,22 +11,23 @@ use crate::{
     model, payload,
     repository::{
         r#trait::{LikeBy, LikeRepository},
-        RepositorySet,
+        Backend, RepositorySet,
     },
 };
 
-#[derive(Debug, Clone, Copy, Deserialize)]
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
-enum PayloadKind {
+pub(crate) enum PayloadKind {
     Book,
     BookTag,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
-struct Temp {
+pub(crate) struct Temp {
     kind: PayloadKind,
     #[serde(default)]
+    #[schemars(skip)]
     user_id: Uuid,
     #[serde(default)]
     ids: Option<Vec<u32>>,
@@ -123,7 +125,10 @@ impl From<Error> for crate::Error {
     }
 }
 
-pub async fn execute(payload: Payload, repository: Arc<RepositorySet>) -> crate::Result<Model> {
+pub async fn execute(
+    payload: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
     let user_id = payload.user_id();
 
     match payload {
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::validate::ValidatorNumberExt;
+
+use crate::{
+    error::UseCaseError,
+    model, payload,
+    repository::{r#trait::RelatedBookRepository, Backend, RepositorySet},
+};
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    pub book_id: u32,
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        let book_id = self
+            .book_id
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidBookId)?;
+
+        let per_page = self
+            .per_page
+            .unwrap_or(25)
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(payload::Error::InvalidPerPage)?;
+
+        let page = self
+            .page
+            .unwrap_or(1)
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidPage)?;
+
+        Ok(Self {
+            book_id,
+            per_page: Some(per_page),
+            page: Some(page),
+        })
+    }
+}
+
+impl TryFrom<&mut Request<Body>> for Payload {
+    type Error = crate::Error;
+
+    fn try_from(request: &mut Request<Body>) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        payload.check()
+    }
+}
+
+pub type Model = Vec<model::RelatedBook>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        book_id,
+        per_page,
+        page,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let r = repository
+        .related_book()
+        .get_many(book_id, per_page.unwrap(), page.unwrap())
+        .await?;
+
+    Ok(r.into_iter().map(Into::into).collect())
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use hyper::{Body, Request};
+
+    use super::Payload;
+
+    fn request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn default() {
+        let mut request = request("/?book-id=3");
+
+        let payload = Payload::try_from(&mut request).unwrap();
+
+        let expected = Payload {
+            book_id: 3,
+            per_page: Some(25),
+            page: Some(1),
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[test]
+    fn missing_book_id() {
+        let mut request = request("/?per-page=3");
+
+        assert!(Payload::try_from(&mut request).is_err());
+    }
+}
@@ -2,4 +2,5 @@ pub mod create_like;
 pub mod delete_like;
 pub mod get_likes;
 pub mod get_likes_by;
+pub mod get_related_books;
 // pub mod get_likes_from_book_tags;
@@ -1,11 +1,27 @@
+mod block;
+mod cache;
+mod collection;
 mod fcm_token;
+mod follow;
 mod history;
 mod like;
 mod notification;
+mod rating;
+mod recommendation;
+mod search;
+mod share;
 mod user;
 
+pub use block::*;
+pub use cache::*;
+pub use collection::*;
 pub use fcm_token::*;
+pub use follow::*;
 pub use history::*;
 pub use like::*;
 pub use notification::*;
+pub use rating::*;
+pub use recommendation::*;
+pub use search::*;
+pub use share::*;
 pub use user::*;
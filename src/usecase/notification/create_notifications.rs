@@ -1,25 +1,51 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::{collections::BTreeMap, sync::Arc};
 use uuid::Uuid;
 
 use crate::{
     command::CommandSet,
-    entity::{Notification, NotificationKind},
+    entity::{BookTitle, Notification, NotificationKind, Visibility},
     error::UseCaseError,
+    metrics::Metrics,
     model::Like,
-    repository::{r#trait::NotificationRepository, RepositorySet},
+    repository::{
+        r#trait::{
+            BlockRepository, FollowRepository, HistoryRepository, NotificationRepository,
+            ProfileRepository, SearchRepository,
+        },
+        Backend, RepositorySet,
+    },
     usecase::get_likes_by,
 };
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Payload {
     Book {
         book_id: u32,
         book_title: String,
         book_tags: Vec<(String, String)>,
+        /// 시리즈물이면 같은 시리즈의 이전 작품들
+        #[serde(default)]
+        book_series: Option<BookSeries>,
+        /// 없으면 알림을 받은 시각을 올라온 날짜로 씀
+        #[serde(default)]
+        book_published_at: Option<DateTime<Utc>>,
     },
+    /// 좋아요가 추가될 때 내부에서만 사용함
+    #[serde(skip_deserializing)]
+    #[schemars(skip)]
+    User { user_id: Uuid, book_id: u32 },
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BookSeries {
+    pub name: String,
+    pub book_ids: Vec<u32>,
 }
 
 pub struct Model;
@@ -34,19 +60,55 @@ impl From<Error> for crate::Error {
 }
 
 /// Book: Library 서버에서 작품이 업로드될때마다 해당 api에 작품 id와 타이틀, 태그를 전달함
+///
+/// 시리즈 정보가 있으면 같은 시리즈의 작품을 읽은 사용자들에게도 알림
+///
+/// User: 공개 프로필인 사용자가 작품을 좋아하면 팔로워들에게 알림
 pub async fn execute(
     p: Payload,
-    repository: Arc<RepositorySet>,
-    #[allow(unused_variables)] command: Arc<CommandSet>,
+    repository: Arc<RepositorySet<impl Backend>>,
+    command: Arc<CommandSet>,
+    metrics: Arc<Metrics>,
 ) -> crate::Result<Model> {
     match p {
         Payload::Book {
             book_tags,
             book_id,
             book_title,
+            book_series,
+            book_published_at,
         } => {
-            #[allow(unused_variables)]
-            let book_title = book_title;
+            if let Some(book_series) = book_series {
+                notify_book_series(
+                    book_id,
+                    &book_title,
+                    book_series,
+                    repository.clone(),
+                    command.clone(),
+                    &metrics,
+                )
+                .await?;
+            }
+
+            // 검색하거나 정렬할 때 쓰려고 제목과 올라온 날짜를 저장해둠
+            repository
+                .search()
+                .add_or_update_title(BookTitle::new(
+                    book_id,
+                    book_title.clone(),
+                    book_published_at,
+                ))
+                .await?;
+
+            // Library에서 이미 없어진 태그로는 알리지 않음
+            // 있는 태그는 캐싱되므로 좋아요할 때 다시 확인하지 않아도 됨
+            let book_tags = match command.has_book_tags(book_tags.clone()).await {
+                Ok(has) => book_tags.into_iter().filter(|x| has.contains(x)).collect(),
+                Err(err) => {
+                    log::warn!("library is unavailable, notify without checking tags: {err}");
+                    book_tags
+                }
+            };
 
             let p = get_likes_by::Payload::BookTag {
                 tags: book_tags,
@@ -80,6 +142,8 @@ pub async fn execute(
                 })
                 .collect::<Vec<_>>();
 
+            metrics.observe_fan_out(NotificationKind::Book, notifications.len());
+
             let _r = repository
                 .notification()
                 .add_many(NotificationKind::Book, notifications.clone())
@@ -110,14 +174,142 @@ pub async fn execute(
 
             Ok(Model)
         }
+
+        Payload::User { user_id, book_id } => {
+            let public = repository
+                .profile()
+                .get(user_id)
+                .await?
+                .map(|x| x.visibility == Visibility::Public)
+                .unwrap_or(false);
+
+            if !public {
+                return Ok(Model);
+            }
+
+            let follower_ids = repository.follow().get_follower_ids(user_id).await?;
+
+            // 차단, 뮤트한 팔로워에게는 알림을 보내지 않음
+            let follower_ids = repository
+                .block()
+                .retain_unblocked(user_id, follower_ids, true)
+                .await?;
+
+            let notifications = follower_ids
+                .into_iter()
+                .map(|follower_id| Notification::user(follower_id, user_id, book_id))
+                .collect::<Vec<_>>();
+
+            metrics.observe_fan_out(NotificationKind::User, notifications.len());
+
+            let _r = repository
+                .notification()
+                .add_many(NotificationKind::User, notifications.clone())
+                .await?;
+
+            #[cfg(feature = "fcm")]
+            {
+                use crate::{
+                    command::send_notification::Message, repository::r#trait::UserRepository,
+                    usecase::get_fcm_tokens,
+                };
+
+                let user_ids = notifications.iter().map(|x| x.user_id()).collect();
+
+                let p = get_fcm_tokens::Payload { user_ids };
+                let fcm_tokens = get_fcm_tokens::execute(p, repository.clone())
+                    .await
+                    .map(|x| x.0);
+
+                let name = repository
+                    .user()
+                    .get(user_id.to_string())
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(|x| x.name)
+                    .unwrap_or_default();
+
+                if let Ok(fcm_tokens) = fcm_tokens {
+                    command
+                        .send_notification(
+                            fcm_tokens,
+                            Message::new(
+                                "팔로우한 사용자가 작품을 좋아해요.",
+                                format!("{name}님이 좋아한 작품을 확인해보세요."),
+                            ),
+                        )
+                        .await
+                        .ok();
+                }
+            }
+
+            Ok(Model)
+        }
+    }
+}
+
+async fn notify_book_series(
+    book_id: u32,
+    #[allow(unused_variables)] book_title: &str,
+    BookSeries { name, book_ids }: BookSeries,
+    repository: Arc<RepositorySet<impl Backend>>,
+    command: Arc<CommandSet>,
+    metrics: &Metrics,
+) -> crate::Result<()> {
+    // 시리즈에서 삭제된 작품의 독자에게는 알리지 않음
+    let book_ids = match command.has_books(book_ids.clone()).await {
+        Ok(has) => book_ids.into_iter().filter(|x| has.contains(x)).collect(),
+        Err(err) => {
+            log::warn!("library is unavailable, notify without checking series: {err}");
+            book_ids
+        }
+    };
+
+    let reader_ids = repository.history().get_reader_ids(book_ids).await?;
+
+    let notifications = reader_ids
+        .into_iter()
+        .map(|user_id| Notification::book_series(user_id, book_id, name.clone()))
+        .collect::<Vec<_>>();
+
+    metrics.observe_fan_out(NotificationKind::BookSeries, notifications.len());
+
+    repository
+        .notification()
+        .add_many(NotificationKind::BookSeries, notifications.clone())
+        .await?;
+
+    #[cfg(feature = "fcm")]
+    {
+        use crate::{command::send_notification::Message, usecase::get_fcm_tokens};
+
+        let user_ids = notifications.iter().map(|x| x.user_id()).collect();
+
+        let p = get_fcm_tokens::Payload { user_ids };
+        let fcm_tokens = get_fcm_tokens::execute(p, repository.clone())
+            .await
+            .map(|x| x.0);
+
+        if let Ok(fcm_tokens) = fcm_tokens {
+            command
+                .send_notification(
+                    fcm_tokens,
+                    Message::new(format!("{name}의 새 작품이 올라왔어요."), book_title),
+                )
+                .await
+                .ok();
+        }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod payload_tests {
     // use crate::payload::notification::NotificationBook;
 
-    use super::Payload;
+    use super::{BookSeries, Payload};
 
     #[tokio::test]
     async fn inject_book() {
@@ -175,6 +367,39 @@ mod payload_tests {
                 ("female".to_string(), "loli".to_string()),
                 ("female".to_string(), "rape".to_string()),
             ],
+            book_series: None,
+            book_published_at: None,
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[tokio::test]
+    async fn inject_book_with_series() {
+        let input = r#"
+            {
+                "kind": "book",
+                "book_id": 3,
+                "book_title": "Series 3",
+                "book_tags": [],
+                "book_published_at": "2022-10-27T00:00:00Z",
+                "book_series": {
+                    "name": "series",
+                    "book_ids": [1, 2]
+                }
+            }"#;
+
+        let payload: Payload = serde_json::from_str(input).unwrap();
+
+        let expected = Payload::Book {
+            book_id: 3,
+            book_title: "Series 3".to_string(),
+            book_tags: vec![],
+            book_series: Some(BookSeries {
+                name: "series".to_string(),
+                book_ids: vec![1, 2],
+            }),
+            book_published_at: Some("2022-10-27T00:00:00Z".parse().unwrap()),
         };
 
         assert_eq!(payload, expected);
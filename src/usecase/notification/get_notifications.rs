@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use hyper::{Body, Request};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use util::{validate::ValidatorNumberExt, FromRequest};
 use uuid::Uuid;
@@ -12,14 +13,15 @@ use crate::{
         self,
         notification::{NotificationKind, NotificationSortBy},
     },
-    repository::{r#trait::NotificationRepository, RepositorySet},
+    repository::{r#trait::NotificationRepository, Backend, RepositorySet},
 };
 
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub struct Payload {
     #[serde(default)]
+    #[schemars(skip)]
     pub user_id: Uuid,
     pub kind: Option<NotificationKind>,
     pub per_page: Option<usize>,
@@ -95,7 +97,7 @@ pub async fn execute(
         page,
         sort_by,
     }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
     let notifications = repository
         .notification()
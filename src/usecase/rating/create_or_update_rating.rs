@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{
+    validate::{number, string, ValidatorNumberExt, ValidatorStringExt},
+    BodyParser, FromRequest,
+};
+use uuid::Uuid;
+
+use crate::{
+    command::CommandSet,
+    entity::Rating,
+    error::UseCaseError,
+    payload,
+    repository::{r#trait::RatingRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub book_id: u32,
+    pub score: u8,
+    pub note: Option<String>,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+}
+
+impl Payload {
+    fn check(self) -> crate::Result<Self> {
+        let book_id = self
+            .book_id
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidBookId)?;
+
+        let score = self
+            .score
+            .validate()
+            .min(1)
+            .max(5)
+            .take()
+            .map_err(Error::InvalidScore)?;
+
+        // 빈 문자열은 메모를 지우는 것으로 취급함
+        let note = match self.note.filter(|x| !x.is_empty()) {
+            Some(note) => Some(
+                note.validate()
+                    .max(1000)
+                    .take()
+                    .map_err(Error::InvalidNote)?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            book_id,
+            score,
+            note,
+            user_id: self.user_id,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = Uuid;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Self { user_id, ..payload }.check()
+    }
+}
+
+pub struct Model;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("score: {0}")]
+    InvalidScore(number::Error<u8>),
+    #[error("note: {0}")]
+    InvalidNote(string::Error),
+
+    #[error("Not found book in library")]
+    NotFoundBook,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        book_id,
+        score,
+        note,
+        user_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+    command: Arc<CommandSet>,
+) -> crate::Result<Model> {
+    let has_book = command.has_book(book_id).await?;
+
+    if !has_book {
+        return Err(Error::NotFoundBook.into());
+    }
+
+    repository
+        .rating()
+        .add_or_update(Rating::new(user_id, book_id, score, note))
+        .await?;
+
+    Ok(Model)
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::validate::ValidatorNumberExt;
+
+use crate::{
+    error::UseCaseError,
+    model, payload,
+    repository::{r#trait::RatingRepository, Backend, RepositorySet},
+};
+
+/// 한 번에 가져올 수 있는 작품 수
+const MAX_BOOK_IDS: usize = 100;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    /// 최대 100개
+    pub book_ids: Vec<u32>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        self.book_ids
+            .len()
+            .validate()
+            .max(MAX_BOOK_IDS)
+            .take()
+            .map_err(payload::Error::InvalidBookIds)?;
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<&mut Request<Body>> for Payload {
+    type Error = crate::Error;
+
+    fn try_from(request: &mut Request<Body>) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        payload.check()
+    }
+}
+
+pub type Model = Vec<model::RatingAggregate>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload { book_ids }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let r = repository.rating().get_aggregates(book_ids).await?;
+
+    Ok(r.into_iter().map(Into::into).collect())
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use hyper::{Body, Request};
+
+    use super::{Payload, MAX_BOOK_IDS};
+
+    fn request(book_ids: impl Iterator<Item = u32>) -> Request<Body> {
+        let qs = book_ids
+            .enumerate()
+            .map(|(i, book_id)| format!("book-ids[{i}]={book_id}"))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        Request::builder()
+            .uri(format!("/?{qs}"))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn inject() {
+        let mut request = request(1..=3);
+
+        let payload = Payload::try_from(&mut request).unwrap();
+
+        assert_eq!(payload.book_ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn too_many_book_ids() {
+        let mut request = request(1..=MAX_BOOK_IDS as u32 + 1);
+
+        assert!(Payload::try_from(&mut request).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_or_update_rating;
+pub mod delete_rating;
+pub mod get_rating_aggregates;
+pub mod get_ratings;
@@ -0,0 +1,551 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    sync::Arc,
+};
+
+use hyper::Request;
+use madome_sdk::api::library;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{validate::ValidatorNumberExt, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    command::CommandSet,
+    entity::{Dislike, History, HistorySortBy, Like, LikeSortBy, Sort},
+    error::UseCaseError,
+    model::{self, Reason},
+    payload,
+    repository::{
+        r#trait::{
+            DislikeBy, DislikeRepository, HistoryBy, HistoryRepository, LikeBy, LikeRepository,
+        },
+        Backend, RepositorySet,
+    },
+};
+
+/// 좋아요한 태그
+const LIKED_BOOK_TAG_WEIGHT: u32 = 3;
+/// 좋아요한 작품의 태그
+const LIKED_BOOK_WEIGHT: u32 = 2;
+/// 읽은 작품의 태그
+const READ_BOOK_WEIGHT: u32 = 1;
+
+/// 후보 작품을 가져올 태그 수
+const PROFILE_SIZE: usize = 5;
+/// 태그마다 가져올 후보 작품 수
+const CANDIDATES_PER_TAG: usize = 20;
+/// 점수를 매길 때 좋아요, 열람 기록, 싫어요는 최근 것부터 이만큼만 사용함
+///
+/// 이미 보거나 싫어요한 작품을 제외할 때는 전부 확인함
+const SIGNALS_LIMIT: usize = 100;
+
+type Tag = (String, String);
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        let per_page = self
+            .per_page
+            .unwrap_or(25)
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(payload::Error::InvalidPerPage)?;
+
+        let page = self
+            .page
+            .unwrap_or(1)
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidPage)?;
+
+        Ok(Self {
+            user_id: self.user_id,
+            per_page: Some(per_page),
+            page: Some(page),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Parameter = Uuid;
+    type Error = crate::Error;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<hyper::Body>,
+    ) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        Ok(Self {
+            user_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub type Model = Vec<model::Recommendation>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+/// 추천에 사용하는 사용자의 활동
+#[derive(Debug, Default)]
+pub struct Signals {
+    pub liked_book_tags: Vec<Tag>,
+    /// (book_id, tags)
+    pub liked_books: Vec<(u32, Vec<Tag>)>,
+    /// (book_id, tags)
+    pub read_books: Vec<(u32, Vec<Tag>)>,
+    pub disliked_book_tags: HashSet<Tag>,
+    pub disliked_book_ids: HashSet<u32>,
+}
+
+impl Signals {
+    /// 추천에서 제외할 작품 (좋아요, 읽음, 싫어요)
+    pub fn excluded_book_ids(&self) -> HashSet<u32> {
+        self.liked_books
+            .iter()
+            .chain(self.read_books.iter())
+            .map(|(book_id, _)| *book_id)
+            .chain(self.disliked_book_ids.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug)]
+pub struct Weight {
+    pub score: u32,
+    /// 가장 큰 가중치를 준 활동
+    pub reason: Reason,
+    reason_weight: u32,
+}
+
+/// 태그별 가중치를 계산하고 가중치가 큰 순서로 `PROFILE_SIZE`개만 남김
+///
+/// 가중치가 같으면 태그 이름 순서
+pub fn tag_profile(signals: &Signals) -> Vec<(Tag, Weight)> {
+    let liked_book_tags = signals.liked_book_tags.iter().map(|tag| {
+        let reason = Reason::LikedBookTag {
+            tag_kind: tag.0.clone(),
+            tag_name: tag.1.clone(),
+        };
+
+        (tag, LIKED_BOOK_TAG_WEIGHT, reason)
+    });
+
+    let liked_books = signals.liked_books.iter().flat_map(|(book_id, tags)| {
+        tags.iter().map(|tag| {
+            let reason = Reason::LikedBook { book_id: *book_id };
+
+            (tag, LIKED_BOOK_WEIGHT, reason)
+        })
+    });
+
+    let read_books = signals.read_books.iter().flat_map(|(book_id, tags)| {
+        tags.iter().map(|tag| {
+            let reason = Reason::ReadBook { book_id: *book_id };
+
+            (tag, READ_BOOK_WEIGHT, reason)
+        })
+    });
+
+    let mut profile = BTreeMap::<&Tag, Weight>::new();
+
+    for (tag, weight, reason) in liked_book_tags.chain(liked_books).chain(read_books) {
+        if signals.disliked_book_tags.contains(tag) {
+            continue;
+        }
+
+        let exists = profile.entry(tag).or_insert(Weight {
+            score: 0,
+            reason: reason.clone(),
+            reason_weight: weight,
+        });
+
+        exists.score += weight;
+
+        // 같은 가중치면 먼저 나온 활동(최근 활동)을 이유로 유지함
+        if weight > exists.reason_weight {
+            exists.reason = reason;
+            exists.reason_weight = weight;
+        }
+    }
+
+    let mut profile = profile
+        .into_iter()
+        .map(|(tag, weight)| (tag.clone(), weight))
+        .collect::<Vec<_>>();
+
+    // BTreeMap이라 이미 태그 순서로 정렬되어 있으므로 stable sort로 점수만 정렬함
+    profile.sort_by(|(_, a), (_, b)| b.score.cmp(&a.score));
+    profile.truncate(PROFILE_SIZE);
+
+    profile
+}
+
+/// 후보 작품에 점수를 매기고 점수 내림차순, 같은 점수면 book_id 내림차순으로 정렬함
+///
+/// `candidates`는 (book_id, tags)
+pub fn rank(
+    profile: &[(Tag, Weight)],
+    candidates: Vec<(u32, Vec<Tag>)>,
+    signals: &Signals,
+) -> Vec<(u32, u32, Reason)> {
+    let excluded = signals.excluded_book_ids();
+
+    let mut merged = BTreeMap::<u32, BTreeSet<Tag>>::new();
+
+    for (book_id, tags) in candidates {
+        merged.entry(book_id).or_default().extend(tags);
+    }
+
+    let mut ranked = merged
+        .into_iter()
+        .filter(|(book_id, _)| !excluded.contains(book_id))
+        .filter(|(_, tags)| !tags.iter().any(|x| signals.disliked_book_tags.contains(x)))
+        .filter_map(|(book_id, tags)| {
+            let matched = profile
+                .iter()
+                .filter(|(tag, _)| tags.contains(tag))
+                .collect::<Vec<_>>();
+
+            // profile은 점수 내림차순이므로 첫번째가 가장 큰 이유
+            let (_, best) = matched.first()?;
+            let score = matched.iter().map(|(_, weight)| weight.score).sum();
+
+            Some((book_id, score, best.reason.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    ranked.sort_by(|(a_id, a_score, _), (b_id, b_score, _)| {
+        b_score.cmp(a_score).then(b_id.cmp(a_id))
+    });
+
+    ranked
+}
+
+/// (tag_kind, tag_name)
+fn tags_of(book: &library::model::Book) -> Vec<Tag> {
+    book.tags.clone()
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        per_page,
+        page,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+    command: Arc<CommandSet>,
+) -> crate::Result<Model> {
+    let (per_page, page) = (per_page.unwrap(), page.unwrap());
+
+    let likes = repository
+        .like()
+        .get_many(
+            user_id,
+            None,
+            Default::default(),
+            SIGNALS_LIMIT,
+            1,
+            LikeSortBy::CreatedAt(Sort::Desc),
+        )
+        .await?;
+
+    let histories = repository
+        .history()
+        .get_many(
+            user_id,
+            None,
+            Default::default(),
+            SIGNALS_LIMIT,
+            1,
+            HistorySortBy::UpdatedAt(Sort::Desc),
+        )
+        .await?;
+
+    let dislikes = repository
+        .dislike()
+        .get_many(user_id, None, SIGNALS_LIMIT, 1, Default::default())
+        .await?;
+
+    let mut liked_book_ids = Vec::new();
+    let mut liked_book_tags = Vec::new();
+
+    for like in likes {
+        match like {
+            Like::Book { book_id, .. } => liked_book_ids.push(book_id),
+            Like::BookTag {
+                tag_kind, tag_name, ..
+            } => liked_book_tags.push((tag_kind, tag_name)),
+        }
+    }
+
+    let read_book_ids = histories
+        .into_iter()
+        .map(|History::Book { book_id, .. }| book_id)
+        .collect::<Vec<_>>();
+
+    let mut disliked_book_ids = HashSet::new();
+    let mut disliked_book_tags = HashSet::new();
+
+    for dislike in dislikes {
+        match dislike {
+            Dislike::Book { book_id, .. } => {
+                disliked_book_ids.insert(book_id);
+            }
+            Dislike::BookTag {
+                tag_kind, tag_name, ..
+            } => {
+                disliked_book_tags.insert((tag_kind, tag_name));
+            }
+        }
+    }
+
+    let book_ids = liked_book_ids
+        .iter()
+        .chain(read_book_ids.iter())
+        .copied()
+        .collect::<BTreeSet<_>>();
+
+    let tags_by_book = command
+        .get_books_by_ids(book_ids.into_iter().collect())
+        .await?
+        .iter()
+        .map(|book| (book.id, tags_of(book)))
+        .collect::<HashMap<_, _>>();
+
+    let with_tags = |book_ids: Vec<u32>| {
+        book_ids
+            .into_iter()
+            .map(|book_id| {
+                let tags = tags_by_book.get(&book_id).cloned().unwrap_or_default();
+
+                (book_id, tags)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let signals = Signals {
+        liked_book_tags,
+        liked_books: with_tags(liked_book_ids),
+        read_books: with_tags(read_book_ids),
+        disliked_book_tags,
+        disliked_book_ids,
+    };
+
+    let profile = tag_profile(&signals);
+
+    if profile.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // 항상 같은 결과가 나오도록 id 내림차순으로 가져옴
+    let books_by_tags = command
+        .get_books_by_tags(
+            profile.iter().map(|(tag, _)| tag.clone()).collect(),
+            CANDIDATES_PER_TAG,
+            1,
+            Some(library::payload::BookSortBy::IdDesc),
+        )
+        .await?;
+
+    let mut books = HashMap::new();
+    let mut candidates = Vec::new();
+
+    for (tag, xs) in books_by_tags {
+        for book in xs {
+            let mut tags = tags_of(&book);
+            tags.push(tag.clone());
+
+            candidates.push((book.id, tags));
+            books.insert(book.id, book);
+        }
+    }
+
+    if books.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // signals에는 최근 것만 있으므로 제외할 작품은 저장소에서 다시 확인함
+    let candidate_ids = books.keys().copied().collect::<Vec<_>>();
+
+    let liked = repository
+        .like()
+        .get_many_by(
+            Some(user_id),
+            LikeBy::Book {
+                ids: candidate_ids.clone(),
+            },
+        )
+        .await?;
+
+    let disliked = repository
+        .dislike()
+        .get_many_by(
+            user_id,
+            DislikeBy::Book {
+                ids: candidate_ids.clone(),
+            },
+        )
+        .await?;
+
+    let read = repository
+        .history()
+        .get_many_by(user_id, HistoryBy::Book { ids: candidate_ids })
+        .await?;
+
+    let seen = liked
+        .into_iter()
+        .filter_map(|x| match x {
+            Like::Book { book_id, .. } => Some(book_id),
+            Like::BookTag { .. } => None,
+        })
+        .chain(disliked.into_iter().filter_map(|x| match x {
+            Dislike::Book { book_id, .. } => Some(book_id),
+            Dislike::BookTag { .. } => None,
+        }))
+        .chain(
+            read.into_iter()
+                .map(|History::Book { book_id, .. }| book_id),
+        )
+        .collect::<HashSet<_>>();
+
+    let candidates = candidates
+        .into_iter()
+        .filter(|(book_id, _)| !seen.contains(book_id))
+        .collect();
+
+    let recommendations = rank(&profile, candidates, &signals)
+        .into_iter()
+        .skip(per_page * (page - 1))
+        .take(per_page)
+        .filter_map(|(book_id, score, reason)| {
+            let book = books.remove(&book_id)?;
+
+            Some(model::Recommendation {
+                book_id,
+                score,
+                reason,
+                book,
+            })
+        })
+        .collect();
+
+    Ok(recommendations)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::model::Reason;
+
+    use super::{rank, tag_profile, Signals, Tag};
+
+    fn tag(kind: &str, name: &str) -> Tag {
+        (kind.to_string(), name.to_string())
+    }
+
+    fn signals() -> Signals {
+        Signals {
+            liked_book_tags: vec![tag("artist", "a")],
+            liked_books: vec![(1, vec![tag("artist", "a"), tag("series", "s")])],
+            read_books: vec![
+                (2, vec![tag("series", "s"), tag("female", "f")]),
+                (3, vec![tag("male", "m")]),
+            ],
+            disliked_book_tags: HashSet::from([tag("male", "m")]),
+            disliked_book_ids: HashSet::from([9]),
+        }
+    }
+
+    #[test]
+    fn weighted_tag_profile() {
+        let profile = tag_profile(&signals())
+            .into_iter()
+            .map(|(tag, weight)| (tag, weight.score, weight.reason))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (
+                tag("artist", "a"),
+                5,
+                Reason::LikedBookTag {
+                    tag_kind: "artist".to_string(),
+                    tag_name: "a".to_string(),
+                },
+            ),
+            (tag("series", "s"), 3, Reason::LikedBook { book_id: 1 }),
+            (tag("female", "f"), 1, Reason::ReadBook { book_id: 2 }),
+        ];
+
+        assert_eq!(profile, expected);
+    }
+
+    #[test]
+    fn rank_candidates() {
+        let signals = signals();
+        let profile = tag_profile(&signals);
+
+        // stubbed library
+        let candidates = vec![
+            // liked
+            (1, vec![tag("artist", "a")]),
+            // read
+            (2, vec![tag("series", "s")]),
+            // disliked
+            (9, vec![tag("artist", "a")]),
+            // has disliked tag
+            (10, vec![tag("artist", "a"), tag("male", "m")]),
+            (11, vec![tag("female", "f")]),
+            (12, vec![tag("series", "s")]),
+            (13, vec![tag("series", "s")]),
+            (14, vec![tag("artist", "a")]),
+            // found by two tags
+            (14, vec![tag("female", "f")]),
+        ];
+
+        let r = rank(&profile, candidates, &signals);
+
+        let expected = vec![
+            (
+                14,
+                6,
+                Reason::LikedBookTag {
+                    tag_kind: "artist".to_string(),
+                    tag_name: "a".to_string(),
+                },
+            ),
+            (13, 3, Reason::LikedBook { book_id: 1 }),
+            (12, 3, Reason::LikedBook { book_id: 1 }),
+            (11, 1, Reason::ReadBook { book_id: 2 }),
+        ];
+
+        assert_eq!(r, expected);
+    }
+}
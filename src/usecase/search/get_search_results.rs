@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{
+    validate::{string, ValidatorNumberExt, ValidatorStringExt},
+    FromRequest,
+};
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    model, payload,
+    repository::{r#trait::SearchRepository, Backend, RepositorySet},
+};
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct Payload {
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    pub q: String,
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+}
+
+impl Payload {
+    pub fn check(self) -> crate::Result<Self> {
+        let q = self
+            .q
+            .trim()
+            .to_string()
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(Error::InvalidQuery)?;
+
+        let per_page = self
+            .per_page
+            .unwrap_or(25)
+            .validate()
+            .min(1)
+            .max(100)
+            .take()
+            .map_err(payload::Error::InvalidPerPage)?;
+
+        let page = self
+            .page
+            .unwrap_or(1)
+            .validate()
+            .min(1)
+            .take()
+            .map_err(payload::Error::InvalidPage)?;
+
+        Ok(Self {
+            user_id: self.user_id,
+            q,
+            per_page: Some(per_page),
+            page: Some(page),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Parameter = Uuid;
+    type Error = crate::Error;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let qs = request.uri().query().unwrap_or_default();
+        let payload: Self =
+            serde_qs::from_str(qs).map_err(payload::Error::QuerystringDeserialize)?;
+
+        Ok(Self {
+            user_id,
+            ..payload.check()?
+        })
+    }
+}
+
+pub type Model = Vec<model::SearchResult>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("q: {0}")]
+    InvalidQuery(string::Error),
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        user_id,
+        q,
+        per_page,
+        page,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let r = repository
+        .search()
+        .search(user_id, q, per_page.unwrap(), page.unwrap())
+        .await?;
+
+    Ok(r.into_iter().map(Into::into).collect())
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use hyper::{Body, Request};
+    use util::ToPayload;
+    use uuid::Uuid;
+
+    use super::Payload;
+
+    pub const USER_ID: Uuid = Uuid::nil();
+
+    fn request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn default() {
+        let mut request = request("/?q=%20big%20breasts%20");
+
+        let payload: Payload = request.to_payload(USER_ID).await.unwrap();
+
+        let expected = Payload {
+            user_id: USER_ID,
+            q: "big breasts".to_string(),
+            per_page: Some(25),
+            page: Some(1),
+        };
+
+        assert_eq!(payload, expected);
+    }
+
+    #[tokio::test]
+    async fn empty_query() {
+        let mut request = request("/?q=%20");
+
+        let r: Result<Payload, _> = request.to_payload(USER_ID).await;
+
+        assert!(r.is_err());
+    }
+}
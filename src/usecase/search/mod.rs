@@ -0,0 +1 @@
+pub mod get_search_results;
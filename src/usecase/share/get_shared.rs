@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use hyper::{Body, Request};
+use util::FromRequest;
+use uuid::Uuid;
+
+use crate::{
+    error::UseCaseError,
+    model,
+    repository::{
+        r#trait::{BlockRepository, CollectionRepository, ProfileRepository, UserRepository},
+        Backend, RepositorySet,
+    },
+    usecase::get_likes,
+};
+
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Debug)]
+pub struct Payload {
+    pub share_token: String,
+    /// 로그인한 사용자가 보는 경우
+    pub viewer_id: Option<Uuid>,
+    /// 프로필일 때 좋아요 목록을 가져오는 옵션
+    pub likes: get_likes::Payload,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = (String, Option<Uuid>);
+
+    async fn from_request(
+        (share_token, viewer_id): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        // user_id는 공유 토큰으로 찾은 프로필에서 정해짐
+        let likes = get_likes::Payload::from_request(Uuid::nil(), request).await?;
+
+        Ok(Self {
+            share_token,
+            viewer_id,
+            likes,
+        })
+    }
+}
+
+pub type Model = model::Shared;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found shared")]
+    NotFoundShared,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+/// 공유한 사용자와 차단 관계인 사용자에게는 없는 것처럼 보임
+async fn check_blocked(
+    owner_id: Uuid,
+    viewer_id: Option<Uuid>,
+    repository: &RepositorySet<impl Backend>,
+) -> crate::Result<()> {
+    if let Some(viewer_id) = viewer_id {
+        let visible = repository
+            .block()
+            .retain_unblocked(owner_id, vec![viewer_id], false)
+            .await?;
+
+        if visible.is_empty() {
+            return Err(Error::NotFoundShared.into());
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn execute(
+    Payload {
+        share_token,
+        viewer_id,
+        likes,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let maybe_collection = repository
+        .collection()
+        .get_by_share_token(share_token.clone())
+        .await?;
+
+    if let Some(collection) = maybe_collection {
+        check_blocked(collection.user_id, viewer_id, &repository).await?;
+
+        let items = repository.collection().get_items(collection.id).await?;
+
+        return Ok(model::Shared::Collection(model::CollectionWithItems {
+            collection: collection.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        }));
+    }
+
+    let profile = repository
+        .profile()
+        .get_by_share_token(share_token)
+        .await?
+        .ok_or(Error::NotFoundShared)?;
+
+    check_blocked(profile.user_id, viewer_id, &repository).await?;
+
+    let user = repository
+        .user()
+        .get(profile.user_id.to_string())
+        .await?
+        .ok_or(Error::NotFoundShared)?;
+
+    let collections = repository
+        .collection()
+        .get_many_public(profile.user_id)
+        .await?;
+
+    let likes = get_likes::execute(
+        get_likes::Payload {
+            user_id: profile.user_id,
+            ..likes
+        },
+        repository,
+    )
+    .await?;
+
+    Ok(model::Shared::Profile(model::SharedProfile {
+        name: user.name,
+        likes,
+        collections: collections.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod payload_tests {
+    use hyper::{Body, Request};
+    use util::ToPayload;
+    use uuid::Uuid;
+
+    use crate::{payload::like::LikeSortBy, usecase::get_likes};
+
+    use super::Payload;
+
+    fn request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn likes_option() {
+        let mut request = request("/?per-page=5&sort-by=created-at-asc");
+
+        let payload: Payload = request
+            .to_payload(("token".to_string(), None))
+            .await
+            .unwrap();
+
+        let expected = Payload {
+            share_token: "token".to_string(),
+            viewer_id: None,
+            likes: get_likes::Payload {
+                user_id: Uuid::nil(),
+                kind: None,
+                per_page: Some(5),
+                page: Some(1),
+                sort_by: Some(LikeSortBy::CreatedAtAsc),
+                created_after: None,
+                created_before: None,
+                tag_kind: None,
+                tag_name: None,
+            },
+        };
+
+        assert_eq!(payload, expected);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get_shared;
+pub mod update_collection_visibility;
+pub mod update_profile_visibility;
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    entity::{self, Collection},
+    error::UseCaseError,
+    model,
+    payload::share::Visibility,
+    repository::{r#trait::CollectionRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub visibility: Visibility,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub collection_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    /// (user_id, collection_id)
+    type Parameter = (Uuid, Uuid);
+
+    async fn from_request(
+        (user_id, collection_id): Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self {
+            user_id,
+            collection_id,
+            ..payload
+        })
+    }
+}
+
+pub type Model = model::Share;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Not found collection")]
+    NotFoundCollection,
+}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        visibility,
+        user_id,
+        collection_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let collection = repository
+        .collection()
+        .get(user_id, collection_id)
+        .await?
+        .ok_or(Error::NotFoundCollection)?;
+
+    let visibility: entity::Visibility = visibility.into();
+    let share_token = visibility.share_token(collection.share_token.clone());
+
+    let updated = repository
+        .collection()
+        .update(Collection {
+            visibility,
+            share_token: share_token.clone(),
+            updated_at: Utc::now(),
+            ..collection
+        })
+        .await?;
+
+    if !updated {
+        return Err(Error::NotFoundCollection.into());
+    }
+
+    Ok(model::Share {
+        visibility: visibility.into(),
+        share_token,
+    })
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use hyper::{Body, Request};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use util::{BodyParser, FromRequest};
+use uuid::Uuid;
+
+use crate::{
+    entity::{self, Profile},
+    error::UseCaseError,
+    model,
+    payload::share::Visibility,
+    repository::{r#trait::ProfileRepository, Backend, RepositorySet},
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct Payload {
+    pub visibility: Visibility,
+    #[serde(default)]
+    #[schemars(skip)]
+    pub user_id: Uuid,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for Payload {
+    type Error = crate::Error;
+    type Parameter = Uuid;
+
+    async fn from_request(
+        user_id: Self::Parameter,
+        request: &'a mut Request<Body>,
+    ) -> Result<Self, Self::Error> {
+        let payload: Payload = request.body_parse().await?;
+
+        Ok(Self { user_id, ..payload })
+    }
+}
+
+pub type Model = model::Share;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl From<Error> for crate::Error {
+    fn from(err: Error) -> Self {
+        UseCaseError::from(err).into()
+    }
+}
+
+pub async fn execute(
+    Payload {
+        visibility,
+        user_id,
+    }: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
+    let profile = repository
+        .profile()
+        .get(user_id)
+        .await?
+        .unwrap_or_else(|| Profile::new(user_id));
+
+    let visibility: entity::Visibility = visibility.into();
+    let share_token = visibility.share_token(profile.share_token);
+
+    repository
+        .profile()
+        .add_or_update(Profile {
+            user_id,
+            visibility,
+            share_token: share_token.clone(),
+            updated_at: Utc::now(),
+        })
+        .await?;
+
+    Ok(model::Share {
+        visibility: visibility.into(),
+        share_token,
+    })
+}
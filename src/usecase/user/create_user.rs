@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 use util::validate::{number, string, ValidatorNumberExt, ValidatorStringExt};
 
 use crate::{
     entity::user::User,
     error::UseCaseError,
-    repository::{r#trait::UserRepository, RepositorySet},
+    repository::{r#trait::UserRepository, Backend, RepositorySet},
 };
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub struct Payload {
     pub name: String,
     pub email: String,
@@ -65,7 +66,10 @@ impl From<Error> for crate::Error {
     }
 }
 
-pub async fn execute(p: Payload, repository: Arc<RepositorySet>) -> crate::Result<Model> {
+pub async fn execute(
+    p: Payload,
+    repository: Arc<RepositorySet<impl Backend>>,
+) -> crate::Result<Model> {
     let Payload { name, email, role } = p.validate()?;
 
     let new_user = User::new(name, email, role.into());
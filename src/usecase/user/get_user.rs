@@ -1,10 +1,9 @@
 use std::sync::Arc;
-use util::http::url::PathVariable;
 
 use crate::{
     error::UseCaseError,
     model,
-    repository::{r#trait::UserRepository, RepositorySet},
+    repository::{r#trait::UserRepository, Backend, RepositorySet},
 };
 
 #[derive(Debug)]
@@ -12,14 +11,6 @@ pub struct Payload {
     pub id_or_email: String,
 }
 
-impl From<PathVariable> for Payload {
-    fn from(mut path_variable: PathVariable) -> Self {
-        let id_or_email = path_variable.next_variable().unwrap_or_default();
-
-        Self { id_or_email }
-    }
-}
-
 pub type Model = model::User;
 
 #[derive(Debug, thiserror::Error)]
@@ -36,7 +27,7 @@ impl From<Error> for crate::Error {
 
 pub async fn execute(
     Payload { id_or_email }: Payload,
-    repository: Arc<RepositorySet>,
+    repository: Arc<RepositorySet<impl Backend>>,
 ) -> crate::Result<Model> {
     let maybe_user = repository.user().get(id_or_email).await?;
 